@@ -3,11 +3,35 @@
 //! This module defines the different authentication methods supported by Portkey
 //! for routing requests to various LLM providers.
 
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
 /// Authentication method for Portkey API.
 ///
 /// Portkey supports multiple authentication methods for routing requests
 /// to different LLM providers.
-#[derive(Debug, Clone)]
+///
+/// # Serialization
+///
+/// The method serializes as an object tagged with its `type`, so it can be
+/// persisted in JSON or TOML configuration files:
+///
+/// ```
+/// use portkey_sdk::builder::AuthMethod;
+///
+/// let auth = AuthMethod::virtual_key("vk-123");
+/// let json = serde_json::to_string(&auth).unwrap();
+/// assert_eq!(json, r#"{"type":"virtual_key","virtual_key":"vk-123"}"#);
+/// assert_eq!(serde_json::from_str::<AuthMethod>(&json).unwrap(), auth);
+/// ```
+///
+/// Serialization writes credentials in plain text, as persistence requires,
+/// so never pass an `AuthMethod` to a logger that records values through
+/// `Serialize`. The [`Debug`] output masks the virtual key and the
+/// authorization header and is safe to log.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum AuthMethod {
     /// Virtual Key authentication - managed provider credentials in Portkey.
     ///
@@ -48,6 +72,7 @@ pub enum AuthMethod {
         /// Authorization header value (e.g., "Bearer sk-...")
         authorization: String,
         /// Optional custom host URL for self-hosted or enterprise endpoints
+        #[serde(default, skip_serializing_if = "Option::is_none")]
         custom_host: Option<String>,
     },
 
@@ -138,4 +163,104 @@ impl AuthMethod {
             config_id: config_id.into(),
         }
     }
+
+    /// Returns `true` if the credential carried by this method is not empty.
+    pub(crate) fn has_credentials(&self) -> bool {
+        let credential = match self {
+            Self::VirtualKey { virtual_key } => virtual_key,
+            Self::ProviderAuth { authorization, .. } => authorization,
+            Self::Config { config_id } => config_id,
+        };
+        !credential.trim().is_empty()
+    }
+}
+
+impl fmt::Debug for AuthMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::VirtualKey { virtual_key } => f
+                .debug_struct("VirtualKey")
+                .field("virtual_key", &mask_secret(virtual_key))
+                .finish(),
+            Self::ProviderAuth {
+                provider,
+                authorization,
+                custom_host,
+            } => f
+                .debug_struct("ProviderAuth")
+                .field("provider", provider)
+                .field("authorization", &mask_secret(authorization))
+                .field("custom_host", custom_host)
+                .finish(),
+            Self::Config { config_id } => f
+                .debug_struct("Config")
+                .field("config_id", config_id)
+                .finish(),
+        }
+    }
+}
+
+/// Masks a secret for display, keeping at most its first four characters.
+pub(crate) fn mask_secret(secret: &str) -> String {
+    match secret.char_indices().nth(4) {
+        Some((end, _)) => format!("{}****", &secret[..end]),
+        None => "****".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(auth: AuthMethod, expected: serde_json::Value) {
+        assert_eq!(serde_json::to_value(&auth).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<AuthMethod>(expected).unwrap(),
+            auth
+        );
+    }
+
+    #[test]
+    fn test_auth_method_serde() {
+        round_trip(
+            AuthMethod::virtual_key("vk-123"),
+            serde_json::json!({"type": "virtual_key", "virtual_key": "vk-123"}),
+        );
+        round_trip(
+            AuthMethod::provider_auth("openai", "Bearer sk-123"),
+            serde_json::json!({
+                "type": "provider_auth",
+                "provider": "openai",
+                "authorization": "Bearer sk-123",
+            }),
+        );
+        round_trip(
+            AuthMethod::provider_auth_with_host("openai", "Bearer sk-123", "https://llm.local"),
+            serde_json::json!({
+                "type": "provider_auth",
+                "provider": "openai",
+                "authorization": "Bearer sk-123",
+                "custom_host": "https://llm.local",
+            }),
+        );
+        round_trip(
+            AuthMethod::config("pc-config-123"),
+            serde_json::json!({"type": "config", "config_id": "pc-config-123"}),
+        );
+    }
+
+    #[test]
+    fn test_auth_method_debug_masks_secrets() {
+        let debug = format!("{:?}", AuthMethod::virtual_key("vk-secret-123"));
+        assert_eq!(debug, r#"VirtualKey { virtual_key: "vk-s****" }"#);
+
+        let debug = format!(
+            "{:?}",
+            AuthMethod::provider_auth("openai", "Bearer sk-secret")
+        );
+        assert!(debug.contains(r#"authorization: "Bear****""#));
+        assert!(!debug.contains("sk-secret"));
+
+        assert_eq!(mask_secret("abc"), "****");
+    }
 }
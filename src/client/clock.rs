@@ -0,0 +1,109 @@
+//! Time source for time-dependent client logic.
+//!
+//! Code that waits or measures elapsed time (backoff, polling, cooldowns,
+//! expiry checks) reads time through the client's [`Clock`] instead of calling
+//! [`Instant::now`] or [`tokio::time::sleep`] directly. Clients use
+//! [`SystemClock`]; tests inject a [`TestClock`] with
+//! [`PortkeyClient::with_clock`](super::PortkeyClient::with_clock) so that
+//! waits complete immediately and elapsed time is deterministic.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+#[cfg(test)]
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A source of time that can also wait.
+pub(crate) trait Clock: fmt::Debug + Send + Sync + 'static {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+
+    /// Waits for the given duration.
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>>;
+}
+
+/// The wall clock, backed by the tokio timer.
+#[derive(Debug, Default)]
+pub(crate) struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// A manually driven clock for tests.
+///
+/// Time only moves when [`advance`](Self::advance) is called or when a
+/// [`sleep`](Clock::sleep) completes, which happens immediately.
+#[cfg(test)]
+#[derive(Debug)]
+pub(crate) struct TestClock {
+    start: Instant,
+    elapsed: Mutex<Duration>,
+}
+
+#[cfg(test)]
+impl TestClock {
+    /// Creates a clock that starts at the current instant.
+    pub(crate) fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            elapsed: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Moves the clock forward.
+    pub(crate) fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().unwrap() += duration;
+    }
+
+    /// Returns the total time the clock has moved forward.
+    pub(crate) fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+impl Clock for TestClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        self.advance(duration);
+        Box::pin(std::future::ready(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_test_clock_sleep_advances_time() {
+        let clock = TestClock::new();
+        let start = clock.now();
+
+        clock.sleep(Duration::from_secs(30)).await;
+        clock.advance(Duration::from_secs(5));
+
+        assert_eq!(clock.now() - start, Duration::from_secs(35));
+        assert_eq!(clock.elapsed(), Duration::from_secs(35));
+    }
+
+    #[tokio::test]
+    async fn test_system_clock_sleep() {
+        let clock = SystemClock;
+        let start = clock.now();
+
+        clock.sleep(Duration::from_millis(5)).await;
+
+        assert!(clock.now() - start >= Duration::from_millis(5));
+    }
+}
@@ -5,13 +5,19 @@
 
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::Arc;
 use std::time::Duration;
 
 use derive_builder::Builder;
 use reqwest::Client;
 
-use super::auth::AuthMethod;
+use super::auth::{AuthMethod, mask_secret};
+use super::metadata::MetadataPolicy;
+use super::options::validate_portkey_header;
+use super::paths::{EndpointCategory, PathMap};
 use super::portkey::PortkeyClient;
+use super::transport::HttpTransport;
+use super::user::UserHasher;
 #[cfg(feature = "tracing")]
 use crate::TRACING_TARGET_CONFIG;
 use crate::error::Result;
@@ -65,8 +71,17 @@ pub struct PortkeyConfig {
     /// API key for authentication with the Portkey API.
     ///
     /// This is your Portkey API key from the dashboard (x-portkey-api-key header).
+    /// Required unless sending it is disabled with `with_send_api_key(false)`.
+    #[builder(default)]
     api_key: String,
 
+    /// Whether to send the `x-portkey-api-key` header.
+    ///
+    /// Disable this for self-hosted gateways that sit behind another auth layer
+    /// and must not receive the Portkey API key. Provider auth headers are still sent.
+    #[builder(default = "true")]
+    send_api_key: bool,
+
     /// Authentication method for provider routing.
     ///
     /// Specifies how to authenticate with LLM providers through Portkey.
@@ -78,12 +93,52 @@ pub struct PortkeyConfig {
     #[builder(default = "Self::default_base_url()")]
     base_url: String,
 
+    /// Optional path prefix inserted between the base URL and every service path.
+    ///
+    /// Useful for self-hosted gateways that namespace their endpoints, e.g.
+    /// `/api/llm/v1` turns `/chat/completions` into `/api/llm/v1/chat/completions`.
+    #[builder(default = "None")]
+    path_prefix: Option<String>,
+
+    /// Optional custom paths for endpoints.
+    ///
+    /// For OpenAI-compatible gateways whose routes differ from the standard
+    /// layout. Endpoints not in the map use their standard path.
+    #[builder(default = "None")]
+    path_map: Option<PathMap>,
+
     /// Timeout for HTTP requests.
     ///
     /// Controls how long the client will wait for API responses before timing out.
     #[builder(default = "Self::default_timeout()")]
     timeout: Duration,
 
+    /// Timeouts that replace [`timeout`](Self::timeout) for categories of
+    /// endpoints.
+    #[builder(default, setter(custom))]
+    endpoint_timeouts: HashMap<EndpointCategory, Duration>,
+
+    /// Maximum number of requests in flight at once, shared by all clones of
+    /// the client.
+    ///
+    /// Requests beyond the limit wait for a slot, and the wait counts against
+    /// their timeout or deadline. Unlimited by default.
+    #[builder(default = "None")]
+    max_concurrent_requests: Option<usize>,
+
+    /// Policy for retrying requests that fail with `429` or a `5xx` status.
+    ///
+    /// Disabled by default, so every failure is returned immediately.
+    #[builder(default = "None")]
+    retry_policy: Option<RetryPolicy>,
+
+    /// `User-Agent` header sent with every request.
+    ///
+    /// Defaults to `portkey-sdk-rust/<version>`, which identifies the SDK
+    /// version in gateway logs.
+    #[builder(default = "Self::default_user_agent()")]
+    user_agent: String,
+
     /// Optional custom reqwest client.
     ///
     /// If provided, this client will be used instead of creating a new one.
@@ -91,6 +146,19 @@ pub struct PortkeyConfig {
     #[builder(default = "None")]
     client: Option<Client>,
 
+    /// Whether the internal client accepts invalid TLS certificates.
+    ///
+    /// Only settable with the `danger` feature.
+    #[builder(default = "false", setter(custom))]
+    danger_accept_invalid_certs: bool,
+
+    /// Optional custom HTTP transport.
+    ///
+    /// If provided, requests are executed by this transport instead of the
+    /// reqwest client. Requests are still built with reqwest types.
+    #[builder(default = "None", setter(custom))]
+    transport: Option<Arc<dyn HttpTransport>>,
+
     /// Optional trace ID for request tracking.
     ///
     /// An ID you can pass to refer to one or more requests later on.
@@ -104,6 +172,49 @@ pub struct PortkeyConfig {
     #[builder(default = "None")]
     metadata: Option<HashMap<String, serde_json::Value>>,
 
+    /// Optional policy applied to metadata before it is sent.
+    ///
+    /// Drops denied keys and enforces a maximum header size.
+    #[builder(default = "None")]
+    metadata_policy: Option<MetadataPolicy>,
+
+    /// Whether metadata serialization failures fail the request.
+    ///
+    /// By default, metadata that cannot be serialized is skipped with a warning
+    /// and the request is sent without it. In strict mode, the request fails
+    /// with an error before it is sent.
+    #[builder(default = "false")]
+    strict_metadata: bool,
+
+    /// Whether the `object` type of responses is checked.
+    ///
+    /// When enabled, responses whose `object` field does not match the type
+    /// the endpoint returns (for example `"list"` for list endpoints) fail
+    /// with [`Error::UnexpectedResponse`](crate::Error::UnexpectedResponse).
+    /// Disabled by default for compatibility with lenient gateways.
+    #[builder(default = "false")]
+    strict_response_objects: bool,
+
+    /// Whether requests are checked against known model limits before they
+    /// are sent.
+    ///
+    /// When enabled, requests that a known model would reject, such as
+    /// DALL-E 3 image generation with `n > 1`, fail with
+    /// [`Error::Validation`](crate::Error::Validation) instead of a round
+    /// trip. Requests for unknown or custom models are sent unchecked.
+    /// Disabled by default.
+    #[builder(default = "false")]
+    strict_validation: bool,
+
+    /// Whether the gateway normalizes responses to the OpenAI format.
+    ///
+    /// Sent as `x-portkey-strict-open-ai-compliance`. Enabled by default, so
+    /// responses match the typed models. Disable it to receive
+    /// provider-native fields such as `content_blocks`, which the models only
+    /// partially cover.
+    #[builder(default = "true")]
+    strict_openai_compliance: bool,
+
     /// Optional cache namespace.
     ///
     /// Partition your Portkey cache store based on custom strings.
@@ -115,6 +226,40 @@ pub struct PortkeyConfig {
     /// Forces a cache refresh by making a new API call and storing the updated value.
     #[builder(default = "None")]
     cache_force_refresh: Option<bool>,
+
+    /// Optional hasher for end-user identifiers.
+    ///
+    /// When set, the `user` field of JSON request bodies is replaced with its
+    /// salted SHA-256 hash before the request is sent.
+    #[builder(default = "None")]
+    user_hasher: Option<UserHasher>,
+
+    /// Optional default model.
+    ///
+    /// Used by chat completion, completion and embedding requests whose
+    /// `model` is left empty. A model set on the request always wins.
+    #[builder(default = "None")]
+    default_model: Option<String>,
+
+    /// Optional default `max_tokens`.
+    ///
+    /// Applied to chat completion and completion requests that do not set
+    /// `max_tokens`. A value set on the request always wins.
+    #[builder(default = "None")]
+    default_max_tokens: Option<u32>,
+
+    /// Optional default sampling temperature, between 0 and 2.
+    ///
+    /// Applied to chat completion and completion requests that do not set
+    /// `temperature`. A value set on the request always wins.
+    #[builder(default = "None")]
+    default_temperature: Option<f32>,
+
+    /// Additional `x-portkey-*` headers sent with every request.
+    ///
+    /// An escape hatch for gateway features the SDK does not model yet.
+    #[builder(default, setter(custom))]
+    portkey_headers: Vec<(String, String)>,
 }
 
 impl PortkeyBuilder {
@@ -128,21 +273,83 @@ impl PortkeyBuilder {
         Duration::from_secs(30)
     }
 
+    /// Returns the default user agent.
+    fn default_user_agent() -> String {
+        concat!("portkey-sdk-rust/", env!("CARGO_PKG_VERSION")).to_string()
+    }
+
     /// Validates the configuration before building.
     fn validate_config(&self) -> Result<(), String> {
-        // Validate API key is not empty
-        if let Some(ref api_key) = self.api_key
-            && api_key.trim().is_empty()
+        if self.send_api_key.unwrap_or(true) {
+            // Validate API key is set and not empty
+            match self.api_key {
+                Some(ref api_key) if api_key.trim().is_empty() => {
+                    return Err("API key cannot be empty".to_string());
+                }
+                None => return Err("API key must be set".to_string()),
+                Some(_) => {}
+            }
+        } else if let Some(ref auth_method) = self.auth_method
+            && !auth_method.has_credentials()
+        {
+            // Without the API key, the auth method is the only credential sent
+            return Err(
+                "Auth method credentials cannot be empty when the API key is not sent".to_string(),
+            );
+        }
+
+        // Validate generation defaults
+        if let Some(Some(max_tokens)) = self.default_max_tokens
+            && !(1..=i32::MAX as u32).contains(&max_tokens)
+        {
+            return Err(format!(
+                "Default max tokens must be between 1 and {}",
+                i32::MAX
+            ));
+        }
+        if let Some(Some(temperature)) = self.default_temperature
+            && !(0.0..=2.0).contains(&temperature)
+        {
+            return Err("Default temperature must be between 0 and 2".to_string());
+        }
+
+        // Validate the concurrency limit
+        if let Some(Some(max_concurrent_requests)) = self.max_concurrent_requests
+            && !(1..=tokio::sync::Semaphore::MAX_PERMITS).contains(&max_concurrent_requests)
         {
-            return Err("API key cannot be empty".to_string());
+            return Err(format!(
+                "Max concurrent requests must be between 1 and {}",
+                tokio::sync::Semaphore::MAX_PERMITS
+            ));
         }
 
-        // Validate timeout is reasonable
-        if let Some(timeout) = self.timeout {
+        // Validate the retry policy
+        if let Some(Some(retry_policy)) = &self.retry_policy
+            && retry_policy.initial_backoff > retry_policy.max_backoff
+        {
+            return Err("Retry initial backoff cannot exceed the max backoff".to_string());
+        }
+
+        // Validate custom Portkey headers
+        for (name, value) in self.portkey_headers.iter().flatten() {
+            validate_portkey_header(name, value)?;
+        }
+
+        // Validate user agent
+        if let Some(ref user_agent) = self.user_agent
+            && (user_agent.trim().is_empty()
+                || reqwest::header::HeaderValue::from_str(user_agent).is_err())
+        {
+            return Err("User agent must be a non-empty header value".to_string());
+        }
+
+        // Validate timeouts are reasonable
+        let endpoint_timeouts = self.endpoint_timeouts.iter().flat_map(HashMap::values);
+        for timeout in self.timeout.iter().chain(endpoint_timeouts) {
             if timeout.is_zero() {
                 return Err("Timeout must be greater than 0".to_string());
             }
-            if timeout > Duration::from_secs(300) {
+            if *timeout > Duration::from_secs(300) {
                 return Err("Timeout cannot exceed 300 seconds (5 minutes)".to_string());
             }
         }
@@ -169,6 +376,238 @@ impl PortkeyBuilder {
         let config = self.build()?;
         PortkeyClient::new(config)
     }
+
+    /// Sets a custom HTTP transport that executes all requests.
+    ///
+    /// Use this to run the client against a non-reqwest HTTP stack or an
+    /// in-memory mock. A custom transport takes precedence over
+    /// [`with_client`](Self::with_client).
+    pub fn with_transport(mut self, transport: impl HttpTransport) -> Self {
+        self.transport = Some(Some(Arc::new(transport)));
+        self
+    }
+
+    /// Accepts invalid TLS certificates, such as self-signed ones.
+    ///
+    /// **This is insecure.** Certificate verification is disabled entirely,
+    /// so any server can impersonate the gateway and read requests,
+    /// including the API key. Use it only for a local or staging gateway
+    /// with a self-signed certificate. It applies to the internal client and
+    /// has no effect when a custom [client](Self::with_client) or
+    /// [transport](Self::with_transport) is set.
+    #[cfg(feature = "danger")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "danger")))]
+    pub fn with_danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.danger_accept_invalid_certs = Some(accept);
+        self
+    }
+
+    /// Sets the timeout of a category of endpoints.
+    ///
+    /// Requests to endpoints in the category use this timeout instead of the
+    /// [global one](Self::with_timeout), which still applies to every other
+    /// category. A [deadline](crate::RequestOptions::with_deadline) on a
+    /// request takes precedence over both.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use portkey_sdk::PortkeyConfig;
+    /// use portkey_sdk::builder::EndpointCategory;
+    ///
+    /// let config = PortkeyConfig::builder()
+    ///     .with_api_key("your-api-key")
+    ///     .with_timeout(Duration::from_secs(30))
+    ///     .with_endpoint_timeout(EndpointCategory::Audio, Duration::from_secs(90))
+    ///     .with_endpoint_timeout(EndpointCategory::Batch, Duration::from_secs(180))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_endpoint_timeout(mut self, category: EndpointCategory, timeout: Duration) -> Self {
+        self.endpoint_timeouts
+            .get_or_insert_with(HashMap::new)
+            .insert(category, timeout);
+        self
+    }
+
+    /// Adds an arbitrary `x-portkey-*` header sent with every request.
+    ///
+    /// Use this for gateway features the SDK does not model yet, such as
+    /// `x-portkey-debug`. The name is lowercased. Building fails if the name
+    /// does not start with `x-portkey-` or is a header the client already
+    /// sets from a dedicated setting.
+    pub fn with_portkey_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.portkey_headers
+            .get_or_insert_with(Vec::new)
+            .push((name.into().to_ascii_lowercase(), value.into()));
+        self
+    }
+}
+
+/// Read-only inspection of the values set so far.
+///
+/// These getters do not consume the builder, so configuration can be layered
+/// from several sources. A field that has not been set reads as `None`, even
+/// if it has a default that is applied by [`build`](PortkeyBuilder::build).
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use portkey_sdk::PortkeyConfig;
+/// let mut builder = PortkeyConfig::builder().with_api_key("your-api-key");
+/// if builder.timeout().is_none() {
+///     builder = builder.with_timeout(Duration::from_secs(60));
+/// }
+/// ```
+impl PortkeyBuilder {
+    /// Returns the API key, if set.
+    pub fn api_key(&self) -> Option<&str> {
+        self.api_key.as_deref()
+    }
+
+    /// Returns whether the API key header is sent, if set.
+    pub fn send_api_key(&self) -> Option<bool> {
+        self.send_api_key
+    }
+
+    /// Returns the authentication method, if set.
+    pub fn auth_method(&self) -> Option<&AuthMethod> {
+        self.auth_method.as_ref()
+    }
+
+    /// Returns the base URL, if set.
+    pub fn base_url(&self) -> Option<&str> {
+        self.base_url.as_deref()
+    }
+
+    /// Returns the path prefix, if set.
+    pub fn path_prefix(&self) -> Option<&str> {
+        self.path_prefix.as_ref()?.as_deref()
+    }
+
+    /// Returns the path map, if set.
+    pub fn path_map(&self) -> Option<&PathMap> {
+        self.path_map.as_ref()?.as_ref()
+    }
+
+    /// Returns the request timeout, if set.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns the user agent, if set.
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+
+    /// Returns `true` if a custom HTTP client is set.
+    pub fn has_client(&self) -> bool {
+        matches!(self.client, Some(Some(_)))
+    }
+
+    /// Returns `true` if a custom HTTP transport is set.
+    pub fn has_transport(&self) -> bool {
+        matches!(self.transport, Some(Some(_)))
+    }
+
+    /// Returns the trace ID, if set.
+    pub fn trace_id(&self) -> Option<&str> {
+        self.trace_id.as_ref()?.as_deref()
+    }
+
+    /// Returns the metadata, if set.
+    pub fn metadata(&self) -> Option<&HashMap<String, serde_json::Value>> {
+        self.metadata.as_ref()?.as_ref()
+    }
+
+    /// Returns the metadata policy, if set.
+    pub fn metadata_policy(&self) -> Option<&MetadataPolicy> {
+        self.metadata_policy.as_ref()?.as_ref()
+    }
+
+    /// Returns whether strict metadata mode is enabled, if set.
+    pub fn strict_metadata(&self) -> Option<bool> {
+        self.strict_metadata
+    }
+
+    /// Returns whether response `object` types are checked, if set.
+    pub fn strict_response_objects(&self) -> Option<bool> {
+        self.strict_response_objects
+    }
+
+    /// Returns whether requests are checked against model limits, if set.
+    pub fn strict_validation(&self) -> Option<bool> {
+        self.strict_validation
+    }
+
+    /// Returns whether invalid TLS certificates are accepted, if set.
+    #[cfg(feature = "danger")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "danger")))]
+    pub fn danger_accept_invalid_certs(&self) -> Option<bool> {
+        self.danger_accept_invalid_certs
+    }
+
+    /// Returns whether strict OpenAI compliance is requested, if set.
+    pub fn strict_openai_compliance(&self) -> Option<bool> {
+        self.strict_openai_compliance
+    }
+
+    /// Returns the cache namespace, if set.
+    pub fn cache_namespace(&self) -> Option<&str> {
+        self.cache_namespace.as_ref()?.as_deref()
+    }
+
+    /// Returns the cache force refresh flag, if set.
+    pub fn cache_force_refresh(&self) -> Option<bool> {
+        self.cache_force_refresh.flatten()
+    }
+
+    /// Returns the user hasher, if set.
+    pub fn user_hasher(&self) -> Option<&UserHasher> {
+        self.user_hasher.as_ref()?.as_ref()
+    }
+
+    /// Returns the default model, if set.
+    pub fn default_model(&self) -> Option<&str> {
+        self.default_model.as_ref()?.as_deref()
+    }
+
+    /// Returns the default `max_tokens`, if set.
+    pub fn default_max_tokens(&self) -> Option<u32> {
+        self.default_max_tokens.flatten()
+    }
+
+    /// Returns the default temperature, if set.
+    pub fn default_temperature(&self) -> Option<f32> {
+        self.default_temperature.flatten()
+    }
+
+    /// Returns the custom Portkey headers added so far.
+    pub fn portkey_headers(&self) -> &[(String, String)] {
+        self.portkey_headers.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the timeout of a category of endpoints, if set.
+    pub fn endpoint_timeout(&self, category: EndpointCategory) -> Option<Duration> {
+        self.endpoint_timeouts.as_ref()?.get(&category).copied()
+    }
+
+    /// Returns the maximum number of concurrent requests, if set.
+    pub fn max_concurrent_requests(&self) -> Option<usize> {
+        self.max_concurrent_requests.flatten()
+    }
+
+    /// Returns the retry policy, if set.
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()?.as_ref()
+    }
 }
 
 impl PortkeyConfig {
@@ -216,11 +655,12 @@ impl PortkeyConfig {
     /// Shows the first 4 characters followed by "****", or just "****"
     /// if the key is shorter than 4 characters.
     pub fn masked_api_key(&self) -> String {
-        if self.api_key.len() > 4 {
-            format!("{}****", &self.api_key[..4])
-        } else {
-            "****".to_string()
-        }
+        mask_secret(&self.api_key)
+    }
+
+    /// Returns whether the `x-portkey-api-key` header is sent.
+    pub fn send_api_key(&self) -> bool {
+        self.send_api_key
     }
 
     /// Returns the authentication method.
@@ -233,16 +673,55 @@ impl PortkeyConfig {
         &self.base_url
     }
 
+    /// Returns the path prefix, if set.
+    pub fn path_prefix(&self) -> Option<&str> {
+        self.path_prefix.as_deref()
+    }
+
+    /// Returns the path map, if set.
+    pub fn path_map(&self) -> Option<&PathMap> {
+        self.path_map.as_ref()
+    }
+
     /// Returns the timeout duration.
     pub fn timeout(&self) -> Duration {
         self.timeout
     }
 
+    /// Returns the timeout of a category of endpoints, falling back to the
+    /// global [`timeout`](Self::timeout).
+    pub fn endpoint_timeout(&self, category: EndpointCategory) -> Duration {
+        self.endpoint_timeouts
+            .get(&category)
+            .copied()
+            .unwrap_or(self.timeout)
+    }
+
+    /// Returns the maximum number of concurrent requests, if limited.
+    pub fn max_concurrent_requests(&self) -> Option<usize> {
+        self.max_concurrent_requests
+    }
+
+    /// Returns the retry policy, if retries are enabled.
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Returns the user agent.
+    pub fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
     /// Returns a clone of the custom reqwest client, if one was provided.
     pub(crate) fn client(&self) -> Option<Client> {
         self.client.clone()
     }
 
+    /// Returns the custom HTTP transport, if one was provided.
+    pub(crate) fn transport(&self) -> Option<Arc<dyn HttpTransport>> {
+        self.transport.clone()
+    }
+
     /// Returns the trace ID, if set.
     pub fn trace_id(&self) -> Option<&str> {
         self.trace_id.as_deref()
@@ -253,6 +732,38 @@ impl PortkeyConfig {
         self.metadata.as_ref()
     }
 
+    /// Returns the metadata policy, if set.
+    pub fn metadata_policy(&self) -> Option<&MetadataPolicy> {
+        self.metadata_policy.as_ref()
+    }
+
+    /// Returns whether metadata serialization failures fail the request.
+    pub fn strict_metadata(&self) -> bool {
+        self.strict_metadata
+    }
+
+    /// Returns whether response `object` types are checked.
+    pub fn strict_response_objects(&self) -> bool {
+        self.strict_response_objects
+    }
+
+    /// Returns whether requests are checked against model limits.
+    pub fn strict_validation(&self) -> bool {
+        self.strict_validation
+    }
+
+    /// Returns whether the internal client accepts invalid TLS certificates.
+    ///
+    /// Always `false` without the `danger` feature.
+    pub fn danger_accept_invalid_certs(&self) -> bool {
+        self.danger_accept_invalid_certs
+    }
+
+    /// Returns whether strict OpenAI compliance is requested.
+    pub fn strict_openai_compliance(&self) -> bool {
+        self.strict_openai_compliance
+    }
+
     /// Returns the cache namespace, if set.
     pub fn cache_namespace(&self) -> Option<&str> {
         self.cache_namespace.as_deref()
@@ -263,8 +774,36 @@ impl PortkeyConfig {
         self.cache_force_refresh
     }
 
+    /// Returns the end-user identifier hasher, if configured.
+    pub fn user_hasher(&self) -> Option<&UserHasher> {
+        self.user_hasher.as_ref()
+    }
+
+    /// Returns the default model, if set.
+    pub fn default_model(&self) -> Option<&str> {
+        self.default_model.as_deref()
+    }
+
+    /// Returns the default `max_tokens`, if set.
+    pub fn default_max_tokens(&self) -> Option<u32> {
+        self.default_max_tokens
+    }
+
+    /// Returns the default temperature, if set.
+    pub fn default_temperature(&self) -> Option<f32> {
+        self.default_temperature
+    }
+
+    /// Returns the custom Portkey headers sent with every request.
+    pub fn portkey_headers(&self) -> &[(String, String)] {
+        &self.portkey_headers
+    }
+
     /// Creates a configuration from environment variables.
     ///
+    /// To override individual values, start from [`PortkeyBuilder::from_env`]
+    /// instead: values set on the builder take precedence over the environment.
+    ///
     /// # Environment Variables
     ///
     /// **Required:**
@@ -301,10 +840,78 @@ impl PortkeyConfig {
     /// ```
     #[cfg_attr(feature = "tracing", tracing::instrument)]
     pub fn from_env() -> Result<Self> {
+        Self::from_env_ignoring(&[])
+    }
+
+    /// Creates a configuration from environment variables, skipping the
+    /// listed variables as if they were not set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use portkey_sdk::{PortkeyConfig, Result};
+    ///
+    /// # fn example() -> Result<()> {
+    /// // Use the default base URL even if `PORTKEY_BASE_URL` is exported.
+    /// let config = PortkeyConfig::from_env_ignoring(&["PORTKEY_BASE_URL"])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn from_env_ignoring(ignored: &[&str]) -> Result<Self> {
         #[cfg(feature = "tracing")]
         tracing::debug!(target: TRACING_TARGET_CONFIG, "Loading configuration from environment");
 
-        let api_key = std::env::var("PORTKEY_API_KEY").map_err(|_| {
+        let config = PortkeyBuilder::from_env_ignoring(ignored)?.build()?;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            target: TRACING_TARGET_CONFIG,
+            base_url = %config.base_url(),
+            timeout = ?config.timeout(),
+            "Configuration loaded successfully from environment"
+        );
+
+        Ok(config)
+    }
+}
+
+impl PortkeyBuilder {
+    /// Creates a builder populated from environment variables.
+    ///
+    /// Reads the same variables as [`PortkeyConfig::from_env`]. Values set on
+    /// the returned builder override the ones read from the environment:
+    ///
+    /// ```no_run
+    /// use portkey_sdk::builder::PortkeyBuilder;
+    /// use portkey_sdk::Result;
+    ///
+    /// # fn example() -> Result<()> {
+    /// let config = PortkeyBuilder::from_env()?
+    ///     .with_base_url("https://api.portkey.ai/v1")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<Self> {
+        Self::from_env_ignoring(&[])
+    }
+
+    /// Creates a builder populated from environment variables, skipping the
+    /// listed variables as if they were not set.
+    pub fn from_env_ignoring(ignored: &[&str]) -> Result<Self> {
+        Self::from_vars(|name| {
+            if ignored.contains(&name) {
+                None
+            } else {
+                std::env::var(name).ok()
+            }
+        })
+    }
+
+    /// Creates a builder from variables returned by `var`.
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let api_key = var("PORTKEY_API_KEY").ok_or_else(|| {
             #[cfg(feature = "tracing")]
             tracing::error!(target: TRACING_TARGET_CONFIG, "PORTKEY_API_KEY environment variable not set");
 
@@ -314,21 +921,21 @@ impl PortkeyConfig {
         })?;
 
         // Determine authentication method
-        let auth_method = if let Ok(virtual_key) = std::env::var("PORTKEY_VIRTUAL_KEY") {
+        let auth_method = if let Some(virtual_key) = var("PORTKEY_VIRTUAL_KEY") {
             AuthMethod::VirtualKey { virtual_key }
-        } else if let Ok(provider) = std::env::var("PORTKEY_PROVIDER") {
-            let authorization = std::env::var("PORTKEY_AUTHORIZATION").map_err(|_| {
+        } else if let Some(provider) = var("PORTKEY_PROVIDER") {
+            let authorization = var("PORTKEY_AUTHORIZATION").ok_or_else(|| {
                 PortkeyBuilderError::ValidationError(
                     "PORTKEY_AUTHORIZATION required when PORTKEY_PROVIDER is set".to_string(),
                 )
             })?;
-            let custom_host = std::env::var("PORTKEY_CUSTOM_HOST").ok();
+            let custom_host = var("PORTKEY_CUSTOM_HOST");
             AuthMethod::ProviderAuth {
                 provider,
                 authorization,
                 custom_host,
             }
-        } else if let Ok(config_id) = std::env::var("PORTKEY_CONFIG") {
+        } else if let Some(config_id) = var("PORTKEY_CONFIG") {
             AuthMethod::Config { config_id }
         } else {
             return Err(PortkeyBuilderError::ValidationError(
@@ -338,12 +945,12 @@ impl PortkeyConfig {
             .into());
         };
 
-        let mut builder = Self::builder()
+        let mut builder = Self::default()
             .with_api_key(api_key)
             .with_auth_method(auth_method);
 
         // Optional: custom base URL
-        if let Ok(base_url) = std::env::var("PORTKEY_BASE_URL") {
+        if let Some(base_url) = var("PORTKEY_BASE_URL") {
             #[cfg(feature = "tracing")]
             tracing::debug!(target: TRACING_TARGET_CONFIG, base_url = %base_url, "Using custom base URL");
 
@@ -351,7 +958,7 @@ impl PortkeyConfig {
         }
 
         // Optional: custom timeout
-        if let Ok(timeout_str) = std::env::var("PORTKEY_TIMEOUT_SECS") {
+        if let Some(timeout_str) = var("PORTKEY_TIMEOUT_SECS") {
             let timeout_secs = timeout_str.parse::<u64>().map_err(|_| {
                 #[cfg(feature = "tracing")]
                 tracing::error!(target: TRACING_TARGET_CONFIG, timeout_str = %timeout_str, "Invalid PORTKEY_TIMEOUT_SECS value");
@@ -369,33 +976,145 @@ impl PortkeyConfig {
         }
 
         // Optional: trace ID
-        if let Ok(trace_id) = std::env::var("PORTKEY_TRACE_ID") {
+        if let Some(trace_id) = var("PORTKEY_TRACE_ID") {
             builder = builder.with_trace_id(trace_id);
         }
 
         // Optional: cache namespace
-        if let Ok(cache_namespace) = std::env::var("PORTKEY_CACHE_NAMESPACE") {
+        if let Some(cache_namespace) = var("PORTKEY_CACHE_NAMESPACE") {
             builder = builder.with_cache_namespace(cache_namespace);
         }
 
         // Optional: cache force refresh
-        if let Ok(cache_force_refresh_str) = std::env::var("PORTKEY_CACHE_FORCE_REFRESH")
+        if let Some(cache_force_refresh_str) = var("PORTKEY_CACHE_FORCE_REFRESH")
             && let Ok(cache_force_refresh) = cache_force_refresh_str.parse::<bool>()
         {
             builder = builder.with_cache_force_refresh(cache_force_refresh);
         }
 
-        let config = builder.build()?;
+        Ok(builder)
+    }
+}
 
-        #[cfg(feature = "tracing")]
-        tracing::info!(
-            target: TRACING_TARGET_CONFIG,
-            base_url = %config.base_url(),
-            timeout = ?config.timeout(),
-            "Configuration loaded successfully from environment"
-        );
+/// Policy for retrying requests that fail with `429` or a `5xx` status.
+///
+/// Requests with idempotent methods, such as `GET` and `DELETE`, are retried
+/// automatically. Other requests are only retried when marked with
+/// [`RequestOptions::with_idempotent`](crate::RequestOptions::with_idempotent).
+/// Requests with a streamed body, such as file uploads, are never retried.
+///
+/// The wait before each retry doubles from `initial_backoff` up to
+/// `max_backoff`, unless the response carries a `Retry-After` header in
+/// seconds, which is honored up to `max_backoff`. Retries share the timeout
+/// or deadline of the call: each retry only gets the time that is left, and a
+/// retry that would start after it fails with
+/// [`Error::DeadlineExceeded`](crate::Error::DeadlineExceeded).
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use portkey_sdk::PortkeyConfig;
+/// use portkey_sdk::builder::{AuthMethod, RetryPolicy};
+///
+/// let config = PortkeyConfig::builder()
+///     .with_api_key("your-api-key")
+///     .with_auth_method(AuthMethod::virtual_key("your-virtual-key"))
+///     .with_retry_policy(
+///         RetryPolicy::new(3)
+///             .with_initial_backoff(Duration::from_millis(250))
+///             .with_max_backoff(Duration::from_secs(4)),
+///     )
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.retry_policy().unwrap().max_retries(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
 
-        Ok(config)
+impl RetryPolicy {
+    /// Creates a policy that retries up to `max_retries` times, backing off
+    /// from 500ms up to 8s with jitter.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+            jitter: true,
+        }
+    }
+
+    /// Sets the wait before the first retry.
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the longest wait between retries.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets whether waits are randomized, so that clients failing together
+    /// do not retry together.
+    ///
+    /// With jitter, each wait is picked between half and all of the backoff.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Returns the maximum number of retries after the first attempt.
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the wait before the first retry.
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    /// Returns the longest wait between retries.
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Returns whether waits are randomized.
+    pub fn jitter(&self) -> bool {
+        self.jitter
+    }
+
+    /// Returns the wait before the given retry, counting from 1.
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        if !self.jitter {
+            return backoff;
+        }
+
+        let mut bytes = [0; 4];
+        let fraction =
+            match ring::rand::SecureRandom::fill(&ring::rand::SystemRandom::new(), &mut bytes) {
+                Ok(()) => f64::from(u32::from_le_bytes(bytes)) / f64::from(u32::MAX),
+                Err(_) => 1.0,
+            };
+        backoff.mul_f64(0.5 + fraction / 2.0)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(2)
     }
 }
 
@@ -404,6 +1123,7 @@ impl fmt::Debug for PortkeyConfig {
         f.debug_struct("PortkeyConfig")
             .field("api_key", &self.masked_api_key())
             .field("base_url", &self.base_url)
+            .field("path_prefix", &self.path_prefix)
             .field("timeout", &self.timeout)
             .finish()
     }
@@ -429,6 +1149,191 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "danger")]
+    #[test]
+    fn test_danger_accept_invalid_certs() -> Result<()> {
+        let builder = PortkeyConfig::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_base_url("https://localhost:8787/v1")
+            .with_danger_accept_invalid_certs(true);
+        assert_eq!(builder.danger_accept_invalid_certs(), Some(true));
+
+        let config = builder.build()?;
+        assert!(config.danger_accept_invalid_certs());
+        config.build_client()?;
+
+        let config = PortkeyConfig::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .build()?;
+        assert!(!config.danger_accept_invalid_certs());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_getters() {
+        let builder = PortkeyConfig::builder();
+        assert_eq!(builder.api_key(), None);
+        assert_eq!(builder.timeout(), None);
+        assert_eq!(builder.base_url(), None);
+        assert!(!builder.has_client());
+
+        let builder = builder
+            .with_api_key("test-key")
+            .with_timeout(Duration::from_secs(60))
+            .with_trace_id("trace-123");
+        assert_eq!(builder.api_key(), Some("test-key"));
+        assert_eq!(builder.timeout(), Some(Duration::from_secs(60)));
+        assert_eq!(builder.trace_id(), Some("trace-123"));
+        assert_eq!(builder.cache_namespace(), None);
+    }
+
+    #[test]
+    fn test_builder_values_override_env() -> Result<()> {
+        let vars: HashMap<&str, &str> = HashMap::from([
+            ("PORTKEY_API_KEY", "env_key"),
+            ("PORTKEY_VIRTUAL_KEY", "env_vk"),
+            ("PORTKEY_BASE_URL", "https://staging.example.com/v1"),
+            ("PORTKEY_TRACE_ID", "env-trace"),
+        ]);
+        let var = |name: &str| vars.get(name).map(|value| value.to_string());
+
+        let config = PortkeyBuilder::from_vars(var)?.build()?;
+        assert_eq!(config.base_url(), "https://staging.example.com/v1");
+        assert_eq!(config.trace_id(), Some("env-trace"));
+
+        let config = PortkeyBuilder::from_vars(var)?
+            .with_base_url("https://api.portkey.ai/v1")
+            .build()?;
+        assert_eq!(config.base_url(), "https://api.portkey.ai/v1");
+        assert_eq!(config.api_key(), "env_key");
+
+        let config = PortkeyBuilder::from_vars(|name| {
+            (name != "PORTKEY_BASE_URL").then(|| var(name)).flatten()
+        })?
+        .build()?;
+        assert_eq!(config.base_url(), "https://api.portkey.ai/v1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_env_ignoring_required_var() {
+        let result = PortkeyConfig::from_env_ignoring(&["PORTKEY_API_KEY"]);
+        assert!(matches!(result, Err(crate::Error::Config(_))));
+    }
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy::new(5)
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(350))
+            .with_jitter(false);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+        assert_eq!(policy.backoff(40), Duration::from_millis(350));
+
+        let jittered = policy.with_jitter(true).backoff(2);
+        assert!(jittered >= Duration::from_millis(100) && jittered <= Duration::from_millis(200));
+
+        let invalid = PortkeyConfig::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk"))
+            .with_retry_policy(RetryPolicy::new(1).with_initial_backoff(Duration::from_secs(60)))
+            .build();
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_config_max_concurrent_requests() {
+        let builder = || {
+            PortkeyConfig::builder()
+                .with_api_key("test_key")
+                .with_auth_method(AuthMethod::virtual_key("vk"))
+        };
+
+        let config = builder()
+            .with_max_concurrent_requests(8usize)
+            .build()
+            .unwrap();
+        assert_eq!(config.max_concurrent_requests(), Some(8));
+        assert_eq!(builder().build().unwrap().max_concurrent_requests(), None);
+
+        assert!(
+            builder()
+                .with_max_concurrent_requests(0usize)
+                .build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_validation_generation_defaults() {
+        let builder = || {
+            PortkeyConfig::builder()
+                .with_api_key("test_key")
+                .with_auth_method(AuthMethod::virtual_key("vk"))
+        };
+
+        let config = builder()
+            .with_default_max_tokens(1024u32)
+            .with_default_temperature(0.2)
+            .build()
+            .unwrap();
+        assert_eq!(config.default_max_tokens(), Some(1024));
+        assert_eq!(config.default_temperature(), Some(0.2));
+
+        assert!(builder().with_default_max_tokens(0u32).build().is_err());
+        assert!(builder().with_default_temperature(2.5).build().is_err());
+        assert!(
+            builder()
+                .with_default_temperature(f32::NAN)
+                .build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_validation_portkey_headers() {
+        let builder = || {
+            PortkeyConfig::builder()
+                .with_api_key("test_key")
+                .with_auth_method(AuthMethod::virtual_key("vk"))
+        };
+
+        let config = builder()
+            .with_portkey_header("X-Portkey-Debug", "false")
+            .build()
+            .unwrap();
+        assert_eq!(
+            config.portkey_headers(),
+            [("x-portkey-debug".to_string(), "false".to_string())]
+        );
+
+        assert!(
+            builder()
+                .with_portkey_header("x-debug", "false")
+                .build()
+                .is_err()
+        );
+        assert!(
+            builder()
+                .with_portkey_header("x-portkey-virtual-key", "vk-2")
+                .build()
+                .is_err()
+        );
+        assert!(
+            builder()
+                .with_portkey_header("x-portkey-debug", "line\nbreak")
+                .build()
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_config_builder_with_custom_values() -> Result<()> {
         let config = PortkeyConfig::builder()
@@ -458,6 +1363,36 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_config_validation_missing_api_key() {
+        let result = PortkeyConfig::builder()
+            .with_auth_method(AuthMethod::virtual_key("test"))
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_config_without_api_key_header() -> Result<()> {
+        let config = PortkeyConfig::builder()
+            .with_send_api_key(false)
+            .with_auth_method(AuthMethod::provider_auth("openai", "Bearer sk-test"))
+            .build()?;
+
+        assert!(!config.send_api_key());
+        assert_eq!(config.api_key(), "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_without_api_key_header_requires_credentials() {
+        let result = PortkeyConfig::builder()
+            .with_send_api_key(false)
+            .with_auth_method(AuthMethod::provider_auth("openai", ""))
+            .build();
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_config_validation_zero_timeout() {
         let result = PortkeyConfig::builder()
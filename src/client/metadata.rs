@@ -0,0 +1,255 @@
+//! Control over the metadata sent in request headers.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[cfg(feature = "tracing")]
+use crate::TRACING_TARGET_CLIENT;
+use crate::error::{Error, Result};
+
+/// What to do when the serialized metadata exceeds the configured size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OversizeAction {
+    /// Drop entries, in key order, that do not fit within the limit.
+    #[default]
+    Truncate,
+    /// Fail the request with [`Error::Validation`] before it is sent.
+    Reject,
+}
+
+/// Policy applied to metadata before it is sent in the `x-portkey-metadata` header.
+///
+/// By default, the whole metadata map is sent as-is. A policy can drop
+/// sensitive keys and cap the size of the header so that large values do
+/// not bloat every request.
+///
+/// # Example
+///
+/// ```no_run
+/// use portkey_sdk::PortkeyConfig;
+/// use portkey_sdk::builder::{AuthMethod, MetadataPolicy, OversizeAction};
+///
+/// let config = PortkeyConfig::builder()
+///     .with_api_key("your-api-key")
+///     .with_auth_method(AuthMethod::virtual_key("your-virtual-key"))
+///     .with_metadata_policy(
+///         MetadataPolicy::new()
+///             .with_denied_keys(["email", "phone"])
+///             .with_max_size(1024)
+///             .with_oversize_action(OversizeAction::Truncate),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MetadataPolicy {
+    max_size: Option<usize>,
+    oversize_action: OversizeAction,
+    denied_keys: HashSet<String>,
+}
+
+impl MetadataPolicy {
+    /// Creates a policy that sends all metadata without a size limit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size in bytes of the serialized metadata header.
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets what to do when the metadata exceeds the maximum size.
+    pub fn with_oversize_action(mut self, action: OversizeAction) -> Self {
+        self.oversize_action = action;
+        self
+    }
+
+    /// Adds a key that is never sent.
+    pub fn with_denied_key(mut self, key: impl Into<String>) -> Self {
+        self.denied_keys.insert(key.into());
+        self
+    }
+
+    /// Adds several keys that are never sent.
+    pub fn with_denied_keys<I, K>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<String>,
+    {
+        self.denied_keys.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns the maximum size of the serialized metadata, if set.
+    pub fn max_size(&self) -> Option<usize> {
+        self.max_size
+    }
+
+    /// Returns the action taken for oversize metadata.
+    pub fn oversize_action(&self) -> OversizeAction {
+        self.oversize_action
+    }
+
+    /// Returns `true` if the key is dropped before sending.
+    pub fn is_denied(&self, key: &str) -> bool {
+        self.denied_keys.contains(key)
+    }
+
+    /// Serializes metadata according to the policy.
+    ///
+    /// Returns `None` when no entries remain to be sent.
+    pub(crate) fn encode(
+        &self,
+        metadata: &HashMap<String, serde_json::Value>,
+    ) -> Result<Option<String>> {
+        let allowed: BTreeMap<&str, &serde_json::Value> = metadata
+            .iter()
+            .filter(|(key, _)| !self.is_denied(key))
+            .map(|(key, value)| (key.as_str(), value))
+            .collect();
+        if allowed.is_empty() {
+            return Ok(None);
+        }
+
+        let encoded = serde_json::to_string(&allowed)?;
+        let Some(max_size) = self.max_size else {
+            return Ok(Some(encoded));
+        };
+        if encoded.len() <= max_size {
+            return Ok(Some(encoded));
+        }
+
+        match self.oversize_action {
+            OversizeAction::Reject => Err(Error::Validation(format!(
+                "metadata header is {} bytes, exceeding the limit of {max_size} bytes",
+                encoded.len()
+            ))),
+            OversizeAction::Truncate => {
+                let mut kept = BTreeMap::new();
+                let mut encoded = None;
+                for (key, value) in allowed {
+                    kept.insert(key, value);
+                    let candidate = serde_json::to_string(&kept)?;
+                    if candidate.len() <= max_size {
+                        encoded = Some(candidate);
+                    } else {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(target: TRACING_TARGET_CLIENT, key = %key, max_size, "Dropping metadata entry exceeding the size limit");
+
+                        kept.remove(key);
+                    }
+                }
+                Ok(encoded)
+            }
+        }
+    }
+}
+
+/// Decides whether a metadata encoding failure fails the request.
+///
+/// Policy rejections always fail. Other failures fail only in strict mode and
+/// otherwise drop the header with a warning.
+pub(crate) fn resolve_metadata(
+    encoded: Result<Option<String>>,
+    strict: bool,
+) -> Result<Option<String>> {
+    match encoded {
+        Ok(encoded) => Ok(encoded),
+        Err(e @ Error::Validation(_)) => Err(e),
+        Err(e) if strict => Err(e),
+        Err(_e) => {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(target: TRACING_TARGET_CLIENT, error = %_e, "Failed to serialize metadata, skipping header");
+
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A metadata value whose serialization always fails.
+    struct Unserializable;
+
+    impl serde::Serialize for Unserializable {
+        fn serialize<S: serde::Serializer>(&self, _: S) -> std::result::Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("value cannot be serialized"))
+        }
+    }
+
+    fn encode_unserializable() -> Result<Option<String>> {
+        let metadata = HashMap::from([("key", Unserializable)]);
+        serde_json::to_string(&metadata)
+            .map(Some)
+            .map_err(Into::into)
+    }
+
+    #[test]
+    fn test_serialization_failure_best_effort() {
+        assert!(matches!(
+            resolve_metadata(encode_unserializable(), false),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn test_serialization_failure_strict() {
+        assert!(matches!(
+            resolve_metadata(encode_unserializable(), true),
+            Err(Error::Serialization(_))
+        ));
+    }
+
+    fn metadata() -> HashMap<String, serde_json::Value> {
+        HashMap::from([
+            ("a_user".to_string(), serde_json::json!("alice")),
+            (
+                "b_email".to_string(),
+                serde_json::json!("alice@example.com"),
+            ),
+            ("c_note".to_string(), serde_json::json!("x".repeat(100))),
+        ])
+    }
+
+    #[test]
+    fn test_denied_keys_are_dropped() -> Result<()> {
+        let policy = MetadataPolicy::new().with_denied_key("b_email");
+        let encoded = policy.encode(&metadata())?.unwrap();
+
+        assert!(!encoded.contains("b_email"));
+        assert!(encoded.contains("a_user"));
+
+        let policy = policy.with_denied_keys(["a_user", "c_note"]);
+        assert_eq!(policy.encode(&metadata())?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oversize_truncates() -> Result<()> {
+        let policy = MetadataPolicy::new().with_max_size(64);
+        let encoded = policy.encode(&metadata())?.unwrap();
+
+        assert_eq!(
+            encoded,
+            r#"{"a_user":"alice","b_email":"alice@example.com"}"#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oversize_rejects() {
+        let policy = MetadataPolicy::new()
+            .with_max_size(64)
+            .with_oversize_action(OversizeAction::Reject);
+
+        assert!(matches!(
+            policy.encode(&metadata()),
+            Err(Error::Validation(_))
+        ));
+    }
+}
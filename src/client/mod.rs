@@ -5,13 +5,36 @@
 //! - [`PortkeyConfig`] - Configuration builder for API settings
 //! - [`PortkeyBuilder`] - Builder pattern for creating configurations
 //! - [`PortkeyClient`] - Main client for making API requests
+//! - [`RequestOptions`] - Per-request overrides
+//! - [`GatewayConfig`] - Inline gateway configs
+//! - [`EventStream`] - Streamed response events
+//! - [`ByteStream`] - Streamed raw response bodies
 
 mod auth;
+mod clock;
 mod config;
+mod metadata;
+mod options;
+mod paths;
 mod portkey;
+mod response;
+mod stream;
+mod transport;
+mod user;
 
+#[cfg(test)]
+pub(crate) use clock::{Clock, TestClock};
 pub use config::PortkeyConfig;
+pub(crate) use options::{ACCEPT_ANY, ACCEPT_EVENT_STREAM};
+pub use options::{GatewayConfig, RequestOptions};
+pub(crate) use paths::Endpoint;
 pub use portkey::PortkeyClient;
+pub use response::Paginated;
+pub(crate) use response::ResponseExt;
+pub(crate) use stream::{ByteSource, NdjsonDecoder, spawn_bytes, spawn_ndjson, spawn_sse};
+pub use stream::{ByteStream, EventStream, collect_all, ndjson_stream, save_while_streaming};
+#[cfg(test)]
+pub(crate) use transport::{MockTransport, RouteTransport};
 
 /// Configuration builder types for Portkey clients.
 ///
@@ -30,5 +53,9 @@ pub use portkey::PortkeyClient;
 /// ```
 pub mod builder {
     pub use super::auth::AuthMethod;
-    pub use super::config::{PortkeyBuilder, PortkeyBuilderError};
+    pub use super::config::{PortkeyBuilder, PortkeyBuilderError, RetryPolicy};
+    pub use super::metadata::{MetadataPolicy, OversizeAction};
+    pub use super::paths::{Endpoint, EndpointCategory, PathMap};
+    pub use super::transport::{HttpTransport, TransportFuture};
+    pub use super::user::UserHasher;
 }
@@ -0,0 +1,793 @@
+//! Per-request options.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use super::auth::AuthMethod;
+use crate::error::{Error, Result};
+
+/// Default capacity of the channel between the network reader and a stream consumer.
+pub(crate) const DEFAULT_STREAM_BUFFER: usize = 16;
+
+/// `Accept` header value for JSON endpoints, the default for every request.
+pub(crate) const ACCEPT_JSON: &str = "application/json";
+
+/// `Accept` header value for server-sent event streams.
+pub(crate) const ACCEPT_EVENT_STREAM: &str = "text/event-stream";
+
+/// `Accept` header value for endpoints returning content of any type.
+pub(crate) const ACCEPT_ANY: &str = "*/*";
+
+/// Portkey headers the client sets from dedicated settings.
+const RESERVED_PORTKEY_HEADERS: &[&str] = &[
+    "x-portkey-api-key",
+    "x-portkey-virtual-key",
+    "x-portkey-provider",
+    "x-portkey-custom-host",
+    "x-portkey-config",
+    "x-portkey-trace-id",
+    "x-portkey-metadata",
+    "x-portkey-cache-namespace",
+    "x-portkey-cache-force-refresh",
+    "x-portkey-forward-headers",
+    "x-portkey-strict-open-ai-compliance",
+];
+
+/// Standard headers the client sets on every request, or for some
+/// authentication methods.
+pub(crate) const CLIENT_HEADERS: &[&str] =
+    &["authorization", "user-agent", "accept", "content-type"];
+
+/// Checks that a header forwarded to the provider is valid and neither a
+/// Portkey header nor one the client sets itself.
+pub(crate) fn validate_forward_header(name: &str, value: &str) -> std::result::Result<(), String> {
+    if name.starts_with("x-portkey-") {
+        return Err(format!("Portkey header `{name}` cannot be forwarded"));
+    }
+    if CLIENT_HEADERS.contains(&name) {
+        return Err(format!(
+            "header `{name}` is set by the client and cannot be forwarded"
+        ));
+    }
+    if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err() {
+        return Err(format!(
+            "forwarded header `{name}` is not a valid header name"
+        ));
+    }
+    if reqwest::header::HeaderValue::from_str(value).is_err() {
+        return Err(format!("forwarded header `{name}` has an invalid value"));
+    }
+    Ok(())
+}
+
+/// Checks that an extra header is valid and neither a Portkey header nor one
+/// the client sets itself.
+pub(crate) fn validate_header(name: &str, value: &str) -> std::result::Result<(), String> {
+    if name.starts_with("x-portkey-") {
+        return Err(format!(
+            "Portkey header `{name}` must be set with `with_portkey_header`"
+        ));
+    }
+    if CLIENT_HEADERS.contains(&name) {
+        return Err(format!("header `{name}` is set by the client"));
+    }
+    if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err() {
+        return Err(format!("header `{name}` is not a valid header name"));
+    }
+    if reqwest::header::HeaderValue::from_str(value).is_err() {
+        return Err(format!("header `{name}` has an invalid value"));
+    }
+    Ok(())
+}
+
+/// Checks that a custom header is a valid `x-portkey-*` header that the
+/// client does not already set itself.
+pub(crate) fn validate_portkey_header(name: &str, value: &str) -> std::result::Result<(), String> {
+    if !name.starts_with("x-portkey-") {
+        return Err(format!(
+            "Portkey header `{name}` must start with `x-portkey-`"
+        ));
+    }
+    if RESERVED_PORTKEY_HEADERS.contains(&name) {
+        return Err(format!(
+            "Portkey header `{name}` is set by the client, use its dedicated setting"
+        ));
+    }
+    if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err() {
+        return Err(format!(
+            "Portkey header `{name}` is not a valid header name"
+        ));
+    }
+    if reqwest::header::HeaderValue::from_str(value).is_err() {
+        return Err(format!("Portkey header `{name}` has an invalid value"));
+    }
+    Ok(())
+}
+
+/// Options that apply to a single request.
+///
+/// Options are passed to the `*_with_options` variants of service methods and
+/// take precedence over the client configuration for that request only.
+///
+/// # Example
+///
+/// ```
+/// use portkey_sdk::RequestOptions;
+///
+/// let options = RequestOptions::default().with_stream_buffer(64);
+/// assert_eq!(options.stream_buffer(), 64);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequestOptions {
+    stream_buffer: usize,
+    config_override: Option<GatewayConfig>,
+    forced_provider: Option<usize>,
+    cache_force_refresh: Option<bool>,
+    auth_method: Option<AuthMethod>,
+    portkey_headers: Vec<(String, String)>,
+    forward_headers: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+    accept: Option<&'static str>,
+    streaming: bool,
+    deadline: Option<Instant>,
+    timeout: Option<Duration>,
+    trace_id: Option<String>,
+    cache_namespace: Option<String>,
+    metadata: Option<HashMap<String, serde_json::Value>>,
+    hedge: Option<Duration>,
+    idempotent: bool,
+}
+
+impl RequestOptions {
+    /// Creates options with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how many streamed events may be buffered ahead of the consumer.
+    ///
+    /// Once the buffer is full, the SDK stops reading from the connection until
+    /// the consumer catches up. Values below 1 are treated as 1.
+    pub fn with_stream_buffer(mut self, capacity: usize) -> Self {
+        self.stream_buffer = capacity.max(1);
+        self
+    }
+
+    /// Sets an inline gateway config for this request.
+    ///
+    /// The config is sent in the `x-portkey-config` header. If the client is
+    /// configured with an inline JSON config, the override's top-level fields
+    /// replace the client's. A saved config ID cannot be merged client-side,
+    /// so the request fails with [`Error::Validation`] rather than silently
+    /// dropping it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::{GatewayConfig, RequestOptions};
+    ///
+    /// let options = RequestOptions::new()
+    ///     .with_config_override(GatewayConfig::new().with_field("cache", serde_json::json!({"mode": "simple"})));
+    /// ```
+    pub fn with_config_override(mut self, config: GatewayConfig) -> Self {
+        self.config_override = Some(config);
+        self
+    }
+
+    /// Pins the request to a single target of the gateway config.
+    ///
+    /// Replaces the `targets` of the effective config with the target at
+    /// `index`, so fallback and load balancing are bypassed. The request fails
+    /// with [`Error::Validation`] if the effective config is not an inline
+    /// config with a target at that index.
+    pub fn with_forced_provider(mut self, index: usize) -> Self {
+        self.forced_provider = Some(index);
+        self
+    }
+
+    /// Overrides the client's cache force refresh setting for this request.
+    pub fn with_cache_force_refresh(mut self, force_refresh: bool) -> Self {
+        self.cache_force_refresh = Some(force_refresh);
+        self
+    }
+
+    /// Overrides the client's authentication method for this request.
+    ///
+    /// The override replaces the client's auth headers entirely: only the
+    /// headers of `auth_method` are sent. This lets one client, and its
+    /// connection pool, serve many tenants.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, RequestOptions, Result};
+    /// # use portkey_sdk::builder::AuthMethod;
+    /// # use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionResponse};
+    /// # use portkey_sdk::service::ChatService;
+    /// async fn complete_for_tenant(
+    ///     client: &PortkeyClient,
+    ///     tenant_virtual_key: &str,
+    ///     request: ChatCompletionRequest,
+    /// ) -> Result<ChatCompletionResponse> {
+    ///     let options =
+    ///         RequestOptions::new().with_auth_method(AuthMethod::virtual_key(tenant_virtual_key));
+    ///     client
+    ///         .create_chat_completion_with_options(request, options)
+    ///         .await
+    /// }
+    /// ```
+    pub fn with_auth_method(mut self, auth_method: AuthMethod) -> Self {
+        self.auth_method = Some(auth_method);
+        self
+    }
+
+    /// Routes this request through a different virtual key.
+    ///
+    /// Shorthand for [`with_auth_method`](Self::with_auth_method) with
+    /// [`AuthMethod::VirtualKey`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::RequestOptions;
+    ///
+    /// let options = RequestOptions::new().with_virtual_key("anthropic-vk");
+    /// ```
+    pub fn with_virtual_key(self, virtual_key: impl Into<String>) -> Self {
+        self.with_auth_method(AuthMethod::virtual_key(virtual_key))
+    }
+
+    /// Routes this request directly to a provider.
+    ///
+    /// Shorthand for [`with_auth_method`](Self::with_auth_method) with
+    /// [`AuthMethod::ProviderAuth`].
+    pub fn with_provider_auth(
+        self,
+        provider: impl Into<String>,
+        authorization: impl Into<String>,
+        custom_host: Option<String>,
+    ) -> Self {
+        self.with_auth_method(AuthMethod::ProviderAuth {
+            provider: provider.into(),
+            authorization: authorization.into(),
+            custom_host,
+        })
+    }
+
+    /// Adds an arbitrary `x-portkey-*` header to this request.
+    ///
+    /// Use this for gateway features the SDK does not model yet. The name is
+    /// lowercased and replaces a header of the same name set with
+    /// [`PortkeyBuilder::with_portkey_header`](crate::builder::PortkeyBuilder::with_portkey_header).
+    /// Names that do not start with `x-portkey-`, or that the client already
+    /// sets from a dedicated setting, fail the request with
+    /// [`Error::Validation`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::RequestOptions;
+    ///
+    /// let options = RequestOptions::new().with_portkey_header("x-portkey-debug", "false");
+    /// ```
+    pub fn with_portkey_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.portkey_headers
+            .push((name.into().to_ascii_lowercase(), value.into()));
+        self
+    }
+
+    /// Adds a header that the gateway forwards verbatim to the provider.
+    ///
+    /// Sets the header and lists its name in `x-portkey-forward-headers`, so
+    /// the gateway passes it through. The name is lowercased. Names starting
+    /// with `x-portkey-`, headers the client sets itself (`authorization`,
+    /// `user-agent`, `accept` and `content-type`) and invalid headers fail
+    /// the request with [`Error::Validation`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::RequestOptions;
+    ///
+    /// let options = RequestOptions::new()
+    ///     .with_forward_header("anthropic-beta", "prompt-caching-2024-07-31")
+    ///     .with_forward_header("x-custom-auth", "secret");
+    /// ```
+    pub fn with_forward_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.forward_headers
+            .push((name.into().to_ascii_lowercase(), value.into()));
+        self
+    }
+
+    /// Adds a header sent to the gateway with this request.
+    ///
+    /// Unlike [`with_forward_header`](Self::with_forward_header), the header is
+    /// not listed in `x-portkey-forward-headers`, so the gateway does not pass
+    /// it on to the provider. The name is lowercased. `x-portkey-*` names,
+    /// headers the client sets itself (`authorization`, `user-agent`,
+    /// `accept` and `content-type`) and invalid headers fail the request with
+    /// [`Error::Validation`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::RequestOptions;
+    ///
+    /// let options = RequestOptions::new().with_header("x-request-source", "batch-job");
+    /// ```
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers
+            .push((name.into().to_ascii_lowercase(), value.into()));
+        self
+    }
+
+    /// Sets an instant by which the request must finish.
+    ///
+    /// The time remaining until the deadline is computed when the request is
+    /// sent and replaces the client timeout, so passing the same deadline to
+    /// a chain of calls makes them share one budget; a
+    /// [timeout](Self::with_timeout) set as well caps it. If the deadline has
+    /// already passed, the request fails with [`Error::DeadlineExceeded`]
+    /// without being sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    ///
+    /// use portkey_sdk::RequestOptions;
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(10);
+    /// let options = RequestOptions::new().with_deadline(deadline);
+    /// assert_eq!(options.deadline(), Some(deadline));
+    /// ```
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Sets the timeout of this request, replacing the client and endpoint
+    /// timeouts.
+    ///
+    /// With a [deadline](Self::with_deadline) as well, the shorter of the two
+    /// applies. For streaming calls, the timeout limits the wait until the
+    /// response starts, and the stream may then run for as long as the
+    /// provider keeps sending.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use portkey_sdk::RequestOptions;
+    ///
+    /// let options = RequestOptions::new().with_timeout(Duration::from_secs(120));
+    /// assert_eq!(options.timeout(), Some(Duration::from_secs(120)));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the `x-portkey-trace-id` of this request, replacing the client's
+    /// trace ID.
+    ///
+    /// Requests sharing a trace ID are grouped together in the Portkey logs.
+    pub fn with_trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    /// Sets the cache namespace of this request, replacing the client's.
+    ///
+    /// Partitions the gateway cache, for example per tenant, without a
+    /// client per partition.
+    pub fn with_cache_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.cache_namespace = Some(namespace.into());
+        self
+    }
+
+    /// Adds a metadata entry to this request.
+    ///
+    /// Entries are merged over the client's metadata, replacing client
+    /// entries with the same key, and the result is subject to the client's
+    /// [`MetadataPolicy`](crate::builder::MetadataPolicy).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::RequestOptions;
+    ///
+    /// let options = RequestOptions::new()
+    ///     .with_metadata("_user", "user-123")
+    ///     .with_metadata("attempt", 2);
+    /// assert_eq!(options.metadata().unwrap()["attempt"], 2);
+    /// ```
+    pub fn with_metadata(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Sends a second, identical request if the first has not responded
+    /// within `after`.
+    ///
+    /// Whichever request completes first is returned and the other is
+    /// cancelled. At most one extra request is sent. This trims tail latency
+    /// for interactive calls such as chat completions, at the cost of paying
+    /// for both requests whenever the hedge fires, so pick a delay near the
+    /// latency percentile worth cutting.
+    ///
+    /// Both requests may reach the provider, so only requests that are safe
+    /// to run twice are hedged: those with an idempotent method such as `GET`
+    /// and those marked with [`with_idempotent`](Self::with_idempotent).
+    /// Other requests, and requests whose body cannot be cloned such as
+    /// streamed file uploads, are sent once. A call is hedged at most once,
+    /// even when it is retried.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use portkey_sdk::RequestOptions;
+    ///
+    /// let options = RequestOptions::new().with_hedge(Duration::from_millis(800));
+    /// assert_eq!(options.hedge(), Some(Duration::from_millis(800)));
+    /// ```
+    pub fn with_hedge(mut self, after: Duration) -> Self {
+        self.hedge = Some(after);
+        self
+    }
+
+    /// Marks the request as safe to send more than once.
+    ///
+    /// The client's [`RetryPolicy`](crate::builder::RetryPolicy) retries, and
+    /// [`with_hedge`](Self::with_hedge) hedges, requests with idempotent
+    /// methods such as `GET` on their own. Mark a `POST` as idempotent to
+    /// retry or hedge it too, for example when the gateway deduplicates it or
+    /// a repeated generation is acceptable.
+    pub fn with_idempotent(mut self, idempotent: bool) -> Self {
+        self.idempotent = idempotent;
+        self
+    }
+
+    /// Returns the stream buffer capacity.
+    pub fn stream_buffer(&self) -> usize {
+        self.stream_buffer
+    }
+
+    /// Returns the gateway config override, if set.
+    pub fn config_override(&self) -> Option<&GatewayConfig> {
+        self.config_override.as_ref()
+    }
+
+    /// Returns the index of the forced provider target, if set.
+    pub fn forced_provider(&self) -> Option<usize> {
+        self.forced_provider
+    }
+
+    /// Returns the cache force refresh override, if set.
+    pub fn cache_force_refresh(&self) -> Option<bool> {
+        self.cache_force_refresh
+    }
+
+    /// Returns the authentication method override, if set.
+    pub fn auth_method(&self) -> Option<&AuthMethod> {
+        self.auth_method.as_ref()
+    }
+
+    /// Returns the deadline of the request, if set.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Returns the request timeout, if set.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns the trace ID override, if set.
+    pub fn trace_id(&self) -> Option<&str> {
+        self.trace_id.as_deref()
+    }
+
+    /// Returns the cache namespace override, if set.
+    pub fn cache_namespace(&self) -> Option<&str> {
+        self.cache_namespace.as_deref()
+    }
+
+    /// Returns the metadata entries of this request, if any.
+    pub fn metadata(&self) -> Option<&HashMap<String, serde_json::Value>> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns the delay after which a hedged request is sent, if set.
+    pub fn hedge(&self) -> Option<Duration> {
+        self.hedge
+    }
+
+    /// Returns whether the request is marked as safe to send more than once.
+    pub fn idempotent(&self) -> bool {
+        self.idempotent
+    }
+
+    /// Returns the custom Portkey headers for this request.
+    pub fn portkey_headers(&self) -> &[(String, String)] {
+        &self.portkey_headers
+    }
+
+    /// Returns the headers forwarded to the provider for this request.
+    pub fn forward_headers(&self) -> &[(String, String)] {
+        &self.forward_headers
+    }
+
+    /// Returns the extra headers sent to the gateway for this request.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Returns the `x-portkey-forward-headers` value listing the forwarded
+    /// header names, if any.
+    pub(crate) fn forward_headers_manifest(&self) -> Option<String> {
+        let mut names: Vec<&str> = Vec::new();
+        for (name, _) in &self.forward_headers {
+            if !names.contains(&name.as_str()) {
+                names.push(name);
+            }
+        }
+        (!names.is_empty()).then(|| names.join(","))
+    }
+
+    /// Sets the `Accept` header, overriding the JSON default.
+    pub(crate) fn with_accept(mut self, accept: &'static str) -> Self {
+        self.accept = Some(accept);
+        self
+    }
+
+    /// Returns the `Accept` header value for the request.
+    pub(crate) fn accept(&self) -> &'static str {
+        self.accept.unwrap_or(ACCEPT_JSON)
+    }
+
+    /// Marks the response as streamed, so the timeout only limits the wait
+    /// for its headers and not the body read after them.
+    pub(crate) fn with_streaming(mut self) -> Self {
+        self.streaming = true;
+        self
+    }
+
+    /// Returns whether the response is streamed.
+    pub(crate) fn streaming(&self) -> bool {
+        self.streaming
+    }
+
+    /// Resolves the `x-portkey-config` header for this request.
+    ///
+    /// `base` is the config configured on the client, either a saved config ID
+    /// or inline JSON.
+    pub(crate) fn resolve_config(&self, base: Option<&str>) -> Result<Option<String>> {
+        if self.config_override.is_none() && self.forced_provider.is_none() {
+            return Ok(base.map(str::to_string));
+        }
+
+        let mut config = match base {
+            Some(base) => serde_json::from_str::<GatewayConfig>(base).map_err(|_| {
+                Error::Validation(
+                    "gateway config overrides need an inline JSON client config, not a saved config ID"
+                        .to_string(),
+                )
+            })?,
+            None => GatewayConfig::default(),
+        };
+        if let Some(config_override) = &self.config_override {
+            config.0.extend(config_override.0.clone());
+        }
+
+        if let Some(index) = self.forced_provider {
+            let target = config
+                .0
+                .get("targets")
+                .and_then(|targets| targets.get(index))
+                .cloned()
+                .ok_or_else(|| {
+                    Error::Validation(format!(
+                        "gateway config has no target at index {index} to force"
+                    ))
+                })?;
+            config.0.insert("targets".to_string(), vec![target].into());
+        }
+
+        Ok(Some(serde_json::to_string(&config)?))
+    }
+}
+
+impl Default for RequestOptions {
+    fn default() -> Self {
+        Self {
+            stream_buffer: DEFAULT_STREAM_BUFFER,
+            config_override: None,
+            forced_provider: None,
+            cache_force_refresh: None,
+            auth_method: None,
+            portkey_headers: Vec::new(),
+            forward_headers: Vec::new(),
+            headers: Vec::new(),
+            accept: None,
+            streaming: false,
+            deadline: None,
+            timeout: None,
+            trace_id: None,
+            cache_namespace: None,
+            metadata: None,
+            hedge: None,
+            idempotent: false,
+        }
+    }
+}
+
+/// An inline gateway config sent in the `x-portkey-config` header.
+///
+/// Gateway configs define routing strategies, fallbacks, load balancing,
+/// caching and retries. See the Portkey documentation for the available fields.
+///
+/// # Example
+///
+/// ```
+/// use portkey_sdk::GatewayConfig;
+///
+/// let config = GatewayConfig::new()
+///     .with_strategy("fallback")
+///     .with_target(serde_json::json!({"virtual_key": "openai-vk"}))
+///     .with_target(serde_json::json!({"virtual_key": "anthropic-vk"}));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GatewayConfig(serde_json::Map<String, serde_json::Value>);
+
+impl GatewayConfig {
+    /// Creates an empty config.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a top-level field of the config.
+    pub fn with_field(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.0.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the routing strategy mode, such as `fallback` or `loadbalance`.
+    pub fn with_strategy(self, mode: impl Into<String>) -> Self {
+        self.with_field("strategy", serde_json::json!({ "mode": mode.into() }))
+    }
+
+    /// Appends a routing target.
+    pub fn with_target(mut self, target: impl Into<serde_json::Value>) -> Self {
+        let targets = self
+            .0
+            .entry("targets")
+            .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+        if let serde_json::Value::Array(targets) = targets {
+            targets.push(target.into());
+        } else {
+            *targets = serde_json::Value::Array(vec![target.into()]);
+        }
+        self
+    }
+
+    /// Returns a top-level field of the config, if set.
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.0.get(key)
+    }
+}
+
+impl TryFrom<serde_json::Value> for GatewayConfig {
+    type Error = Error;
+
+    fn try_from(value: serde_json::Value) -> Result<Self> {
+        match value {
+            serde_json::Value::Object(map) => Ok(Self(map)),
+            _ => Err(Error::Validation(
+                "gateway config must be a JSON object".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fallback_config() -> GatewayConfig {
+        GatewayConfig::new()
+            .with_strategy("fallback")
+            .with_target(serde_json::json!({"virtual_key": "vk-a"}))
+            .with_target(serde_json::json!({"virtual_key": "vk-b"}))
+    }
+
+    #[test]
+    fn test_resolve_config_without_overrides() -> Result<()> {
+        let options = RequestOptions::new();
+        assert_eq!(options.resolve_config(None)?, None);
+        assert_eq!(
+            options.resolve_config(Some("pc-123"))?.as_deref(),
+            Some("pc-123")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_config_merges_inline_config() -> Result<()> {
+        let base = serde_json::to_string(&fallback_config())?;
+        let options = RequestOptions::new().with_config_override(
+            GatewayConfig::new().with_field("cache", serde_json::json!({"mode": "simple"})),
+        );
+
+        let resolved = options.resolve_config(Some(&base))?.unwrap();
+        let resolved: serde_json::Value = serde_json::from_str(&resolved)?;
+        assert_eq!(resolved["strategy"]["mode"], "fallback");
+        assert_eq!(resolved["cache"]["mode"], "simple");
+        assert_eq!(resolved["targets"].as_array().unwrap().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_config_rejects_saved_config() {
+        let options = RequestOptions::new().with_config_override(fallback_config());
+        assert!(matches!(
+            options.resolve_config(Some("pc-123")),
+            Err(Error::Validation(_))
+        ));
+        assert!(matches!(
+            options.resolve_config(Some("{\"strategy\":")),
+            Err(Error::Validation(_))
+        ));
+
+        let options = RequestOptions::new().with_forced_provider(0);
+        assert!(matches!(
+            options.resolve_config(Some("pc-123")),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_config_forced_provider() -> Result<()> {
+        let options = RequestOptions::new()
+            .with_config_override(fallback_config())
+            .with_forced_provider(1);
+
+        let resolved = options.resolve_config(None)?.unwrap();
+        let resolved: serde_json::Value = serde_json::from_str(&resolved)?;
+        assert_eq!(
+            resolved["targets"],
+            serde_json::json!([{"virtual_key": "vk-b"}])
+        );
+
+        let options = options.with_forced_provider(2);
+        assert!(matches!(
+            options.resolve_config(None),
+            Err(Error::Validation(_))
+        ));
+        Ok(())
+    }
+}
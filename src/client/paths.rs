@@ -0,0 +1,211 @@
+//! Endpoint paths and categories.
+//!
+//! Paths can be remapped for OpenAI-compatible gateways, and each path falls
+//! into a category that selects its timeout.
+
+use std::collections::HashMap;
+
+/// An API endpoint whose path can be remapped with a [`PathMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    /// Chat completions, `/chat/completions` by default.
+    ChatCompletions,
+    /// Text completions, `/completions` by default.
+    Completions,
+    /// Embeddings, `/embeddings` by default.
+    Embeddings,
+    /// Image generation, `/images/generations` by default.
+    ImageGenerations,
+    /// Image edits, `/images/edits` by default.
+    ImageEdits,
+    /// Image variations, `/images/variations` by default.
+    ImageVariations,
+    /// Speech synthesis, `/audio/speech` by default.
+    AudioSpeech,
+    /// Audio transcription, `/audio/transcriptions` by default.
+    AudioTranscriptions,
+    /// Audio translation, `/audio/translations` by default.
+    AudioTranslations,
+    /// Moderations, `/moderations` by default.
+    Moderations,
+    /// Model listing, `/models` by default.
+    Models,
+}
+
+impl Endpoint {
+    /// Returns the path the SDK uses for the endpoint, relative to the base URL.
+    pub fn default_path(self) -> &'static str {
+        match self {
+            Self::ChatCompletions => "/chat/completions",
+            Self::Completions => "/completions",
+            Self::Embeddings => "/embeddings",
+            Self::ImageGenerations => "/images/generations",
+            Self::ImageEdits => "/images/edits",
+            Self::ImageVariations => "/images/variations",
+            Self::AudioSpeech => "/audio/speech",
+            Self::AudioTranscriptions => "/audio/transcriptions",
+            Self::AudioTranslations => "/audio/translations",
+            Self::Moderations => "/moderations",
+            Self::Models => "/models",
+        }
+    }
+
+    /// Returns the category of the endpoint.
+    pub fn category(self) -> EndpointCategory {
+        match self {
+            Self::ChatCompletions | Self::Completions => EndpointCategory::Chat,
+            Self::ImageGenerations | Self::ImageEdits | Self::ImageVariations => {
+                EndpointCategory::Image
+            }
+            Self::AudioSpeech | Self::AudioTranscriptions | Self::AudioTranslations => {
+                EndpointCategory::Audio
+            }
+            Self::Embeddings | Self::Moderations | Self::Models => EndpointCategory::Other,
+        }
+    }
+}
+
+/// A group of endpoints that share a timeout.
+///
+/// Set with [`PortkeyBuilder::with_endpoint_timeout`](crate::builder::PortkeyBuilder::with_endpoint_timeout).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointCategory {
+    /// Chat completions and text completions.
+    Chat,
+    /// Speech synthesis, transcription and translation.
+    Audio,
+    /// Image generation, edits and variations.
+    Image,
+    /// File uploads, downloads and management.
+    Files,
+    /// Batch creation, polling and results.
+    Batch,
+    /// Every other endpoint.
+    Other,
+}
+
+impl EndpointCategory {
+    /// Returns the category of a path relative to the base URL, such as
+    /// `/chat/completions`.
+    ///
+    /// Paths remapped with a [`PathMap`] keep the category of their
+    /// endpoint, including sub-resources below them such as
+    /// `/chat/completions/{id}`.
+    pub(crate) fn for_path(path: &str, path_map: Option<&PathMap>) -> Self {
+        if let Some(endpoint) = path_map.and_then(|path_map| path_map.endpoint(path)) {
+            return endpoint.category();
+        }
+
+        let first_segment = path.trim_start_matches('/').split('/').next();
+        match first_segment.unwrap_or_default() {
+            "chat" | "completions" => Self::Chat,
+            "audio" => Self::Audio,
+            "images" => Self::Image,
+            "files" => Self::Files,
+            "batches" => Self::Batch,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Custom paths for API endpoints.
+///
+/// By default every endpoint uses its standard OpenAI-compatible path. A path
+/// map replaces the paths of selected endpoints, for gateways that lay out
+/// their routes differently. Custom paths are relative to the base URL and
+/// the [path prefix](crate::builder::PortkeyBuilder::with_path_prefix), like
+/// the standard ones.
+///
+/// # Example
+///
+/// ```no_run
+/// use portkey_sdk::PortkeyConfig;
+/// use portkey_sdk::builder::{Endpoint, PathMap};
+///
+/// let config = PortkeyConfig::builder()
+///     .with_api_key("your-api-key")
+///     .with_base_url("https://gateway.internal.example.com")
+///     .with_path_map(
+///         PathMap::new()
+///             .with_path(Endpoint::ChatCompletions, "/llm/chat")
+///             .with_path(Endpoint::Embeddings, "/llm/embed"),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PathMap {
+    paths: HashMap<Endpoint, String>,
+}
+
+impl PathMap {
+    /// Creates a map that uses the standard path for every endpoint.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the path of an endpoint.
+    pub fn with_path(mut self, endpoint: Endpoint, path: impl Into<String>) -> Self {
+        self.paths.insert(endpoint, path.into());
+        self
+    }
+
+    /// Returns the path of an endpoint.
+    pub fn path(&self, endpoint: Endpoint) -> &str {
+        self.paths
+            .get(&endpoint)
+            .map_or(endpoint.default_path(), String::as_str)
+    }
+
+    /// Returns the endpoint whose custom path is `path` or a parent of it,
+    /// preferring the longest match.
+    fn endpoint(&self, path: &str) -> Option<Endpoint> {
+        let path = path.split('?').next().unwrap_or(path);
+        self.paths
+            .iter()
+            .filter(|(_, custom)| {
+                let custom = custom.trim_end_matches('/');
+                path.strip_prefix(custom)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+            .max_by_key(|(_, custom)| custom.len())
+            .map(|(endpoint, _)| *endpoint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_category_for_path() {
+        let category = |path| EndpointCategory::for_path(path, None);
+        assert_eq!(category("/chat/completions"), EndpointCategory::Chat);
+        assert_eq!(category("/completions"), EndpointCategory::Chat);
+        assert_eq!(category("/audio/speech"), EndpointCategory::Audio);
+        assert_eq!(category("/images/generations"), EndpointCategory::Image);
+        assert_eq!(category("/files/file-abc/content"), EndpointCategory::Files);
+        assert_eq!(category("/batches/batch_abc"), EndpointCategory::Batch);
+        assert_eq!(category("/embeddings"), EndpointCategory::Other);
+        assert_eq!(
+            category("/threads/thread_abc/runs"),
+            EndpointCategory::Other
+        );
+
+        let path_map = PathMap::new()
+            .with_path(Endpoint::ChatCompletions, "/v1/chat/completions")
+            .with_path(Endpoint::Models, "/v1/models");
+        let category = |path| EndpointCategory::for_path(path, Some(&path_map));
+        assert_eq!(category("/v1/chat/completions"), EndpointCategory::Chat);
+        // A retrieve call below the remapped path keeps its category
+        assert_eq!(
+            category("/v1/chat/completions/chatcmpl-1"),
+            EndpointCategory::Chat
+        );
+        assert_eq!(
+            category("/v1/chat/completions-legacy"),
+            EndpointCategory::Other
+        );
+        assert_eq!(category("/v1/models/gpt-4o"), EndpointCategory::Other);
+    }
+}
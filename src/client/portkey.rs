@@ -3,17 +3,49 @@
 //! This module contains the main [`PortkeyClient`] struct and its implementation,
 //! providing the core HTTP client functionality for interacting with the Portkey API.
 
+use std::borrow::Cow;
 use std::fmt;
+use std::future::poll_fn;
+use std::pin::pin;
 use std::sync::Arc;
+use std::task::Poll;
+use std::time::{Duration, Instant};
 
 use reqwest::multipart::Form;
 use reqwest::{Client, Method, RequestBuilder, Response};
+use tokio::sync::{Semaphore, SemaphorePermit, mpsc};
 
 use super::auth::AuthMethod;
+#[cfg(feature = "tracing")]
+use super::auth::mask_secret;
+use super::clock::{Clock, SystemClock};
 use super::config::PortkeyConfig;
+use super::metadata::resolve_metadata;
+use super::options::{
+    RequestOptions, validate_forward_header, validate_header, validate_portkey_header,
+};
+use super::paths::{Endpoint, EndpointCategory};
+#[cfg(feature = "tracing")]
+use super::response::TRACE_ID_HEADER;
+use super::response::{Attempts, ObjectType, Paginated, ResponseExt};
+use super::stream::EventStream;
+use super::transport::HttpTransport;
 #[cfg(feature = "tracing")]
 use crate::TRACING_TARGET_CLIENT;
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::model::{ModelsCatalog, PaginationParams};
+
+/// The largest page size cursor-paginated list endpoints accept.
+const MAX_PAGE_LIMIT: usize = 100;
+
+/// Owned pagination state of a [`PortkeyClient::paginate`] stream.
+#[derive(Debug)]
+struct PageCursor {
+    limit: Option<i32>,
+    order: Option<String>,
+    after: Option<String>,
+    metadata: Option<std::collections::HashMap<String, String>>,
+}
 
 /// Main Portkey API client for interacting with all Portkey services.
 ///
@@ -23,7 +55,11 @@ use crate::error::Result;
 ///
 /// # Features
 ///
-/// - **Thread-safe**: Safe to use across multiple threads
+/// - **Thread-safe**: `Send + Sync`, and the futures returned by service
+///   methods are `Send`, so the client can be shared across spawned tasks.
+///   Every extension point, such as a custom
+///   [`HttpTransport`](crate::builder::HttpTransport), must be
+///   `Send + Sync + 'static` to keep this guarantee.
 /// - **Cheap to clone**: Uses `Arc` internally for efficient cloning
 /// - **Automatic authentication**: Handles API key authentication automatically
 ///
@@ -81,6 +117,14 @@ use crate::error::Result;
 /// # Ok(())
 /// # }
 /// ```
+///
+/// ## Shutdown
+///
+/// The client runs no background tasks and buffers nothing that needs
+/// flushing, so there is no shutdown method: dropping the last clone closes
+/// its connection pool. Each streamed response is read by its own task, which
+/// stops when the stream is dropped or fully consumed; to finish in-flight
+/// streams before exiting, drive them to the end rather than dropping them.
 #[derive(Clone)]
 pub struct PortkeyClient {
     pub(crate) inner: Arc<PortkeyClientInner>,
@@ -91,21 +135,47 @@ pub struct PortkeyClient {
 pub(crate) struct PortkeyClientInner {
     pub(crate) config: PortkeyConfig,
     pub(crate) client: Client,
+    pub(crate) transport: Arc<dyn HttpTransport>,
+    pub(crate) clock: Arc<dyn Clock>,
+    /// The cached models catalog and the instant it was fetched.
+    pub(crate) models_catalog: tokio::sync::Mutex<Option<(Instant, Arc<ModelsCatalog>)>>,
+    /// Slots for in-flight requests, if concurrency is limited.
+    pub(crate) request_permits: Option<Semaphore>,
 }
 
 impl PortkeyClient {
     /// Creates a new Portkey API client.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(config), fields(api_key = %config.masked_api_key())))]
     pub fn new(config: PortkeyConfig) -> Result<Self> {
+        Self::with_clock(config, Arc::new(SystemClock))
+    }
+
+    /// Creates a new client that reads time from the given clock.
+    ///
+    /// This is the injection point for a test clock in time-dependent tests.
+    pub(crate) fn with_clock(config: PortkeyConfig, clock: Arc<dyn Clock>) -> Result<Self> {
         #[cfg(feature = "tracing")]
         tracing::debug!(target: TRACING_TARGET_CLIENT, "Creating Portkey client");
 
         let client = if let Some(custom_client) = config.client() {
             custom_client
         } else {
-            Client::builder().timeout(config.timeout()).build()?
+            // Each request sets its own timeout; a client-wide one would also
+            // cut off streaming responses mid-body
+            let builder = Client::builder().connect_timeout(config.timeout());
+            #[cfg(feature = "danger")]
+            let builder = builder.danger_accept_invalid_certs(config.danger_accept_invalid_certs());
+            builder.build()?
         };
 
+        #[cfg(feature = "tracing")]
+        if config.danger_accept_invalid_certs() {
+            tracing::warn!(
+                target: TRACING_TARGET_CLIENT,
+                "TLS certificate verification is disabled; do not use this in production"
+            );
+        }
+
         #[cfg(feature = "tracing")]
         tracing::info!(
             target: TRACING_TARGET_CLIENT,
@@ -113,10 +183,23 @@ impl PortkeyClient {
             timeout = ?config.timeout(),
             api_key = %config.masked_api_key(),
             custom_client = config.client().is_some(),
+            custom_transport = config.transport().is_some(),
             "Portkey client created successfully"
         );
 
-        let inner = Arc::new(PortkeyClientInner { config, client });
+        let transport = config
+            .transport()
+            .unwrap_or_else(|| Arc::new(client.clone()));
+
+        let request_permits = config.max_concurrent_requests().map(Semaphore::new);
+        let inner = Arc::new(PortkeyClientInner {
+            config,
+            client,
+            transport,
+            clock,
+            models_catalog: tokio::sync::Mutex::new(None),
+            request_permits,
+        });
         Ok(Self { inner })
     }
 
@@ -173,20 +256,157 @@ impl PortkeyClient {
         Self::new(config)
     }
 
+    /// Returns the `User-Agent` header sent with every request.
+    ///
+    /// Defaults to `portkey-sdk-rust/<version>` and can be changed with
+    /// [`PortkeyBuilder::with_user_agent`](crate::builder::PortkeyBuilder::with_user_agent).
+    pub fn user_agent(&self) -> &str {
+        self.inner.config.user_agent()
+    }
+
+    /// Returns how many more requests can start before new ones wait for a
+    /// slot.
+    ///
+    /// `None` if the client does not limit concurrent requests, see
+    /// [`PortkeyBuilder::with_max_concurrent_requests`](crate::builder::PortkeyBuilder::with_max_concurrent_requests).
+    /// The count is shared by all clones of the client.
+    pub fn available_permits(&self) -> Option<usize> {
+        self.inner
+            .request_permits
+            .as_ref()
+            .map(Semaphore::available_permits)
+    }
+
+    /// Returns the full URL the client would request for a path and query parameters.
+    ///
+    /// The path is appended to the configured base URL, exactly as for API
+    /// calls made through the services. Useful for logging and for asserting
+    /// on endpoints in tests.
+    ///
+    /// # Example
+    /// ```
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::builder::AuthMethod;
+    /// # fn example() -> Result<()> {
+    /// let client = PortkeyClient::builder()
+    ///     .with_api_key("your-api-key")
+    ///     .with_auth_method(AuthMethod::virtual_key("your-virtual-key"))
+    ///     .build_client()?;
+    ///
+    /// let url = client.resolved_url("/files", &[("purpose", "batch")])?;
+    /// assert_eq!(url, "https://api.portkey.ai/v1/files?purpose=batch");
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    pub fn resolved_url(&self, path: &str, params: &[(&str, &str)]) -> Result<String> {
+        Ok(self.build_url(path, params)?.into())
+    }
+
+    /// Streams every item of a cursor-paginated list endpoint.
+    ///
+    /// Pages are fetched from `path` in the background, each one using the
+    /// `last_id` of the previous page as its `after` cursor, until a page
+    /// reports that no more items follow. The `limit`, `order`, `after` and
+    /// `metadata` of `params` apply to every page; `before` is ignored. The
+    /// stream yields the first error and then ends, and dropping it stops
+    /// fetching pages. Must be called from within a Tokio runtime.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::{ListBatchesResponse, PaginationParams};
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let params = PaginationParams::new().with_limit(100);
+    /// let mut batches = client.paginate::<ListBatchesResponse>("/batches", params);
+    /// while let Some(batch) = batches.next().await {
+    ///     println!("{}", batch?.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn paginate<P>(&self, path: &str, params: PaginationParams<'_>) -> EventStream<P::Item>
+    where
+        P: serde::de::DeserializeOwned + Paginated + Send + 'static,
+        P::Item: Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel(RequestOptions::default().stream_buffer());
+        let pages = PageCursor {
+            limit: params.limit,
+            order: params.order.map(str::to_owned),
+            after: params.after.map(str::to_owned),
+            metadata: params.metadata,
+        };
+        tokio::spawn(self.clone().pump_pages::<P>(path.to_owned(), pages, sender));
+        EventStream::new(receiver)
+    }
+
+    /// Fetches pages until the list ends, the consumer goes away, or an
+    /// error occurs, sending their items to the consumer.
+    async fn pump_pages<P>(
+        self,
+        path: String,
+        mut pages: PageCursor,
+        sender: mpsc::Sender<Result<P::Item>>,
+    ) where
+        P: serde::de::DeserializeOwned + Paginated,
+    {
+        loop {
+            let params = PaginationParams {
+                limit: pages.limit,
+                order: pages.order.as_deref(),
+                after: pages.after.as_deref(),
+                before: None,
+                metadata: pages.metadata.clone(),
+            };
+            let page = match self.get_paginated::<P>(&path, &params).await {
+                Ok(page) => page,
+                Err(e) => {
+                    let _ = sender.send(Err(e)).await;
+                    return;
+                }
+            };
+
+            pages.after = page.next_cursor();
+            for item in page.into_items() {
+                if sender.send(Ok(item)).await.is_err() {
+                    return;
+                }
+            }
+            if pages.after.is_none() {
+                return;
+            }
+        }
+    }
+
     /// Applies Portkey-specific headers to a request builder.
     ///
     /// This method adds all required and optional Portkey headers to the request.
-    /// If metadata serialization fails, it logs a warning and continues without the metadata header.
+    /// If metadata serialization fails, it logs a warning and continues without the metadata header,
+    /// unless strict metadata is enabled. Fails if the metadata is rejected by the configured
+    /// [`MetadataPolicy`](crate::builder::MetadataPolicy). Request options take
+    /// precedence over the client configuration.
     #[cfg_attr(
         feature = "tracing",
-        tracing::instrument(skip(self, builder), fields(auth_method))
+        tracing::instrument(skip(self, builder, options), fields(auth_method))
     )]
-    fn apply_portkey_headers(&self, mut builder: RequestBuilder) -> RequestBuilder {
-        // Always add the Portkey API key
-        builder = builder.header("x-portkey-api-key", self.inner.config.api_key());
+    fn apply_portkey_headers(
+        &self,
+        mut builder: RequestBuilder,
+        options: &RequestOptions,
+    ) -> Result<RequestBuilder> {
+        // Add the Portkey API key unless disabled for pass-through gateways
+        if self.inner.config.send_api_key() {
+            builder = builder.header("x-portkey-api-key", self.inner.config.api_key());
+        }
 
-        // Add authentication method headers
-        match self.inner.config.auth_method() {
+        // Add authentication method headers, preferring the request's override
+        let auth_method = options
+            .auth_method()
+            .unwrap_or(self.inner.config.auth_method());
+        let mut saved_config = None;
+        match auth_method {
             AuthMethod::VirtualKey { virtual_key } => {
                 #[cfg(feature = "tracing")]
                 tracing::trace!(target: TRACING_TARGET_CLIENT, "Using virtual key authentication");
@@ -211,41 +431,63 @@ impl PortkeyClient {
                 #[cfg(feature = "tracing")]
                 tracing::trace!(target: TRACING_TARGET_CLIENT, config_id = %config_id, "Using config-based authentication");
 
-                builder = builder.header("x-portkey-config", config_id);
+                saved_config = Some(config_id.as_str());
             }
         }
 
-        // Add optional headers
-        if let Some(trace_id) = self.inner.config.trace_id() {
+        if let Some(config) = options.resolve_config(saved_config)? {
+            builder = builder.header("x-portkey-config", config);
+        }
+
+        // Add optional headers, preferring the request's overrides
+        let trace_id = options.trace_id().or(self.inner.config.trace_id());
+        if let Some(trace_id) = trace_id {
             #[cfg(feature = "tracing")]
             tracing::trace!(target: TRACING_TARGET_CLIENT, trace_id = %trace_id, "Adding trace ID");
 
             builder = builder.header("x-portkey-trace-id", trace_id);
         }
 
-        if let Some(metadata) = self.inner.config.metadata() {
-            match serde_json::to_string(metadata) {
-                Ok(metadata_json) => {
-                    #[cfg(feature = "tracing")]
-                    tracing::trace!(target: TRACING_TARGET_CLIENT, "Adding metadata header");
+        let metadata = match (self.inner.config.metadata(), options.metadata()) {
+            (metadata, None) => metadata.map(Cow::Borrowed),
+            (base, Some(overrides)) => {
+                let mut metadata = base.cloned().unwrap_or_default();
+                metadata.extend(overrides.clone());
+                Some(Cow::Owned(metadata))
+            }
+        };
+        if let Some(metadata) = metadata.as_deref() {
+            let encoded = match self.inner.config.metadata_policy() {
+                Some(policy) => policy.encode(metadata),
+                None => serde_json::to_string(metadata)
+                    .map(Some)
+                    .map_err(Into::into),
+            };
+
+            if let Some(metadata_json) =
+                resolve_metadata(encoded, self.inner.config.strict_metadata())?
+            {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(target: TRACING_TARGET_CLIENT, "Adding metadata header");
 
-                    builder = builder.header("x-portkey-metadata", metadata_json);
-                }
-                Err(_e) => {
-                    #[cfg(feature = "tracing")]
-                    tracing::warn!(target: TRACING_TARGET_CLIENT, error = %_e, "Failed to serialize metadata, skipping header");
-                }
+                builder = builder.header("x-portkey-metadata", metadata_json);
             }
         }
 
-        if let Some(cache_namespace) = self.inner.config.cache_namespace() {
+        let cache_namespace = options
+            .cache_namespace()
+            .or(self.inner.config.cache_namespace());
+        if let Some(cache_namespace) = cache_namespace {
             #[cfg(feature = "tracing")]
             tracing::trace!(target: TRACING_TARGET_CLIENT, cache_namespace = %cache_namespace, "Adding cache namespace");
 
             builder = builder.header("x-portkey-cache-namespace", cache_namespace);
         }
 
-        if let Some(cache_force_refresh) = self.inner.config.cache_force_refresh() {
+        let cache_force_refresh = options
+            .cache_force_refresh()
+            .or(self.inner.config.cache_force_refresh());
+        if let Some(cache_force_refresh) = cache_force_refresh {
             #[cfg(feature = "tracing")]
             tracing::trace!(target: TRACING_TARGET_CLIENT, cache_force_refresh, "Adding cache force refresh");
 
@@ -255,13 +497,66 @@ impl PortkeyClient {
             );
         }
 
-        builder
+        builder = builder.header(
+            "x-portkey-strict-open-ai-compliance",
+            self.inner.config.strict_openai_compliance().to_string(),
+        );
+
+        // Add custom Portkey headers, preferring the request's over the client's
+        for (name, value) in options.portkey_headers() {
+            validate_portkey_header(name, value).map_err(Error::Validation)?;
+        }
+        let client_headers = self
+            .inner
+            .config
+            .portkey_headers()
+            .iter()
+            .filter(|(name, _)| !options.portkey_headers().iter().any(|(n, _)| n == name));
+        for (name, value) in client_headers.chain(options.portkey_headers()) {
+            builder = builder.header(name, value);
+        }
+
+        // Add headers forwarded to the provider, with the manifest listing them
+        for (name, value) in options.forward_headers() {
+            validate_forward_header(name, value).map_err(Error::Validation)?;
+            builder = builder.header(name, value);
+        }
+        if let Some(manifest) = options.forward_headers_manifest() {
+            builder = builder.header("x-portkey-forward-headers", manifest);
+        }
+
+        // Add extra headers meant for the gateway only
+        for (name, value) in options.headers() {
+            validate_header(name, value).map_err(Error::Validation)?;
+            builder = builder.header(name, value);
+        }
+
+        Ok(builder)
     }
 
-    /// Parses the base URL and appends the given path.
+    /// Returns the path of an endpoint, after applying the configured path map.
+    pub(crate) fn endpoint_path(&self, endpoint: Endpoint) -> &str {
+        match self.inner.config.path_map() {
+            Some(path_map) => path_map.path(endpoint),
+            None => endpoint.default_path(),
+        }
+    }
+
+    /// Parses the base URL and appends the path prefix, if any, and the
+    /// given path.
     fn parse_url(&self, path: &str) -> Result<url::Url> {
         let mut url = url::Url::parse(self.inner.config.base_url())?;
-        url.set_path(&format!("{}{}", url.path().trim_end_matches('/'), path));
+        let mut full_path = url.path().trim_end_matches('/').to_string();
+        if let Some(prefix) = self.inner.config.path_prefix() {
+            let prefix = prefix.trim_matches('/');
+            if !prefix.is_empty() {
+                full_path.push('/');
+                full_path.push_str(prefix);
+            }
+        }
+        full_path.push('/');
+        full_path.push_str(path.trim_start_matches('/'));
+        url.set_path(&full_path);
         Ok(url)
     }
 
@@ -277,7 +572,16 @@ impl PortkeyClient {
     }
 
     /// Creates an HTTP request with the specified method.
-    fn request(&self, method: Method, url: url::Url) -> RequestBuilder {
+    ///
+    /// `path` is the path relative to the base URL that `url` was built from,
+    /// which selects the endpoint timeout.
+    fn request(
+        &self,
+        method: Method,
+        url: url::Url,
+        path: &str,
+        options: &RequestOptions,
+    ) -> Result<RequestBuilder> {
         #[cfg(feature = "tracing")]
         tracing::trace!(
             target: TRACING_TARGET_CLIENT,
@@ -286,20 +590,51 @@ impl PortkeyClient {
             "Creating HTTP request"
         );
 
+        let timeout = match (options.deadline(), options.timeout()) {
+            (Some(deadline), timeout) => {
+                let remaining = deadline.saturating_duration_since(self.inner.clock.now());
+                if remaining.is_zero() {
+                    return Err(Error::DeadlineExceeded);
+                }
+                timeout.map_or(remaining, |timeout| timeout.min(remaining))
+            }
+            (None, Some(timeout)) => timeout,
+            (None, None) => self
+                .inner
+                .config
+                .endpoint_timeout(EndpointCategory::for_path(
+                    path,
+                    self.inner.config.path_map(),
+                )),
+        };
+
         let builder = self
             .inner
             .client
             .request(method, url)
-            .timeout(self.inner.config.timeout());
+            .timeout(timeout)
+            .header(reqwest::header::USER_AGENT, self.inner.config.user_agent())
+            .header(reqwest::header::ACCEPT, options.accept());
 
-        self.apply_portkey_headers(builder)
+        self.apply_portkey_headers(builder, options)
     }
 
     /// Sends a GET request and returns the response.
     pub(crate) async fn send(&self, method: Method, path: &str) -> Result<Response> {
+        self.send_with_options(method, path, &RequestOptions::default())
+            .await
+    }
+
+    /// Sends a request without a body and with per-request options.
+    pub(crate) async fn send_with_options(
+        &self,
+        method: Method,
+        path: &str,
+        options: &RequestOptions,
+    ) -> Result<Response> {
         let url = self.parse_url(path)?;
-        let response = self.request(method, url).send().await?;
-        Ok(response)
+        let builder = self.request(method, url, path, options)?;
+        self.execute_with_options(builder, options).await
     }
 
     /// Sends a request with JSON body.
@@ -308,10 +643,30 @@ impl PortkeyClient {
         method: Method,
         path: &str,
         data: &T,
+    ) -> Result<Response> {
+        self.send_json_with_options(method, path, data, &RequestOptions::default())
+            .await
+    }
+
+    /// Sends a request with JSON body and per-request options.
+    pub(crate) async fn send_json_with_options<T: serde::Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        data: &T,
+        options: &RequestOptions,
     ) -> Result<Response> {
         let url = self.parse_url(path)?;
-        let response = self.request(method, url).json(data).send().await?;
-        Ok(response)
+        let builder = self.request(method, url, path, options)?;
+        let builder = match self.inner.config.user_hasher() {
+            Some(hasher) => {
+                let mut body = serde_json::to_value(data)?;
+                hasher.apply(&mut body);
+                builder.json(&body)
+            }
+            None => builder.json(data),
+        };
+        self.execute_with_options(builder, options).await
     }
 
     /// Sends a request with query parameters.
@@ -322,8 +677,69 @@ impl PortkeyClient {
         params: &[(&str, &str)],
     ) -> Result<Response> {
         let url = self.build_url(path, params)?;
-        let response = self.request(method, url).send().await?;
-        Ok(response)
+        let options = RequestOptions::default();
+        let builder = self.request(method, url, path, &options)?;
+        self.execute_with_options(builder, &options).await
+    }
+
+    /// Fetches a page from a paginated list endpoint.
+    pub(crate) async fn get_paginated<T>(
+        &self,
+        path: &str,
+        params: &PaginationParams<'_>,
+    ) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned + ObjectType,
+    {
+        let query_params = params.to_query_params();
+        let query_params: Vec<(&str, &str)> = query_params
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+
+        let response = self
+            .send_with_params(Method::GET, path, &query_params)
+            .await?
+            .error_for_api_status()
+            .await?;
+        self.json_object(response).await
+    }
+
+    /// Collects the items of a cursor-paginated list endpoint.
+    ///
+    /// Fetches pages until the list ends or `max_items` items are collected.
+    /// Page sizes shrink to the number of items still needed, so no page is
+    /// fetched beyond the cap.
+    pub(crate) async fn get_all_pages<P>(
+        &self,
+        path: &str,
+        max_items: Option<usize>,
+    ) -> Result<Vec<P::Item>>
+    where
+        P: serde::de::DeserializeOwned + Paginated,
+    {
+        let max_items = max_items.unwrap_or(usize::MAX);
+        let mut items = Vec::new();
+        let mut after: Option<String> = None;
+
+        while items.len() < max_items {
+            let limit = (max_items - items.len()).min(MAX_PAGE_LIMIT);
+            let mut params = PaginationParams::new().with_limit(limit as i32);
+            if let Some(after) = after.as_deref() {
+                params = params.with_after(after);
+            }
+
+            let page = self.get_paginated::<P>(path, &params).await?;
+            let next = page.next_cursor();
+            items.extend(page.into_items());
+            match next {
+                Some(next) => after = Some(next),
+                None => break,
+            }
+        }
+
+        items.truncate(max_items);
+        Ok(items)
     }
 
     /// Sends a request with multipart form data.
@@ -332,20 +748,387 @@ impl PortkeyClient {
         method: Method,
         path: &str,
         form: Form,
+    ) -> Result<Response> {
+        self.send_multipart_with_options(method, path, form, &RequestOptions::default())
+            .await
+    }
+
+    /// Sends a request with multipart form data and per-request options.
+    pub(crate) async fn send_multipart_with_options(
+        &self,
+        method: Method,
+        path: &str,
+        form: Form,
+        options: &RequestOptions,
     ) -> Result<Response> {
         let url = self.parse_url(path)?;
-        let response = self.request(method, url).multipart(form).send().await?;
-        Ok(response)
+        let builder = self.request(method, url, path, options)?.multipart(form);
+        self.execute_with_options(builder, options).await
+    }
+
+    /// Executes the request with the per-request options, retrying it under
+    /// the client's retry policy.
+    ///
+    /// For a streamed response, the timeout only bounds the wait for the
+    /// headers, so a long stream is not cut off mid-body.
+    ///
+    /// The number of attempts is recorded in the response extensions, so
+    /// [`ResponseExt::error_for_api_status`] can report it.
+    async fn execute_with_options(
+        &self,
+        builder: RequestBuilder,
+        options: &RequestOptions,
+    ) -> Result<Response> {
+        let mut request = builder.build()?;
+        if options.streaming() {
+            // The timeout covers the wait for the response headers, which is
+            // all a call returning a stream waits for, but not the body
+            if let Some(timeout) = request.timeout_mut().take() {
+                let mut response = pin!(self.execute_repeatable(request, options));
+                let mut expired = self.inner.clock.sleep(timeout);
+                return poll_fn(|cx| match response.as_mut().poll(cx) {
+                    Poll::Ready(result) => Poll::Ready(result),
+                    Poll::Pending => expired
+                        .as_mut()
+                        .poll(cx)
+                        .map(|()| Err(Error::DeadlineExceeded)),
+                })
+                .await;
+            }
+        }
+        self.execute_repeatable(request, options).await
+    }
+
+    /// Executes the request, retrying and hedging it if that is safe.
+    async fn execute_repeatable(
+        &self,
+        mut request: reqwest::Request,
+        options: &RequestOptions,
+    ) -> Result<Response> {
+        // Only requests that are safe to send twice are hedged or retried
+        let repeatable = request.method().is_idempotent() || options.idempotent();
+        let mut hedge = options.hedge().filter(|_| repeatable);
+        let policy = self.inner.config.retry_policy().filter(|_| repeatable);
+        let Some(policy) = policy else {
+            return self.execute_attempt(request, hedge).await;
+        };
+
+        // Retries share the timeout of the call rather than each getting it anew
+        let budget = request.timeout().copied();
+        let start = self.inner.clock.now();
+        let mut attempt = 1;
+        loop {
+            let retry = request
+                .try_clone()
+                .filter(|_| attempt <= policy.max_retries());
+            // Hedge at most once per call, not once per attempt
+            let mut response = self.execute_attempt(request, hedge.take()).await?;
+            let Some(next) = retry.filter(|_| is_retryable_status(response.status())) else {
+                response.extensions_mut().insert(Attempts(attempt));
+                return Ok(response);
+            };
+
+            let delay = retry_after(&response)
+                .map(|delay| delay.min(policy.max_backoff()))
+                .unwrap_or_else(|| policy.backoff(attempt));
+            let remaining = budget.map(|budget| {
+                let elapsed = self.inner.clock.now().saturating_duration_since(start);
+                budget.saturating_sub(elapsed)
+            });
+            if remaining.is_some_and(|remaining| delay >= remaining) {
+                return Err(Error::DeadlineExceeded);
+            }
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                target: TRACING_TARGET_CLIENT,
+                status = %response.status(),
+                attempt,
+                delay_ms = delay.as_millis() as u64,
+                "Retrying request"
+            );
+            drop(response);
+
+            self.inner.clock.sleep(delay).await;
+            request = next;
+            if let (Some(remaining), Some(timeout)) = (remaining, request.timeout_mut()) {
+                *timeout = remaining - delay;
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Executes one attempt of a request, hedging it after `hedge` if set.
+    async fn execute_attempt(
+        &self,
+        request: reqwest::Request,
+        hedge: Option<Duration>,
+    ) -> Result<Response> {
+        match hedge {
+            Some(after) => self.execute_hedged(request, after).await,
+            None => self.execute_request(request).await,
+        }
+    }
+
+    /// Executes the request and, if it has not completed after `after`, a
+    /// copy of it.
+    ///
+    /// The first successful response wins and the other request is cancelled
+    /// by dropping its future. A failure, either a transport error or a
+    /// server error status, is only returned once both requests have
+    /// finished, preferring a response over a transport error, so a hedge
+    /// that fails fast does not cancel a healthy primary. Requests with a
+    /// body that cannot be cloned are sent once.
+    async fn execute_hedged(&self, request: reqwest::Request, after: Duration) -> Result<Response> {
+        let Some(hedge) = request.try_clone() else {
+            return self.execute_request(request).await;
+        };
+
+        let mut primary = pin!(self.execute_request(request));
+        let mut delay = self.inner.clock.sleep(after);
+        let result = poll_fn(|cx| match primary.as_mut().poll(cx) {
+            Poll::Ready(result) => Poll::Ready(Some(result)),
+            Poll::Pending => delay.as_mut().poll(cx).map(|()| None),
+        })
+        .await;
+        if let Some(result) = result {
+            return result;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_CLIENT,
+            after_ms = after.as_millis() as u64,
+            "Sending hedged request"
+        );
+
+        let mut hedge = pin!(self.execute_request(hedge));
+        let mut primary_result = None;
+        let mut hedge_result = None;
+        poll_fn(|cx| {
+            if primary_result.is_none()
+                && let Poll::Ready(result) = primary.as_mut().poll(cx)
+            {
+                if is_hedge_success(&result) {
+                    return Poll::Ready(result);
+                }
+                primary_result = Some(result);
+            }
+            if hedge_result.is_none()
+                && let Poll::Ready(result) = hedge.as_mut().poll(cx)
+            {
+                if is_hedge_success(&result) {
+                    return Poll::Ready(result);
+                }
+                hedge_result = Some(result);
+            }
+
+            match (primary_result.take(), hedge_result.take()) {
+                (Some(primary), Some(hedge)) => Poll::Ready(match primary {
+                    Ok(_) => primary,
+                    Err(_) => hedge,
+                }),
+                (primary, hedge) => {
+                    primary_result = primary;
+                    hedge_result = hedge;
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+
+    /// Waits for a slot if the client limits concurrent requests.
+    ///
+    /// The wait is taken out of the request timeout. If no slot frees up
+    /// before the timeout, fails with [`Error::DeadlineExceeded`].
+    async fn acquire_permit(
+        &self,
+        request: &mut reqwest::Request,
+    ) -> Result<Option<SemaphorePermit<'_>>> {
+        let Some(permits) = &self.inner.request_permits else {
+            return Ok(None);
+        };
+        if let Ok(permit) = permits.try_acquire() {
+            return Ok(Some(permit));
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_CLIENT,
+            "Waiting for a concurrent request slot"
+        );
+
+        let start = self.inner.clock.now();
+        let mut acquire = pin!(permits.acquire());
+        let permit = match request.timeout().copied() {
+            Some(timeout) => {
+                let mut delay = self.inner.clock.sleep(timeout);
+                let permit = poll_fn(|cx| match acquire.as_mut().poll(cx) {
+                    Poll::Ready(permit) => Poll::Ready(Some(permit)),
+                    Poll::Pending => delay.as_mut().poll(cx).map(|()| None),
+                })
+                .await;
+                permit.ok_or(Error::DeadlineExceeded)?
+            }
+            None => acquire.await,
+        };
+        // The semaphore is owned by the client and never closed
+        let permit = permit.expect("request semaphore closed");
+
+        if let Some(timeout) = request.timeout_mut() {
+            let waited = self.inner.clock.now().saturating_duration_since(start);
+            *timeout = timeout.saturating_sub(waited);
+        }
+        Ok(Some(permit))
+    }
+
+    /// Builds the request and executes it with the configured transport.
+    pub(crate) async fn execute(&self, builder: RequestBuilder) -> Result<Response> {
+        self.execute_request(builder.build()?).await
+    }
+
+    /// Executes the request with the configured transport.
+    #[cfg(not(feature = "tracing"))]
+    async fn execute_request(&self, mut request: reqwest::Request) -> Result<Response> {
+        let _permit = self.acquire_permit(&mut request).await?;
+        self.inner.transport.execute(request).await
+    }
+
+    /// Executes the request with the configured transport.
+    ///
+    /// The request runs in a `portkey_request` span that records the method,
+    /// path and masked credentials up front, and the trace ID, status and
+    /// latency once the response arrives, so a single span links both ends
+    /// of the call.
+    #[cfg(feature = "tracing")]
+    async fn execute_request(&self, mut request: reqwest::Request) -> Result<Response> {
+        use tracing::Instrument;
+        use tracing::field::Empty;
+
+        let _permit = self.acquire_permit(&mut request).await?;
+        let span = tracing::info_span!(
+            target: TRACING_TARGET_CLIENT,
+            "portkey_request",
+            method = %request.method(),
+            path = %request.url().path(),
+            auth = %masked_auth(request.headers()),
+            trace_id = Empty,
+            status = Empty,
+            latency_ms = Empty,
+            error = Empty,
+        );
+        let request_trace_id = header_value(request.headers(), TRACE_ID_HEADER).map(str::to_owned);
+
+        let start = self.inner.clock.now();
+        let result = self
+            .inner
+            .transport
+            .execute(request)
+            .instrument(span.clone())
+            .await;
+        let latency = self.inner.clock.now().saturating_duration_since(start);
+
+        span.record("latency_ms", latency.as_millis() as u64);
+        match &result {
+            Ok(response) => {
+                let trace_id = header_value(response.headers(), TRACE_ID_HEADER)
+                    .or(request_trace_id.as_deref());
+                if let Some(trace_id) = trace_id {
+                    span.record("trace_id", trace_id);
+                }
+                span.record("status", response.status().as_u16());
+            }
+            Err(error) => {
+                span.record("error", tracing::field::display(error));
+            }
+        }
+
+        span.in_scope(|| {
+            tracing::debug!(target: TRACING_TARGET_CLIENT, "Request completed");
+        });
+
+        result
+    }
+
+    /// Fills an empty request `model` with the configured default model.
+    ///
+    /// Fails if the request has no model and no default model is configured.
+    pub(crate) fn resolve_model(&self, model: &mut String) -> Result<()> {
+        if !model.is_empty() {
+            return Ok(());
+        }
+
+        match self.inner.config.default_model() {
+            Some(default_model) => {
+                model.push_str(default_model);
+                Ok(())
+            }
+            None => Err(Error::Validation(
+                "request has no model and no default model is configured".to_string(),
+            )),
+        }
+    }
+
+    /// Fills unset `max_tokens` and `temperature` with the configured
+    /// generation defaults. Values already set on the request are kept.
+    pub(crate) fn apply_generation_defaults<T>(
+        &self,
+        max_tokens: &mut Option<T>,
+        temperature: &mut Option<f32>,
+    ) where
+        T: TryFrom<u32>,
+    {
+        let config = &self.inner.config;
+        if max_tokens.is_none() {
+            *max_tokens = config
+                .default_max_tokens()
+                .and_then(|value| T::try_from(value).ok());
+        }
+        if temperature.is_none() {
+            *temperature = config.default_temperature();
+        }
+    }
+
+    /// Deserializes a JSON response, checking its `object` type if strict
+    /// response checking is enabled.
+    pub(crate) async fn json_object<T>(&self, response: Response) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned + ObjectType,
+    {
+        let value: T = response.json().await?;
+        if self.inner.config.strict_response_objects() {
+            value.ensure_object()?;
+        }
+        Ok(value)
     }
 
     /// Creates a request builder for custom query parameter building.
     /// Use this for complex query scenarios that need conditional parameters.
     pub(crate) fn request_builder(&self, method: Method, path: &str) -> Result<RequestBuilder> {
         let url = self.parse_url(path)?;
-        Ok(self.request(method, url))
+        self.request(method, url, path, &RequestOptions::default())
     }
 }
 
+/// Returns whether a leg of a hedged request can win the race.
+fn is_hedge_success(result: &Result<Response>) -> bool {
+    result
+        .as_ref()
+        .is_ok_and(|response| !response.status().is_server_error())
+}
+
+/// Returns whether a response status is worth retrying.
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Returns the wait requested by a `Retry-After` header in seconds, if any.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(reqwest::header::RETRY_AFTER)?;
+    let secs: f64 = value.to_str().ok()?.trim().parse().ok()?;
+    Duration::try_from_secs_f64(secs).ok()
+}
+
 impl fmt::Debug for PortkeyClient {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("PortkeyClient")
@@ -356,11 +1139,37 @@ impl fmt::Debug for PortkeyClient {
     }
 }
 
+/// Returns a header value as a string, if present and valid.
+#[cfg(feature = "tracing")]
+fn header_value<'a>(headers: &'a reqwest::header::HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+/// Describes the credentials a request carries, with secrets masked.
+#[cfg(feature = "tracing")]
+fn masked_auth(headers: &reqwest::header::HeaderMap) -> String {
+    if let Some(virtual_key) = header_value(headers, "x-portkey-virtual-key") {
+        return format!("virtual_key:{}", mask_secret(virtual_key));
+    }
+    if let Some(config) = header_value(headers, "x-portkey-config") {
+        // Inline configs can embed provider keys, so only IDs are shown.
+        if config.starts_with('{') {
+            return "config:inline".to_string();
+        }
+        return format!("config:{config}");
+    }
+    if let Some(provider) = header_value(headers, "x-portkey-provider") {
+        return format!("provider:{provider}");
+    }
+    "none".to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
 
     use super::*;
+    use crate::GatewayConfig;
 
     fn create_test_config() -> PortkeyConfig {
         PortkeyConfig::builder()
@@ -372,6 +1181,44 @@ mod tests {
             .unwrap()
     }
 
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[test]
+    fn test_send_sync() -> Result<()> {
+        use crate::model::{
+            ChatCompletionChunk, ChatCompletionRequest, ChatCompletionRequestMessage,
+            CreateSpeechRequest, Voice,
+        };
+        use crate::service::{AudioService, ChatService, ModelsService};
+        use crate::{ByteStream, EventStream};
+
+        assert_send_sync::<PortkeyClient>();
+        assert_send_sync::<PortkeyConfig>();
+        assert_send_sync::<RequestOptions>();
+        assert_send_sync::<Error>();
+        assert_send_sync::<ByteStream>();
+        assert_send_sync::<EventStream<ChatCompletionChunk>>();
+
+        let client = PortkeyClient::new(create_test_config())?;
+        let request =
+            ChatCompletionRequest::new("gpt-4o", vec![ChatCompletionRequestMessage::user("Hi")]);
+        assert_send(&client.create_chat_completion(request.clone()));
+        assert_send(&client.create_chat_completion_stream(request));
+        assert_send(&client.list_models(None));
+        assert_send(&client.models_catalog(Duration::from_secs(60)));
+        assert_send(&client.create_speech_long(CreateSpeechRequest {
+            model: "tts-1".to_string(),
+            input: "Hello".to_string(),
+            voice: Voice::Alloy,
+            response_format: None,
+            speed: None,
+        }));
+
+        Ok(())
+    }
+
     #[test]
     fn test_client_creation() -> Result<()> {
         let config = create_test_config();
@@ -383,24 +1230,571 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_client_creation_with_custom_config() -> Result<()> {
-        let config = PortkeyConfig::builder()
-            .with_api_key("custom_key")
-            .with_auth_method(AuthMethod::ProviderAuth {
-                provider: "openai".to_string(),
-                authorization: "Bearer sk-test".to_string(),
-                custom_host: None,
-            })
-            .with_base_url("https://custom.api.com")
-            .with_timeout(Duration::from_secs(60))
-            .build()?;
+    #[tokio::test]
+    async fn test_request_deadline() -> Result<()> {
+        use super::super::clock::TestClock;
 
-        let client = PortkeyClient::new(config)?;
+        let clock = Arc::new(TestClock::new());
+        let client = PortkeyClient::with_clock(create_test_config(), clock.clone())?;
+        let options = RequestOptions::new().with_deadline(clock.now() + Duration::from_secs(5));
 
-        assert_eq!(client.inner.config.api_key(), "custom_key");
-        assert_eq!(client.inner.config.base_url(), "https://custom.api.com");
-        assert_eq!(client.inner.config.timeout(), Duration::from_secs(60));
+        let url = client.parse_url("/models")?;
+        let request = client
+            .request(Method::GET, url.clone(), "/models", &options)?
+            .build()?;
+        assert_eq!(request.timeout(), Some(&Duration::from_secs(5)));
+
+        clock.advance(Duration::from_secs(3));
+        let request = client
+            .request(Method::GET, url.clone(), "/models", &options)?
+            .build()?;
+        assert_eq!(request.timeout(), Some(&Duration::from_secs(2)));
+
+        clock.advance(Duration::from_secs(2));
+        let error = client
+            .request(Method::GET, url, "/models", &options)
+            .unwrap_err();
+        assert!(matches!(error, Error::DeadlineExceeded));
+        assert!(error.is_timeout());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_client_with_test_clock() -> Result<()> {
+        use super::super::clock::TestClock;
+
+        let clock = Arc::new(TestClock::new());
+        let client = PortkeyClient::with_clock(create_test_config(), clock.clone())?;
+
+        let start = client.inner.clock.now();
+        client.inner.clock.sleep(Duration::from_secs(60)).await;
+
+        assert_eq!(client.inner.clock.now() - start, Duration::from_secs(60));
+        assert_eq!(clock.elapsed(), Duration::from_secs(60));
+
+        Ok(())
+    }
+
+    /// Leaves the first request pending and answers later ones, recording
+    /// whether the pending request was cancelled.
+    #[derive(Debug, Default)]
+    struct SlowFirstTransport {
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+        cancelled: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    struct CancelGuard(Arc<std::sync::atomic::AtomicBool>);
+
+    impl Drop for CancelGuard {
+        fn drop(&mut self) {
+            self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    impl HttpTransport for SlowFirstTransport {
+        fn execute(&self, _: reqwest::Request) -> crate::builder::TransportFuture<'_> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if call == 0 {
+                let guard = CancelGuard(self.cancelled.clone());
+                return Box::pin(async move {
+                    let _guard = guard;
+                    std::future::pending().await
+                });
+            }
+            Box::pin(async move {
+                let response = http::Response::builder()
+                    .status(200)
+                    .body(format!("hedge {call}"))
+                    .unwrap();
+                Ok(Response::from(response))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hedged_request() -> Result<()> {
+        use super::super::clock::TestClock;
+
+        let transport = SlowFirstTransport::default();
+        let (calls, cancelled) = (transport.calls.clone(), transport.cancelled.clone());
+        let config = PortkeyConfig::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_transport(transport)
+            .build()?;
+        let clock = Arc::new(TestClock::new());
+        let client = PortkeyClient::with_clock(config, clock.clone())?;
+
+        let options = RequestOptions::new()
+            .with_hedge(Duration::from_millis(300))
+            .with_idempotent(true);
+        let response = client
+            .send_json_with_options(
+                Method::POST,
+                "/chat/completions",
+                &serde_json::json!({}),
+                &options,
+            )
+            .await?;
+
+        assert_eq!(response.text().await?, "hedge 1");
+        assert_eq!(clock.elapsed(), Duration::from_millis(300));
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert!(cancelled.load(std::sync::atomic::Ordering::SeqCst));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_hedge_skips_non_idempotent_requests() -> Result<()> {
+        let transport = SequenceTransport::new(vec![(200, None)]);
+        let calls = transport.calls.clone();
+        let config = PortkeyConfig::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_transport(transport)
+            .build()?;
+        let clock = Arc::new(super::super::clock::TestClock::new());
+        let client = PortkeyClient::with_clock(config, clock.clone())?;
+
+        let options = RequestOptions::new().with_hedge(Duration::ZERO);
+        client
+            .send_json_with_options(
+                Method::POST,
+                "/chat/completions",
+                &serde_json::json!({}),
+                &options,
+            )
+            .await?;
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+
+        Ok(())
+    }
+
+    /// Answers the first request after a short delay and every later one
+    /// immediately with the given status.
+    #[derive(Debug)]
+    struct SlowPrimaryTransport {
+        primary_status: u16,
+        hedge_status: u16,
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl SlowPrimaryTransport {
+        fn new(primary_status: u16, hedge_status: u16) -> Self {
+            Self {
+                primary_status,
+                hedge_status,
+                calls: Arc::default(),
+            }
+        }
+    }
+
+    impl HttpTransport for SlowPrimaryTransport {
+        fn execute(&self, _: reqwest::Request) -> crate::builder::TransportFuture<'_> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let (status, delay) = match call % 2 {
+                0 => (self.primary_status, Duration::from_millis(20)),
+                _ => (self.hedge_status, Duration::ZERO),
+            };
+            Box::pin(async move {
+                tokio::time::sleep(delay).await;
+                let response = http::Response::builder()
+                    .status(status)
+                    .body(format!("call {call}"))
+                    .unwrap();
+                Ok(Response::from(response))
+            })
+        }
+    }
+
+    fn hedging_client(
+        transport: SlowPrimaryTransport,
+        retry_policy: Option<crate::builder::RetryPolicy>,
+    ) -> Result<PortkeyClient> {
+        let mut builder = PortkeyConfig::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_transport(transport);
+        if let Some(retry_policy) = retry_policy {
+            builder = builder.with_retry_policy(retry_policy);
+        }
+        let clock = Arc::new(super::super::clock::TestClock::new());
+        PortkeyClient::with_clock(builder.build()?, clock)
+    }
+
+    #[tokio::test]
+    async fn test_hedge_failure_keeps_primary() -> Result<()> {
+        // The hedge fails first, but the primary still succeeds
+        let client = hedging_client(SlowPrimaryTransport::new(200, 503), None)?;
+        let options = RequestOptions::new().with_hedge(Duration::ZERO);
+        let response = client
+            .send_with_options(Method::GET, "/models", &options)
+            .await?;
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.text().await?, "call 0");
+
+        // With both failing, the result waits for both legs
+        let client = hedging_client(SlowPrimaryTransport::new(500, 503), None)?;
+        let response = client
+            .send_with_options(Method::GET, "/models", &options)
+            .await?;
+        assert_eq!(response.status(), 500);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_hedge_once_per_call() -> Result<()> {
+        use crate::builder::RetryPolicy;
+
+        let transport = SlowPrimaryTransport::new(503, 503);
+        let calls = transport.calls.clone();
+        let policy = RetryPolicy::new(1).with_jitter(false);
+        let client = hedging_client(transport, Some(policy))?;
+
+        let options = RequestOptions::new().with_hedge(Duration::ZERO);
+        let response = client
+            .send_with_options(Method::GET, "/models", &options)
+            .await?;
+        assert_eq!(response.status(), 503);
+        // Two legs for the first attempt and one for the retry
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        Ok(())
+    }
+
+    /// Answers requests after a short delay, recording the most requests
+    /// seen in flight at once.
+    #[derive(Debug, Default)]
+    struct InFlightTransport {
+        in_flight: Arc<std::sync::atomic::AtomicUsize>,
+        max_in_flight: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl HttpTransport for InFlightTransport {
+        fn execute(&self, _: reqwest::Request) -> crate::builder::TransportFuture<'_> {
+            use std::sync::atomic::Ordering;
+
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+                let response = http::Response::builder().status(200).body("{}").unwrap();
+                Ok(Response::from(response))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_requests() -> Result<()> {
+        let transport = InFlightTransport::default();
+        let max_in_flight = transport.max_in_flight.clone();
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_max_concurrent_requests(2usize)
+            .with_transport(transport)
+            .build_client()?;
+        assert_eq!(client.available_permits(), Some(2));
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for _ in 0..6 {
+            let client = client.clone();
+            tasks.spawn(async move { client.send(Method::GET, "/models").await });
+        }
+        while let Some(result) = tasks.join_next().await {
+            assert_eq!(result.unwrap()?.status(), 200);
+        }
+
+        assert_eq!(max_in_flight.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(client.available_permits(), Some(2));
+        assert_eq!(
+            PortkeyClient::new(create_test_config())?.available_permits(),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_requests_wait_times_out() -> Result<()> {
+        use super::super::clock::TestClock;
+
+        let config = PortkeyConfig::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_max_concurrent_requests(1usize)
+            .with_transport(InFlightTransport::default())
+            .build()?;
+        let clock = Arc::new(TestClock::new());
+        let client = PortkeyClient::with_clock(config, clock.clone())?;
+
+        let _held = client
+            .inner
+            .request_permits
+            .as_ref()
+            .unwrap()
+            .acquire()
+            .await;
+        let error = client.send(Method::GET, "/models").await.unwrap_err();
+
+        assert!(matches!(error, Error::DeadlineExceeded));
+        assert_eq!(clock.elapsed(), Duration::from_secs(30));
+
+        Ok(())
+    }
+
+    /// Answers requests with a fixed sequence of statuses, repeating the
+    /// last one, and counts the requests.
+    #[derive(Debug)]
+    struct SequenceTransport {
+        statuses: Vec<(u16, Option<&'static str>)>,
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl SequenceTransport {
+        fn new(statuses: Vec<(u16, Option<&'static str>)>) -> Self {
+            Self {
+                statuses,
+                calls: Arc::default(),
+            }
+        }
+    }
+
+    impl HttpTransport for SequenceTransport {
+        fn execute(&self, _: reqwest::Request) -> crate::builder::TransportFuture<'_> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let (status, retry_after) = self.statuses[call.min(self.statuses.len() - 1)];
+            let mut response = http::Response::builder().status(status);
+            if let Some(retry_after) = retry_after {
+                response = response.header(reqwest::header::RETRY_AFTER, retry_after);
+            }
+            let response = response.body("{}").unwrap();
+            Box::pin(async move { Ok(Response::from(response)) })
+        }
+    }
+
+    fn retry_client(
+        transport: SequenceTransport,
+    ) -> Result<(PortkeyClient, Arc<super::super::clock::TestClock>)> {
+        use crate::builder::RetryPolicy;
+
+        let config = PortkeyConfig::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_retry_policy(
+                RetryPolicy::new(2)
+                    .with_initial_backoff(Duration::from_millis(100))
+                    .with_jitter(false),
+            )
+            .with_transport(transport)
+            .build()?;
+        let clock = Arc::new(super::super::clock::TestClock::new());
+        Ok((PortkeyClient::with_clock(config, clock.clone())?, clock))
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy() -> Result<()> {
+        use std::sync::atomic::Ordering;
+
+        // Idempotent requests are retried with exponential backoff
+        let transport = SequenceTransport::new(vec![(503, None), (502, None), (200, None)]);
+        let calls = transport.calls.clone();
+        let (client, clock) = retry_client(transport)?;
+        let response = client.send(Method::GET, "/models").await?;
+        assert_eq!(response.status(), 200);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(clock.elapsed(), Duration::from_millis(300));
+
+        // `Retry-After` replaces the backoff, up to `max_backoff`
+        let transport = SequenceTransport::new(vec![(429, Some("3")), (200, None)]);
+        let (client, clock) = retry_client(transport)?;
+        client.send(Method::GET, "/models").await?;
+        assert_eq!(clock.elapsed(), Duration::from_secs(3));
+
+        let transport = SequenceTransport::new(vec![(429, Some("3600")), (200, None)]);
+        let (client, clock) = retry_client(transport)?;
+        client.send(Method::GET, "/models").await?;
+        assert_eq!(clock.elapsed(), Duration::from_secs(8));
+
+        // The final error reports every attempt
+        let transport = SequenceTransport::new(vec![(500, None)]);
+        let calls = transport.calls.clone();
+        let (client, _) = retry_client(transport)?;
+        let error = client
+            .send(Method::GET, "/models")
+            .await?
+            .error_for_api_status()
+            .await
+            .unwrap_err();
+        let Error::Api(error) = error else {
+            panic!("expected an API error");
+        };
+        assert_eq!(error.attempts(), 3);
+        assert!(error.to_string().contains("after 3 attempts"));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_deadline() -> Result<()> {
+        use std::sync::atomic::Ordering;
+
+        // A retry that cannot start before the timeout fails right away
+        let transport = SequenceTransport::new(vec![(429, Some("5")), (200, None)]);
+        let calls = transport.calls.clone();
+        let (client, clock) = retry_client(transport)?;
+        let options = RequestOptions::new().with_timeout(Duration::from_secs(2));
+        let error = client
+            .send_with_options(Method::GET, "/models", &options)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::DeadlineExceeded));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+
+        // A retry only gets what is left of the timeout
+        let transport = TimeoutRecorder::default();
+        let timeouts = transport.timeouts.clone();
+        let config = PortkeyConfig::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_retry_policy(
+                crate::builder::RetryPolicy::new(1)
+                    .with_initial_backoff(Duration::from_millis(500))
+                    .with_jitter(false),
+            )
+            .with_transport(transport)
+            .build()?;
+        let clock = Arc::new(super::super::clock::TestClock::new());
+        let client = PortkeyClient::with_clock(config, clock)?;
+        let deadline = client.inner.clock.now() + Duration::from_secs(2);
+        let options = RequestOptions::new().with_deadline(deadline);
+        client
+            .send_with_options(Method::GET, "/models", &options)
+            .await?;
+        assert_eq!(
+            *timeouts.lock().unwrap(),
+            [
+                Some(Duration::from_secs(2)),
+                Some(Duration::from_millis(1500))
+            ]
+        );
+
+        Ok(())
+    }
+
+    /// Records the timeout of every request and answers each with a 503.
+    #[derive(Debug, Default)]
+    struct TimeoutRecorder {
+        timeouts: Arc<std::sync::Mutex<Vec<Option<Duration>>>>,
+    }
+
+    impl HttpTransport for TimeoutRecorder {
+        fn execute(&self, request: reqwest::Request) -> crate::builder::TransportFuture<'_> {
+            self.timeouts
+                .lock()
+                .unwrap()
+                .push(request.timeout().copied());
+            let response = http::Response::builder().status(503).body("{}").unwrap();
+            Box::pin(async move { Ok(Response::from(response)) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_post_opt_in() -> Result<()> {
+        use std::sync::atomic::Ordering;
+
+        let body = serde_json::json!({});
+        let transport = SequenceTransport::new(vec![(429, None), (200, None)]);
+        let calls = transport.calls.clone();
+        let (client, _) = retry_client(transport)?;
+
+        let response = client
+            .send_json(Method::POST, "/chat/completions", &body)
+            .await?;
+        assert_eq!(response.status(), 429);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let options = RequestOptions::new().with_idempotent(true);
+        let response = client
+            .send_json_with_options(Method::POST, "/chat/completions", &body, &options)
+            .await?;
+        assert_eq!(response.status(), 200);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_paginate() -> Result<()> {
+        use crate::model::ListAssistantsResponse;
+
+        let assistant = |id: &str| {
+            format!(
+                r#"{{"id":"{id}","object":"assistant","created_at":1,"model":"gpt-4o","tools":[],"metadata":{{}}}}"#
+            )
+        };
+        let first = format!(
+            r#"{{"object":"list","data":[{},{}],"first_id":"asst_1","last_id":"asst_2","has_more":true}}"#,
+            assistant("asst_1"),
+            assistant("asst_2"),
+        );
+        let second = format!(
+            r#"{{"object":"list","data":[{}],"first_id":"asst_3","last_id":"asst_3","has_more":false}}"#,
+            assistant("asst_3"),
+        );
+        let transport = crate::client::RouteTransport::default()
+            .route("GET /assistants?limit=2&order=asc", 200, first)
+            .route(
+                "GET /assistants?limit=2&order=asc&after=asst_2",
+                200,
+                second,
+            );
+        let requests = transport.requests();
+        let client = transport.client();
+
+        let params = PaginationParams::new().with_limit(2).with_order("asc");
+        let stream = client.paginate::<ListAssistantsResponse>("/assistants", params);
+        let ids: Vec<_> = crate::collect_all(stream, None)
+            .await?
+            .into_iter()
+            .map(|assistant| assistant.id)
+            .collect();
+        assert_eq!(ids, ["asst_1", "asst_2", "asst_3"]);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+
+        // A failed page ends the stream with its error
+        let mut stream =
+            client.paginate::<ListAssistantsResponse>("/threads", PaginationParams::new());
+        assert!(matches!(stream.next().await, Some(Err(Error::Api(_)))));
+        assert!(stream.next().await.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_client_creation_with_custom_config() -> Result<()> {
+        let config = PortkeyConfig::builder()
+            .with_api_key("custom_key")
+            .with_auth_method(AuthMethod::ProviderAuth {
+                provider: "openai".to_string(),
+                authorization: "Bearer sk-test".to_string(),
+                custom_host: None,
+            })
+            .with_base_url("https://custom.api.com")
+            .with_timeout(Duration::from_secs(60))
+            .build()?;
+
+        let client = PortkeyClient::new(config)?;
+
+        assert_eq!(client.inner.config.api_key(), "custom_key");
+        assert_eq!(client.inner.config.base_url(), "https://custom.api.com");
+        assert_eq!(client.inner.config.timeout(), Duration::from_secs(60));
 
         Ok(())
     }
@@ -511,6 +1905,816 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_api_key_header_can_be_disabled() -> Result<()> {
+        let client = PortkeyClient::builder()
+            .with_send_api_key(false)
+            .with_auth_method(AuthMethod::provider_auth("openai", "Bearer sk-test"))
+            .build_client()?;
+
+        let request = client.request_builder(Method::GET, "/models")?.build()?;
+        assert!(request.headers().get("x-portkey-api-key").is_none());
+        assert_eq!(request.headers()["Authorization"], "Bearer sk-test");
+
+        let request = PortkeyClient::new(create_test_config())?
+            .request_builder(Method::GET, "/models")?
+            .build()?;
+        assert!(request.headers().get("x-portkey-api-key").is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_request_options_config_override() -> Result<()> {
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::config(r#"{"retry":{"attempts":2}}"#))
+            .with_cache_force_refresh(false)
+            .build_client()?;
+
+        let request = client.request_builder(Method::GET, "/models")?.build()?;
+        assert_eq!(
+            request.headers()["x-portkey-config"],
+            r#"{"retry":{"attempts":2}}"#
+        );
+        assert_eq!(request.headers()["x-portkey-cache-force-refresh"], "false");
+
+        let options = RequestOptions::new()
+            .with_config_override(GatewayConfig::new().with_strategy("fallback"))
+            .with_cache_force_refresh(true);
+        let url = client.parse_url("/models")?;
+        let request = client
+            .request(Method::GET, url, "/models", &options)?
+            .build()?;
+        assert_eq!(
+            request.headers()["x-portkey-config"],
+            r#"{"retry":{"attempts":2},"strategy":{"mode":"fallback"}}"#
+        );
+        assert_eq!(
+            request.headers().get_all("x-portkey-config").iter().count(),
+            1
+        );
+        assert_eq!(request.headers()["x-portkey-cache-force-refresh"], "true");
+
+        Ok(())
+    }
+
+    const MODELS_BODY: &str = r#"{"object":"list","data":[{"id":"gpt-4o","created":0,"object":"model","owned_by":"openai"}]}"#;
+
+    #[derive(Debug)]
+    struct RecordingTransport {
+        requests: Arc<std::sync::Mutex<Vec<String>>>,
+        body: &'static str,
+    }
+
+    impl RecordingTransport {
+        fn new(body: &'static str) -> Self {
+            Self {
+                requests: Arc::default(),
+                body,
+            }
+        }
+    }
+
+    impl HttpTransport for RecordingTransport {
+        fn execute(&self, request: reqwest::Request) -> crate::builder::TransportFuture<'_> {
+            let header = request.headers()["x-portkey-virtual-key"].to_str().unwrap();
+            self.requests.lock().unwrap().push(format!(
+                "{} {} {header}",
+                request.method(),
+                request.url()
+            ));
+            let body = self.body;
+            Box::pin(async move {
+                let response = http::Response::builder().status(200).body(body).unwrap();
+                Ok(Response::from(response))
+            })
+        }
+    }
+
+    /// Records the `Accept` header of each request and fails it.
+    #[derive(Debug, Default)]
+    struct AcceptTransport {
+        accepts: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl HttpTransport for AcceptTransport {
+        fn execute(&self, request: reqwest::Request) -> crate::builder::TransportFuture<'_> {
+            let accept = request.headers().get_all(reqwest::header::ACCEPT);
+            assert_eq!(accept.iter().count(), 1);
+            let accept = accept.iter().next().unwrap().to_str().unwrap();
+            self.accepts.lock().unwrap().push(accept.to_string());
+            Box::pin(async { Err(Error::Stream("recorded".into())) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_accept_header() -> Result<()> {
+        use crate::model::{
+            ChatCompletionRequest, ChatCompletionRequestMessage, CreateSpeechRequest,
+            CreateTranscriptionRequest, SpeechResponseFormat, Voice,
+        };
+        use crate::service::{AudioService, ChatService, FilesService, ModelsService};
+
+        let transport = AcceptTransport::default();
+        let accepts = transport.accepts.clone();
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_transport(transport)
+            .build_client()?;
+        let chat =
+            || ChatCompletionRequest::new("gpt-4o", vec![ChatCompletionRequestMessage::user("Hi")]);
+        let speech = CreateSpeechRequest {
+            model: "tts-1".to_string(),
+            input: "Hello".to_string(),
+            voice: Voice::Alloy,
+            response_format: Some(SpeechResponseFormat::Wav),
+            speed: None,
+        };
+
+        assert!(client.list_models(None).await.is_err());
+        assert!(client.create_chat_completion(chat()).await.is_err());
+        assert!(client.create_chat_completion_stream(chat()).await.is_err());
+        assert!(
+            client
+                .create_transcription_stream(
+                    Vec::new(),
+                    "audio.mp3",
+                    CreateTranscriptionRequest::default(),
+                )
+                .await
+                .is_err()
+        );
+        assert!(client.create_speech(speech).await.is_err());
+        assert!(client.retrieve_file_content("file-123").await.is_err());
+
+        assert_eq!(
+            *accepts.lock().unwrap(),
+            [
+                "application/json",
+                "application/json",
+                "text/event-stream",
+                "text/event-stream",
+                "audio/wav",
+                "*/*",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_custom_transport() -> Result<()> {
+        use crate::service::ModelsService;
+
+        let transport = RecordingTransport::new(MODELS_BODY);
+        let requests = transport.requests.clone();
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_transport(transport)
+            .build_client()?;
+
+        let models = client.list_models(None).await?;
+        assert_eq!(models.data[0].id, "gpt-4o");
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET https://api.portkey.ai/v1/models vk-123"]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_malformed_base_url() -> Result<()> {
+        use crate::service::{BatchesService, ModelsService};
+
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_base_url("not a url")
+            .build_client()?;
+
+        assert!(matches!(
+            client.resolved_url("/files", &[("purpose", "batch")]),
+            Err(Error::UrlParse(_))
+        ));
+        assert!(matches!(
+            client.list_models(None).await,
+            Err(Error::UrlParse(_))
+        ));
+        assert!(matches!(
+            client
+                .list_batches(PaginationParams::new().with_limit(1))
+                .await,
+            Err(Error::UrlParse(_))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_paginated() -> Result<()> {
+        use crate::model::ListModelsResponse;
+
+        let transport = RecordingTransport::new(MODELS_BODY);
+        let requests = transport.requests.clone();
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_transport(transport)
+            .build_client()?;
+
+        let params = PaginationParams::new().with_limit(10).with_after("obj_1");
+        let page: ListModelsResponse = client.get_paginated("/models", &params).await?;
+        assert_eq!(page.data.len(), 1);
+
+        let page: ListModelsResponse = client
+            .get_paginated("/models", &PaginationParams::new())
+            .await?;
+        assert_eq!(page.data.len(), 1);
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [
+                "GET https://api.portkey.ai/v1/models?limit=10&after=obj_1 vk-123",
+                "GET https://api.portkey.ai/v1/models vk-123",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_strict_response_objects() -> Result<()> {
+        use crate::service::ModelsService;
+
+        let body = r#"{"object":"error","data":[]}"#;
+        let client = |strict: bool| {
+            PortkeyClient::builder()
+                .with_api_key("test_key")
+                .with_auth_method(AuthMethod::virtual_key("vk-123"))
+                .with_strict_response_objects(strict)
+                .with_transport(RecordingTransport::new(body))
+                .build_client()
+        };
+
+        assert!(client(false)?.list_models(None).await.is_ok());
+        let err = client(true)?.list_models(None).await.unwrap_err();
+        assert!(matches!(err, crate::Error::UnexpectedResponse(_)));
+
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_strict_response_objects(true)
+            .with_transport(RecordingTransport::new(MODELS_BODY))
+            .build_client()?;
+        assert!(client.list_models(None).await.is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_path_map() -> Result<()> {
+        use crate::builder::{Endpoint, PathMap};
+        use crate::model::CreateImageVariationRequest;
+        use crate::service::{ImagesService, ModelsService};
+
+        let transport = RecordingTransport::new(MODELS_BODY);
+        let requests = transport.requests.clone();
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_base_url("https://gw.example.com")
+            .with_path_prefix("/openai")
+            .with_path_map(
+                PathMap::new()
+                    .with_path(Endpoint::Models, "/catalog/models")
+                    .with_path(Endpoint::ChatCompletions, "/llm/chat")
+                    .with_path(Endpoint::ImageVariations, "/img/variations"),
+            )
+            .with_transport(transport)
+            .build_client()?;
+
+        client.list_models(None).await?;
+        // Only the request paths matter here, not the decoded responses.
+        let _ = client.delete_model("ft-1").await;
+        let _ = client
+            .create_image_variation(vec![0], "image.png", CreateImageVariationRequest::default())
+            .await;
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [
+                "GET https://gw.example.com/openai/catalog/models vk-123",
+                "DELETE https://gw.example.com/openai/catalog/models/ft-1 vk-123",
+                "POST https://gw.example.com/openai/img/variations vk-123",
+            ]
+        );
+        assert_eq!(client.endpoint_path(Endpoint::ChatCompletions), "/llm/chat");
+        assert_eq!(client.endpoint_path(Endpoint::Embeddings), "/embeddings");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_request_span_fields() -> Result<()> {
+        use std::collections::HashMap;
+        use std::sync::Mutex;
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        type Fields = HashMap<String, String>;
+
+        /// Captures the fields of every span by name.
+        #[derive(Clone, Default)]
+        struct SpanCapture {
+            spans: Arc<Mutex<Vec<(&'static str, Fields)>>>,
+        }
+
+        struct FieldVisitor<'a>(&'a mut Fields);
+
+        impl Visit for FieldVisitor<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.0
+                    .insert(field.name().to_string(), format!("{value:?}"));
+            }
+
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.insert(field.name().to_string(), value.to_string());
+            }
+        }
+
+        impl Subscriber for SpanCapture {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+                let mut fields = Fields::new();
+                attrs.record(&mut FieldVisitor(&mut fields));
+                let mut spans = self.spans.lock().unwrap();
+                spans.push((attrs.metadata().name(), fields));
+                Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, span: &Id, values: &Record<'_>) {
+                let mut spans = self.spans.lock().unwrap();
+                let (_, fields) = &mut spans[span.into_u64() as usize - 1];
+                values.record(&mut FieldVisitor(fields));
+            }
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, _: &Event<'_>) {}
+
+            fn enter(&self, _: &Id) {}
+
+            fn exit(&self, _: &Id) {}
+        }
+
+        #[derive(Debug)]
+        struct TracedTransport;
+
+        impl HttpTransport for TracedTransport {
+            fn execute(&self, _: reqwest::Request) -> crate::builder::TransportFuture<'_> {
+                Box::pin(async {
+                    let response = http::Response::builder()
+                        .status(201)
+                        .header("x-portkey-trace-id", "trace-abc")
+                        .body("{}")
+                        .unwrap();
+                    Ok(Response::from(response))
+                })
+            }
+        }
+
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-secret-123"))
+            .with_transport(TracedTransport)
+            .build_client()?;
+
+        let capture = SpanCapture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+        client.send(Method::GET, "/models").await?;
+
+        let spans = capture.spans.lock().unwrap();
+        let (_, fields) = spans
+            .iter()
+            .find(|(name, _)| *name == "portkey_request")
+            .expect("request span");
+        assert_eq!(fields["method"], "GET");
+        assert_eq!(fields["path"], "/v1/models");
+        assert_eq!(fields["auth"], "virtual_key:vk-s****");
+        assert_eq!(fields["trace_id"], "trace-abc");
+        assert_eq!(fields["status"], "201");
+        assert!(fields.contains_key("latency_ms"));
+        assert!(!fields.values().any(|value| value.contains("secret")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_endpoint_timeouts() -> Result<()> {
+        use crate::builder::{Endpoint, EndpointCategory, PathMap};
+
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_timeout(Duration::from_secs(30))
+            .with_endpoint_timeout(EndpointCategory::Chat, Duration::from_secs(10))
+            .with_endpoint_timeout(EndpointCategory::Audio, Duration::from_secs(60))
+            .with_endpoint_timeout(EndpointCategory::Image, Duration::from_secs(90))
+            .with_endpoint_timeout(EndpointCategory::Files, Duration::from_secs(120))
+            .with_endpoint_timeout(EndpointCategory::Batch, Duration::from_secs(180))
+            .with_path_map(PathMap::new().with_path(Endpoint::ChatCompletions, "/llm/chat"))
+            .build_client()?;
+
+        let timeout = |path: &str| -> Result<Option<Duration>> {
+            let request = client.request_builder(Method::GET, path)?.build()?;
+            Ok(request.timeout().copied())
+        };
+        let secs = |secs| Some(Duration::from_secs(secs));
+
+        assert_eq!(timeout("/chat/completions")?, secs(10));
+        assert_eq!(timeout("/llm/chat")?, secs(10));
+        assert_eq!(timeout("/llm/chat/chatcmpl-1")?, secs(10));
+        assert_eq!(timeout("/audio/speech")?, secs(60));
+        assert_eq!(timeout("/images/generations")?, secs(90));
+        assert_eq!(timeout("/files/file-abc/content")?, secs(120));
+        assert_eq!(timeout("/batches/batch_abc")?, secs(180));
+        assert_eq!(timeout("/embeddings")?, secs(30));
+
+        let invalid = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_endpoint_timeout(EndpointCategory::Batch, Duration::ZERO)
+            .build();
+        assert!(invalid.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_url_path_prefix() -> Result<()> {
+        let parse = |base_url: &str, prefix: Option<&str>, path: &str| -> Result<String> {
+            let mut builder = PortkeyClient::builder()
+                .with_api_key("test_key")
+                .with_auth_method(AuthMethod::virtual_key("vk-123"))
+                .with_base_url(base_url);
+            if let Some(prefix) = prefix {
+                builder = builder.with_path_prefix(prefix);
+            }
+            Ok(builder.build_client()?.parse_url(path)?.to_string())
+        };
+
+        assert_eq!(
+            parse("https://api.portkey.ai/v1", None, "/chat/completions")?,
+            "https://api.portkey.ai/v1/chat/completions"
+        );
+        assert_eq!(
+            parse(
+                "https://gw.example.com",
+                Some("/api/llm/v1"),
+                "/chat/completions"
+            )?,
+            "https://gw.example.com/api/llm/v1/chat/completions"
+        );
+        assert_eq!(
+            parse("https://gw.example.com/", Some("api/llm/v1/"), "/models")?,
+            "https://gw.example.com/api/llm/v1/models"
+        );
+        assert_eq!(
+            parse("https://gw.example.com/base/", Some("//llm//"), "/models")?,
+            "https://gw.example.com/base/llm/models"
+        );
+        assert_eq!(
+            parse("https://gw.example.com", Some("/"), "/models")?,
+            "https://gw.example.com/models"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_portkey_headers() -> Result<()> {
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_portkey_header("X-Portkey-Debug", "false")
+            .with_portkey_header("x-portkey-request-timeout", "5000")
+            .build_client()?;
+
+        let request = client.request_builder(Method::GET, "/models")?.build()?;
+        assert_eq!(request.headers()["x-portkey-debug"], "false");
+        assert_eq!(request.headers()["x-portkey-request-timeout"], "5000");
+
+        let options = RequestOptions::new().with_portkey_header("x-portkey-debug", "true");
+        let url = client.parse_url("/models")?;
+        let request = client
+            .request(Method::GET, url, "/models", &options)?
+            .build()?;
+        assert_eq!(request.headers()["x-portkey-debug"], "true");
+        assert_eq!(
+            request.headers().get_all("x-portkey-debug").iter().count(),
+            1
+        );
+
+        for name in [
+            "authorization",
+            "x-portkey-trace-id",
+            "x-portkey-bad header",
+        ] {
+            let options = RequestOptions::new().with_portkey_header(name, "value");
+            let url = client.parse_url("/models")?;
+            assert!(matches!(
+                client.request(Method::GET, url, "/models", &options),
+                Err(Error::Validation(_))
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_user_agent() -> Result<()> {
+        let client = PortkeyClient::new(create_test_config())?;
+        let expected = format!("portkey-sdk-rust/{}", env!("CARGO_PKG_VERSION"));
+        assert_eq!(client.user_agent(), expected);
+
+        let request = client.request_builder(Method::GET, "/models")?.build()?;
+        assert_eq!(request.headers()[reqwest::header::USER_AGENT], *expected);
+
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_user_agent("my-app/1.0")
+            .build_client()?;
+        let request = client.request_builder(Method::GET, "/models")?.build()?;
+        assert_eq!(request.headers()[reqwest::header::USER_AGENT], "my-app/1.0");
+
+        assert!(
+            PortkeyClient::builder()
+                .with_api_key("test_key")
+                .with_auth_method(AuthMethod::virtual_key("vk-123"))
+                .with_user_agent(" ")
+                .build()
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_openai_compliance() -> Result<()> {
+        let client = PortkeyClient::new(create_test_config())?;
+        let request = client.request_builder(Method::GET, "/models")?.build()?;
+        assert_eq!(
+            request.headers()["x-portkey-strict-open-ai-compliance"],
+            "true"
+        );
+
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_strict_openai_compliance(false)
+            .build_client()?;
+        let request = client.request_builder(Method::GET, "/models")?.build()?;
+        assert_eq!(
+            request.headers()["x-portkey-strict-open-ai-compliance"],
+            "false"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_forward_headers() -> Result<()> {
+        let client = PortkeyClient::new(create_test_config())?;
+        let url = client.parse_url("/chat/completions")?;
+
+        let request = client
+            .request(
+                Method::POST,
+                url.clone(),
+                "/chat/completions",
+                &RequestOptions::new(),
+            )?
+            .build()?;
+        assert!(request.headers().get("x-portkey-forward-headers").is_none());
+
+        let options = RequestOptions::new()
+            .with_forward_header("Anthropic-Beta", "prompt-caching-2024-07-31")
+            .with_forward_header("x-custom-auth", "secret");
+        let request = client
+            .request(Method::POST, url.clone(), "/chat/completions", &options)?
+            .build()?;
+        let headers = request.headers();
+        assert_eq!(headers["anthropic-beta"], "prompt-caching-2024-07-31");
+        assert_eq!(headers["x-custom-auth"], "secret");
+        assert_eq!(
+            headers["x-portkey-forward-headers"],
+            "anthropic-beta,x-custom-auth"
+        );
+
+        let options = RequestOptions::new().with_forward_header("x-portkey-debug", "true");
+        assert!(matches!(
+            client.request(Method::POST, url.clone(), "/chat/completions", &options),
+            Err(Error::Validation(_))
+        ));
+
+        // Headers the client sets itself would be sent twice.
+        let options = RequestOptions::new().with_forward_header("Authorization", "Bearer sk-1");
+        match client.request(Method::POST, url, "/chat/completions", &options) {
+            Err(Error::Validation(message)) => assert!(message.contains("`authorization`")),
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extra_headers() -> Result<()> {
+        let client = PortkeyClient::new(create_test_config())?;
+        let url = client.parse_url("/chat/completions")?;
+
+        let options = RequestOptions::new().with_header("X-Request-Source", "batch-job");
+        let request = client
+            .request(Method::POST, url.clone(), "/chat/completions", &options)?
+            .build()?;
+        let headers = request.headers();
+        assert_eq!(headers["x-request-source"], "batch-job");
+        assert!(headers.get("x-portkey-forward-headers").is_none());
+
+        for name in [
+            "Authorization",
+            "content-type",
+            "x-portkey-debug",
+            "bad header",
+        ] {
+            let options = RequestOptions::new().with_header(name, "value");
+            assert!(matches!(
+                client.request(Method::POST, url.clone(), "/chat/completions", &options),
+                Err(Error::Validation(_))
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_model() -> Result<()> {
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_default_model("gpt-4o")
+            .build_client()?;
+
+        let mut model = String::new();
+        client.resolve_model(&mut model)?;
+        assert_eq!(model, "gpt-4o");
+
+        let mut model = "gpt-4o-mini".to_string();
+        client.resolve_model(&mut model)?;
+        assert_eq!(model, "gpt-4o-mini");
+
+        let client = PortkeyClient::new(create_test_config())?;
+        let mut model = String::new();
+        assert!(matches!(
+            client.resolve_model(&mut model),
+            Err(Error::Validation(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_generation_defaults() -> Result<()> {
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_default_max_tokens(256u32)
+            .with_default_temperature(0.3)
+            .build_client()?;
+
+        let (mut max_tokens, mut temperature) = (None::<i32>, None);
+        client.apply_generation_defaults(&mut max_tokens, &mut temperature);
+        assert_eq!((max_tokens, temperature), (Some(256), Some(0.3)));
+
+        let (mut max_tokens, mut temperature) = (Some(64u32), Some(1.0));
+        client.apply_generation_defaults(&mut max_tokens, &mut temperature);
+        assert_eq!((max_tokens, temperature), (Some(64), Some(1.0)));
+
+        let client = PortkeyClient::new(create_test_config())?;
+        let (mut max_tokens, mut temperature) = (None::<u32>, None);
+        client.apply_generation_defaults(&mut max_tokens, &mut temperature);
+        assert_eq!((max_tokens, temperature), (None, None));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_request_options_auth_override() -> Result<()> {
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::provider_auth_with_host(
+                "openai",
+                "Bearer sk-test",
+                "https://openai.internal",
+            ))
+            .build_client()?;
+        let url = client.parse_url("/chat/completions")?;
+
+        let options = RequestOptions::new().with_virtual_key("anthropic-vk");
+        let request = client
+            .request(Method::POST, url.clone(), "/chat/completions", &options)?
+            .build()?;
+        let headers = request.headers();
+        assert_eq!(headers["x-portkey-virtual-key"], "anthropic-vk");
+        assert_eq!(headers.get_all("x-portkey-virtual-key").iter().count(), 1);
+        assert!(headers.get("x-portkey-provider").is_none());
+        assert!(headers.get("Authorization").is_none());
+        assert!(headers.get("x-portkey-custom-host").is_none());
+        assert_eq!(headers["x-portkey-api-key"], "test_key");
+
+        let options = RequestOptions::new().with_provider_auth("anthropic", "Bearer sk-ant", None);
+        let request = client
+            .request(Method::POST, url, "/chat/completions", &options)?
+            .build()?;
+        let headers = request.headers();
+        assert_eq!(headers["x-portkey-provider"], "anthropic");
+        assert_eq!(headers["Authorization"], "Bearer sk-ant");
+        assert_eq!(headers.get_all("Authorization").iter().count(), 1);
+        assert!(headers.get("x-portkey-custom-host").is_none());
+        assert!(headers.get("x-portkey-virtual-key").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolved_url() -> Result<()> {
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-test"))
+            .with_base_url("https://gateway.example.com/v1/")
+            .build_client()?;
+
+        assert_eq!(
+            client.resolved_url("/chat/completions", &[])?,
+            "https://gateway.example.com/v1/chat/completions"
+        );
+        assert_eq!(
+            client.resolved_url("/files", &[("limit", "10"), ("order", "desc")])?,
+            "https://gateway.example.com/v1/files?limit=10&order=desc"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_metadata_policy_applied_to_header() -> Result<()> {
+        use crate::Error;
+        use crate::builder::{MetadataPolicy, OversizeAction};
+
+        let metadata = std::collections::HashMap::from([
+            ("user".to_string(), serde_json::json!("alice")),
+            ("email".to_string(), serde_json::json!("alice@example.com")),
+        ]);
+
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-test"))
+            .with_metadata(metadata.clone())
+            .with_metadata_policy(MetadataPolicy::new().with_denied_key("email"))
+            .build_client()?;
+        let request = client.request_builder(Method::GET, "/models")?.build()?;
+        assert_eq!(
+            request.headers()["x-portkey-metadata"],
+            r#"{"user":"alice"}"#
+        );
+
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-test"))
+            .with_metadata(metadata)
+            .with_metadata_policy(
+                MetadataPolicy::new()
+                    .with_max_size(8)
+                    .with_oversize_action(OversizeAction::Reject),
+            )
+            .build_client()?;
+        assert!(matches!(
+            client.request_builder(Method::GET, "/models"),
+            Err(Error::Validation(_))
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_optional_headers_config() -> Result<()> {
         let mut metadata = std::collections::HashMap::new();
@@ -536,4 +2740,62 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_request_options_override_config() -> Result<()> {
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("team".to_string(), serde_json::json!("search"));
+        metadata.insert("env".to_string(), serde_json::json!("prod"));
+
+        let config = PortkeyConfig::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_trace_id("client-trace")
+            .with_metadata(metadata)
+            .with_cache_namespace("client-cache")
+            .build()?;
+        let client = PortkeyClient::new(config)?;
+        let url = client.parse_url("/chat/completions")?;
+        let header = |request: &reqwest::Request, name: &str| {
+            request.headers()[name].to_str().unwrap().to_string()
+        };
+
+        let request = client
+            .request(
+                Method::POST,
+                url.clone(),
+                "/chat/completions",
+                &RequestOptions::new(),
+            )?
+            .build()?;
+        assert_eq!(header(&request, "x-portkey-trace-id"), "client-trace");
+        assert_eq!(
+            header(&request, "x-portkey-cache-namespace"),
+            "client-cache"
+        );
+        assert_eq!(request.timeout(), Some(&Duration::from_secs(30)));
+
+        let options = RequestOptions::new()
+            .with_trace_id("request-trace")
+            .with_cache_namespace("tenant-a")
+            .with_metadata("team", "ranking")
+            .with_metadata("_user", "user-1")
+            .with_timeout(Duration::from_secs(120));
+        let request = client
+            .request(Method::POST, url, "/chat/completions", &options)?
+            .build()?;
+        assert_eq!(header(&request, "x-portkey-trace-id"), "request-trace");
+        assert_eq!(header(&request, "x-portkey-cache-namespace"), "tenant-a");
+        assert_eq!(request.timeout(), Some(&Duration::from_secs(120)));
+        let metadata: serde_json::Value =
+            serde_json::from_str(&header(&request, "x-portkey-metadata"))?;
+        assert_eq!(
+            metadata,
+            serde_json::json!({"team": "ranking", "env": "prod", "_user": "user-1"})
+        );
+
+        // The config is left untouched for later requests
+        assert_eq!(client.inner.config.trace_id(), Some("client-trace"));
+        Ok(())
+    }
 }
@@ -0,0 +1,350 @@
+//! Response status handling shared by all services.
+
+use reqwest::Response;
+
+use crate::error::{ApiError, Error, Result};
+use crate::model::{self, ResponseMeta};
+
+/// Header carrying the Portkey trace ID of a request.
+pub(crate) const TRACE_ID_HEADER: &str = "x-portkey-trace-id";
+/// Header carrying the slug of the prompt template that served a request.
+const PROMPT_SLUG_HEADER: &str = "x-portkey-prompt-slug";
+/// Header carrying the ID of the gateway config applied to a request.
+const CONFIG_HEADER: &str = "x-portkey-config";
+/// Header carrying the provider that served a request.
+const PROVIDER_HEADER: &str = "x-portkey-provider";
+/// Header carrying the requests left in the provider's rate limit window.
+const RATELIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining-requests";
+/// Header carrying the time until the provider's request limit resets.
+const RATELIMIT_RESET_HEADER: &str = "x-ratelimit-reset-requests";
+/// Header carrying the cost of a request.
+const COST_HEADER: &str = "x-portkey-cost";
+
+/// Number of times a request was sent, stored in the extensions of its
+/// final response by the retry loop.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Attempts(pub(crate) u32);
+
+/// Extension methods for [`Response`].
+pub(crate) trait ResponseExt: Sized {
+    /// Turns a non-success response into an [`Error::Api`].
+    ///
+    /// Unlike [`Response::error_for_status`], this keeps the response body
+    /// the resolved URL, and the Portkey trace ID so that they can be
+    /// reported to the caller.
+    async fn error_for_api_status(self) -> Result<Self>;
+
+    /// Reads the Portkey correlation headers of the response.
+    fn meta(&self) -> ResponseMeta;
+}
+
+impl ResponseExt for Response {
+    async fn error_for_api_status(self) -> Result<Self> {
+        let status = self.status();
+        if !status.is_client_error() && !status.is_server_error() {
+            return Ok(self);
+        }
+
+        let trace_id = self
+            .headers()
+            .get(TRACE_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let url = self.url().to_string();
+        let attempts = self.extensions().get::<Attempts>().map_or(1, |a| a.0);
+        let body = self.text().await.unwrap_or_default();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_CLIENT,
+            status = %status,
+            url = %url,
+            trace_id = ?trace_id,
+            "Request failed"
+        );
+
+        Err(Error::Api(
+            ApiError::new(status, url, trace_id, body).with_attempts(attempts),
+        ))
+    }
+
+    fn meta(&self) -> ResponseMeta {
+        let header = |name: &str| {
+            self.headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned)
+        };
+
+        ResponseMeta {
+            trace_id: header(TRACE_ID_HEADER),
+            prompt_slug: header(PROMPT_SLUG_HEADER),
+            config: header(CONFIG_HEADER),
+            provider: header(PROVIDER_HEADER),
+            ratelimit_remaining: header(RATELIMIT_REMAINING_HEADER)
+                .and_then(|value| value.trim().parse().ok()),
+            ratelimit_reset: header(RATELIMIT_RESET_HEADER)
+                .and_then(|value| parse_reset(value.trim())),
+            cost: header(COST_HEADER).and_then(|value| value.trim().parse().ok()),
+        }
+    }
+}
+
+/// Parses a rate limit reset time, either in seconds (`"20"`, `"0.5"`) or
+/// as a duration such as `"1s"`, `"6m0s"` or `"250ms"`.
+fn parse_reset(value: &str) -> Option<std::time::Duration> {
+    use std::time::Duration;
+
+    if let Ok(secs) = value.parse::<f64>() {
+        return Duration::try_from_secs_f64(secs).ok();
+    }
+
+    let mut total = Duration::ZERO;
+    let mut rest = value;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .filter(|&len| len > 0)?;
+        let (number, tail) = rest.split_at(number_len);
+        let number: f64 = number.parse().ok()?;
+        let unit_len = tail
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+        let scale = match unit {
+            "h" => 3600.0,
+            "m" => 60.0,
+            "s" => 1.0,
+            "ms" => 0.001,
+            _ => return None,
+        };
+        total += Duration::try_from_secs_f64(number * scale).ok()?;
+        rest = tail;
+    }
+    Some(total)
+}
+
+/// Response types with a fixed `object` discriminator.
+///
+/// Public only so that it can bound [`Paginated`]; the module is private, so
+/// the trait cannot be named or implemented outside the crate.
+pub trait ObjectType {
+    /// The `object` value the endpoint returns.
+    const OBJECT: &'static str;
+
+    /// Returns the deserialized `object` value.
+    fn object(&self) -> &str;
+
+    /// Checks that the deserialized `object` matches [`OBJECT`](Self::OBJECT).
+    fn ensure_object(&self) -> Result<()> {
+        if self.object() == Self::OBJECT {
+            return Ok(());
+        }
+
+        Err(Error::UnexpectedResponse(format!(
+            "expected object {:?}, got {:?}",
+            Self::OBJECT,
+            self.object()
+        )))
+    }
+}
+
+/// A page of a list endpoint paginated with an `after` cursor.
+///
+/// Implemented by the list responses that report `has_more`, so that
+/// [`PortkeyClient::paginate`](super::PortkeyClient::paginate) can follow
+/// them to the end. The trait is sealed.
+pub trait Paginated: ObjectType {
+    /// The listed item.
+    type Item;
+
+    /// Returns the items of this page.
+    fn items(&self) -> &[Self::Item];
+
+    /// Returns the ID of the last item, used as the `after` cursor of the
+    /// next page.
+    fn last_id(&self) -> Option<&str>;
+
+    /// Returns whether more items follow this page.
+    fn has_more(&self) -> bool;
+
+    /// Consumes the page and returns its items.
+    fn into_items(self) -> Vec<Self::Item>;
+
+    /// Returns the cursor of the next page, which is `None` on the last page.
+    fn next_cursor(&self) -> Option<String> {
+        self.last_id()
+            .filter(|_| self.has_more())
+            .map(str::to_owned)
+    }
+}
+
+macro_rules! impl_paginated {
+    ($($ty:ty => $item:ty),* $(,)?) => {
+        $(
+            impl Paginated for $ty {
+                type Item = $item;
+
+                fn items(&self) -> &[$item] {
+                    &self.data
+                }
+
+                fn last_id(&self) -> Option<&str> {
+                    self.last_id.as_deref()
+                }
+
+                fn has_more(&self) -> bool {
+                    self.has_more
+                }
+
+                fn into_items(self) -> Vec<$item> {
+                    self.data
+                }
+            }
+        )*
+    };
+}
+
+impl_paginated! {
+    model::ListAssistantFilesResponse => model::AssistantFile,
+    model::ListAssistantsResponse => model::Assistant,
+    model::ListBatchesResponse => model::Batch,
+    model::ListChatCompletionsResponse => model::ChatCompletionResponse,
+    model::ListFineTuningJobCheckpointsResponse => model::FineTuningJobCheckpoint,
+    model::ListMessageFilesResponse => model::MessageFile,
+    model::ListMessagesResponse => model::Message,
+    model::ListRunStepsResponse => model::RunStep,
+    model::ListRunsResponse => model::Run,
+}
+
+// The fine-tuning jobs list has no `last_id`; the cursor is the ID of the
+// last job on the page.
+impl Paginated for model::ListFineTuningJobsResponse {
+    type Item = model::FineTuningJob;
+
+    fn items(&self) -> &[model::FineTuningJob] {
+        &self.data
+    }
+
+    fn last_id(&self) -> Option<&str> {
+        self.data.last().map(|job| job.id.as_str())
+    }
+
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    fn into_items(self) -> Vec<model::FineTuningJob> {
+        self.data
+    }
+}
+
+macro_rules! impl_object_type {
+    ($($ty:ty => $object:literal),* $(,)?) => {
+        $(
+            impl ObjectType for $ty {
+                const OBJECT: &'static str = $object;
+
+                fn object(&self) -> &str {
+                    &self.object
+                }
+            }
+        )*
+    };
+}
+
+impl_object_type! {
+    model::Assistant => "assistant",
+    model::Batch => "batch",
+    model::ChatCompletionResponse => "chat.completion",
+    model::CompletionResponse => "text_completion",
+    model::CreateEmbeddingResponse => "list",
+    model::CreateLogExportResponse => "export",
+    model::DeleteFileResponse => "file",
+    model::DeleteModelResponse => "model",
+    model::ExportTaskResponse => "export",
+    model::FileObject => "file",
+    model::FineTuningJob => "fine_tuning.job",
+    model::ListAssistantFilesResponse => "list",
+    model::ListAssistantsResponse => "list",
+    model::ListBatchesResponse => "list",
+    model::ListChatCompletionsResponse => "list",
+    model::ListFilesResponse => "list",
+    model::ListFineTuningJobCheckpointsResponse => "list",
+    model::ListFineTuningJobEventsResponse => "list",
+    model::ListFineTuningJobsResponse => "list",
+    model::ListLogExportsResponse => "list",
+    model::ListMessageFilesResponse => "list",
+    model::ListMessagesResponse => "list",
+    model::ListModelsResponse => "list",
+    model::ListRunStepsResponse => "list",
+    model::ListRunsResponse => "list",
+    model::LogExport => "export",
+    model::Message => "thread.message",
+    model::ResponseGeneration => "response",
+    model::Run => "thread.run",
+    model::RunStep => "thread.run.step",
+    model::Thread => "thread",
+    model::UpdateLogExportResponse => "export",
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_response_meta() {
+        let response = http::Response::builder()
+            .header(TRACE_ID_HEADER, "trace-123")
+            .header(PROMPT_SLUG_HEADER, "support-v2")
+            .header(CONFIG_HEADER, "pc-routing")
+            .header(PROVIDER_HEADER, "azure-openai")
+            .body(r#"{"id":"chatcmpl-1","object":"chat.completion","created":1,"model":"gpt-4o","choices":[]}"#)
+            .unwrap();
+        let response = Response::from(response);
+
+        let meta = response.meta();
+        assert_eq!(meta.trace_id.as_deref(), Some("trace-123"));
+        assert_eq!(meta.prompt_slug.as_deref(), Some("support-v2"));
+        assert_eq!(meta.config.as_deref(), Some("pc-routing"));
+
+        let chat = model::PortkeyResponse {
+            body: serde_json::from_str::<model::ChatCompletionResponse>(
+                r#"{"id":"chatcmpl-1","object":"chat.completion","created":1,"model":"gpt-4o","choices":[]}"#,
+            )
+            .unwrap(),
+            meta,
+        };
+        assert_eq!(chat.effective_provider(), Some("azure-openai"));
+
+        let response = Response::from(http::Response::new(""));
+        assert_eq!(response.meta(), ResponseMeta::default());
+    }
+
+    #[test]
+    fn test_response_meta_rate_limits() {
+        let response = http::Response::builder()
+            .header(RATELIMIT_REMAINING_HEADER, "59")
+            .header(RATELIMIT_RESET_HEADER, "6m0.5s")
+            .header(COST_HEADER, "0.0042")
+            .body("")
+            .unwrap();
+        let meta = Response::from(response).meta();
+
+        assert_eq!(meta.ratelimit_remaining, Some(59));
+        assert_eq!(meta.ratelimit_reset, Some(Duration::from_millis(360_500)));
+        assert_eq!(meta.cost, Some(0.0042));
+    }
+
+    #[test]
+    fn test_parse_reset() {
+        assert_eq!(parse_reset("20"), Some(Duration::from_secs(20)));
+        assert_eq!(parse_reset("1s"), Some(Duration::from_secs(1)));
+        assert_eq!(parse_reset("250ms"), Some(Duration::from_millis(250)));
+        assert_eq!(parse_reset("1h2m3s"), Some(Duration::from_secs(3723)));
+        assert_eq!(parse_reset("soon"), None);
+        assert_eq!(parse_reset("5d"), None);
+    }
+}
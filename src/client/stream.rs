@@ -0,0 +1,857 @@
+//! Server-sent event and newline-delimited JSON streaming.
+//!
+//! Streaming responses are read by a background task that decodes events and
+//! pushes them into a bounded channel. When the consumer falls behind, the
+//! channel fills up and the task stops reading from the connection, so memory
+//! use stays proportional to the configured buffer rather than to the response.
+
+use std::fmt;
+use std::future::{Future, poll_fn};
+use std::path::PathBuf;
+use std::pin::{Pin, pin};
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures_core::Stream;
+use reqwest::{Response, StatusCode};
+use serde::de::DeserializeOwned;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+
+use super::options::DEFAULT_STREAM_BUFFER;
+use super::response::ResponseExt;
+use crate::error::{ApiError, Error, Result};
+
+/// Data payload that marks the end of an OpenAI-compatible event stream.
+const DONE_MARKER: &str = "[DONE]";
+
+/// A stream of events decoded from a streaming response.
+///
+/// Implements [`Stream`] and also provides an inherent [`next`](Self::next)
+/// method, so it can be consumed without additional crates. Dropping the
+/// stream stops the background reader and closes the connection.
+pub struct EventStream<T> {
+    receiver: mpsc::Receiver<Result<T>>,
+    peeked: Option<Result<T>>,
+}
+
+impl<T> EventStream<T> {
+    /// Creates a stream that reads events from the channel.
+    pub(crate) fn new(receiver: mpsc::Receiver<Result<T>>) -> Self {
+        Self {
+            receiver,
+            peeked: None,
+        }
+    }
+
+    /// Returns the next event, or `None` once the stream has ended.
+    pub async fn next(&mut self) -> Option<Result<T>> {
+        match self.peeked.take() {
+            Some(item) => Some(item),
+            None => self.receiver.recv().await,
+        }
+    }
+
+    /// Waits for the next event and returns a reference to it without
+    /// consuming it, or `None` once the stream has ended.
+    ///
+    /// The peeked event is returned again by the following call to
+    /// [`next`](Self::next).
+    pub async fn peek(&mut self) -> Option<&Result<T>> {
+        if self.peeked.is_none() {
+            self.peeked = self.receiver.recv().await;
+        }
+        self.peeked.as_ref()
+    }
+
+    /// Creates a stream that ends immediately.
+    pub(crate) fn empty() -> Self {
+        let (_, receiver) = mpsc::channel(1);
+        Self::new(receiver)
+    }
+
+    /// Creates a stream that yields the given items and then ends.
+    #[cfg(test)]
+    pub(crate) fn from_items(items: Vec<Result<T>>) -> Self {
+        let (sender, receiver) = mpsc::channel(items.len().max(1));
+        for item in items {
+            let _ = sender.try_send(item);
+        }
+        Self::new(receiver)
+    }
+}
+
+// The stream never pins its fields, so it can be moved regardless of `T`.
+impl<T> Unpin for EventStream<T> {}
+
+impl<T> Stream for EventStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(item) = self.peeked.take() {
+            return Poll::Ready(Some(item));
+        }
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl<T> fmt::Debug for EventStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventStream")
+            .field(
+                "buffered",
+                &(self.receiver.len() + usize::from(self.peeked.is_some())),
+            )
+            .finish()
+    }
+}
+
+/// A stream of raw response body chunks.
+pub type ByteStream = EventStream<Bytes>;
+
+/// A source of raw response body chunks.
+pub(crate) trait ByteSource: Send + 'static {
+    /// The chunk type yielded by the source.
+    type Chunk: AsRef<[u8]> + Into<Bytes> + Send;
+
+    /// Reads the next chunk, or `None` at the end of the body.
+    fn next_chunk(&mut self) -> impl Future<Output = Result<Option<Self::Chunk>>> + Send;
+
+    /// Returns the URL and trace ID of the response the chunks come from,
+    /// used to describe errors reported inside the body.
+    fn origin(&self) -> (String, Option<String>) {
+        (String::new(), None)
+    }
+}
+
+impl ByteSource for Response {
+    type Chunk = Bytes;
+
+    async fn next_chunk(&mut self) -> Result<Option<Self::Chunk>> {
+        Ok(self.chunk().await?)
+    }
+
+    fn origin(&self) -> (String, Option<String>) {
+        (self.url().to_string(), self.meta().trace_id)
+    }
+}
+
+impl ByteSource for ByteStream {
+    type Chunk = Bytes;
+
+    async fn next_chunk(&mut self) -> Result<Option<Self::Chunk>> {
+        self.next().await.transpose()
+    }
+}
+
+/// Spawns a task that forwards the raw chunks of `source` into a [`ByteStream`].
+///
+/// At most `buffer` chunks are held in memory ahead of the consumer.
+pub(crate) fn spawn_bytes<S>(mut source: S, buffer: usize) -> ByteStream
+where
+    S: ByteSource,
+{
+    let (sender, receiver) = mpsc::channel(buffer.max(1));
+    tokio::spawn(async move {
+        loop {
+            let item = match source.next_chunk().await {
+                Ok(Some(chunk)) => Ok(chunk.into()),
+                Ok(None) => return,
+                Err(e) => Err(e),
+            };
+            let is_err = item.is_err();
+            if sender.send(item).await.is_err() || is_err {
+                return;
+            }
+        }
+    });
+    EventStream::new(receiver)
+}
+
+/// Writes each chunk of `stream` to the file at `path` while passing it through.
+///
+/// The file is created (or truncated) by a background task, and every chunk
+/// is written before it is yielded. The file is flushed and closed once the
+/// input ends, yields an error, or the returned stream is dropped. A failure
+/// to open or write the file is yielded as an [`Error::Io`](crate::Error::Io)
+/// item and ends the stream.
+///
+/// Composes with byte streams such as
+/// [`FilesService::retrieve_file_content_stream`](crate::service::FilesService::retrieve_file_content_stream).
+///
+/// # Example
+///
+/// ```no_run
+/// # use portkey_sdk::{PortkeyClient, Result, save_while_streaming};
+/// # use portkey_sdk::service::FilesService;
+/// # async fn example(client: PortkeyClient) -> Result<()> {
+/// let content = client.retrieve_file_content_stream("file-abc123").await?;
+/// let mut stream = save_while_streaming(content, "output.jsonl");
+/// while let Some(chunk) = stream.next().await {
+///     println!("received {} bytes", chunk?.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn save_while_streaming<S>(stream: S, path: impl Into<PathBuf>) -> ByteStream
+where
+    S: Stream<Item = Result<Bytes>> + Send + 'static,
+{
+    let path = path.into();
+    let (sender, receiver) = mpsc::channel(DEFAULT_STREAM_BUFFER);
+
+    tokio::spawn(async move {
+        let mut file = match tokio::fs::File::create(&path).await {
+            Ok(file) => file,
+            Err(e) => {
+                let _ = sender.send(Err(e.into())).await;
+                return;
+            }
+        };
+
+        let mut stream = pin!(stream);
+        while let Some(item) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            let item = match item {
+                Ok(chunk) => match file.write_all(&chunk).await {
+                    Ok(()) => Ok(chunk),
+                    Err(e) => Err(e.into()),
+                },
+                Err(e) => Err(e),
+            };
+
+            if item.is_err() {
+                // Persist what was written before surfacing the error
+                let _ = file.flush().await;
+                let _ = sender.send(item).await;
+                return;
+            }
+            if sender.send(item).await.is_err() {
+                // The consumer went away, but keep what was written
+                let _ = file.flush().await;
+                return;
+            }
+        }
+
+        if let Err(e) = file.flush().await {
+            let _ = sender.send(Err(e.into())).await;
+        }
+    });
+
+    EventStream::new(receiver)
+}
+
+/// Spawns a task that decodes server-sent events from `source` into an [`EventStream`].
+///
+/// At most `buffer` decoded events are held in memory ahead of the consumer.
+pub(crate) fn spawn_sse<S, T>(source: S, buffer: usize) -> EventStream<T>
+where
+    S: ByteSource,
+    T: DeserializeOwned + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel(buffer.max(1));
+    tokio::spawn(pump_sse(source, sender));
+    EventStream::new(receiver)
+}
+
+/// Reads `source` until it ends, the consumer goes away, or an error occurs.
+async fn pump_sse<S, T>(mut source: S, sender: mpsc::Sender<Result<T>>)
+where
+    S: ByteSource,
+    T: DeserializeOwned + Send + 'static,
+{
+    let mut decoder = SseDecoder::default();
+    let origin = source.origin();
+
+    loop {
+        let chunk = match source.next_chunk().await {
+            Ok(Some(chunk)) => chunk,
+            Ok(None) => break,
+            Err(e) => {
+                let _ = sender.send(Err(e)).await;
+                return;
+            }
+        };
+
+        for event in decoder.feed(chunk.as_ref()) {
+            if !forward_event(&sender, event, &origin).await {
+                return;
+            }
+        }
+    }
+
+    if let Some(event) = decoder.finish() {
+        forward_event(&sender, event, &origin).await;
+    }
+}
+
+/// Sends a decoded event to the consumer, returning whether to keep reading.
+///
+/// Waits while the channel is full, which is what applies backpressure to the
+/// connection. An error frame ends the stream with an [`Error::Api`].
+async fn forward_event<T>(
+    sender: &mpsc::Sender<Result<T>>,
+    event: SseEvent,
+    origin: &(String, Option<String>),
+) -> bool
+where
+    T: DeserializeOwned,
+{
+    if event.data == DONE_MARKER {
+        return false;
+    }
+
+    if let Some(error) = error_frame(&event.data, origin) {
+        let _ = sender.send(Err(error)).await;
+        return false;
+    }
+
+    match serde_json::from_str(&event.data) {
+        Ok(item) => sender.send(Ok(item)).await.is_ok(),
+        Err(e) => {
+            let _ = sender.send(Err(e.into())).await;
+            false
+        }
+    }
+}
+
+/// Returns the error reported by an `{"error": {...}}` frame, if `data` is one.
+///
+/// Providers send these mid-stream, after the `200` status line, for failures
+/// such as rate limits. The status comes from the frame's `status` or `code`
+/// when numeric, `429` for rate limit errors, and `500` otherwise.
+fn error_frame(data: &str, origin: &(String, Option<String>)) -> Option<Error> {
+    if !data.contains("\"error\"") {
+        return None;
+    }
+    let frame: serde_json::Value = serde_json::from_str(data).ok()?;
+    let error = frame.get("error").filter(|error| !error.is_null())?;
+
+    let numeric = |value: &serde_json::Value| match value {
+        serde_json::Value::Number(number) => number.as_u64(),
+        serde_json::Value::String(string) => string.parse().ok(),
+        _ => None,
+    };
+    let status = [error.get("status"), error.get("code"), frame.get("status")]
+        .into_iter()
+        .flatten()
+        .filter_map(numeric)
+        .filter_map(|code| u16::try_from(code).ok())
+        .find_map(|code| {
+            StatusCode::from_u16(code)
+                .ok()
+                .filter(|s| s.is_client_error() || s.is_server_error())
+        });
+    let is_rate_limit = ["type", "code"].iter().any(|key| {
+        error
+            .get(key)
+            .and_then(serde_json::Value::as_str)
+            .is_some_and(|value| value.starts_with("rate_limit"))
+    });
+    let status = status.unwrap_or(if is_rate_limit {
+        StatusCode::TOO_MANY_REQUESTS
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    });
+
+    let (url, trace_id) = origin.clone();
+    Some(Error::Api(ApiError::new(
+        status,
+        url,
+        trace_id,
+        data.to_string(),
+    )))
+}
+
+/// Collects a stream of results into a `Vec`, stopping at the first error.
+///
+/// With `max_items`, collection stops as soon as that many items have been
+/// read, without polling the stream further.
+///
+/// # Example
+///
+/// ```no_run
+/// # use portkey_sdk::{PortkeyClient, Result, collect_all};
+/// # use portkey_sdk::service::BatchesService;
+/// # async fn example(client: PortkeyClient) -> Result<()> {
+/// let results = client.stream_batch_results("batch_abc123").await?;
+/// let first_hundred = collect_all(results, Some(100)).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn collect_all<S, T>(mut stream: S, max_items: Option<usize>) -> Result<Vec<T>>
+where
+    S: Stream<Item = Result<T>> + Unpin,
+{
+    let max_items = max_items.unwrap_or(usize::MAX);
+    let mut items = Vec::new();
+    while items.len() < max_items {
+        match poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+            Some(item) => items.push(item?),
+            None => break,
+        }
+    }
+    Ok(items)
+}
+
+/// Decodes a newline-delimited JSON (NDJSON) response into a stream of `T`.
+///
+/// Each non-blank line of the body is deserialized as one item, and lines may
+/// be split across network reads. The stream ends after the first error. Must
+/// be called from within a Tokio runtime.
+///
+/// # Example
+///
+/// ```no_run
+/// # use portkey_sdk::{Result, ndjson_stream};
+/// # async fn example(response: reqwest::Response) -> Result<()> {
+/// let mut stream = ndjson_stream::<serde_json::Value>(response);
+/// while let Some(item) = stream.next().await {
+///     println!("{}", item?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn ndjson_stream<T>(response: Response) -> EventStream<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    spawn_ndjson(response, DEFAULT_STREAM_BUFFER)
+}
+
+/// Spawns a task that decodes NDJSON lines from `source` into an [`EventStream`].
+///
+/// At most `buffer` decoded items are held in memory ahead of the consumer.
+pub(crate) fn spawn_ndjson<S, T>(source: S, buffer: usize) -> EventStream<T>
+where
+    S: ByteSource,
+    T: DeserializeOwned + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel(buffer.max(1));
+    tokio::spawn(pump_ndjson(source, sender));
+    EventStream::new(receiver)
+}
+
+/// Reads `source` until it ends, the consumer goes away, or an error occurs.
+async fn pump_ndjson<S, T>(mut source: S, sender: mpsc::Sender<Result<T>>)
+where
+    S: ByteSource,
+    T: DeserializeOwned + Send + 'static,
+{
+    let mut decoder = NdjsonDecoder::default();
+
+    loop {
+        let chunk = match source.next_chunk().await {
+            Ok(Some(chunk)) => chunk,
+            Ok(None) => break,
+            Err(e) => {
+                let _ = sender.send(Err(e)).await;
+                return;
+            }
+        };
+
+        for line in decoder.feed(chunk.as_ref()) {
+            if !forward_line(&sender, &line).await {
+                return;
+            }
+        }
+    }
+
+    if let Some(line) = decoder.finish() {
+        forward_line(&sender, &line).await;
+    }
+}
+
+/// Sends a decoded line to the consumer, returning whether to keep reading.
+async fn forward_line<T>(sender: &mpsc::Sender<Result<T>>, line: &[u8]) -> bool
+where
+    T: DeserializeOwned,
+{
+    match serde_json::from_slice(line) {
+        Ok(item) => sender.send(Ok(item)).await.is_ok(),
+        Err(e) => {
+            let _ = sender.send(Err(e.into())).await;
+            false
+        }
+    }
+}
+
+/// Incremental splitter for newline-delimited JSON.
+///
+/// Input is buffered as bytes until a full line is available, so chunks may
+/// split lines and multi-byte characters at arbitrary positions. Blank lines
+/// are skipped.
+#[derive(Debug, Default)]
+pub(crate) struct NdjsonDecoder {
+    buffer: Vec<u8>,
+}
+
+impl NdjsonDecoder {
+    /// Feeds a chunk of bytes and returns the lines it completed.
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Vec<Vec<u8>> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+        while let Some(offset) = self.buffer[start..].iter().position(|&b| b == b'\n') {
+            let line = self.buffer[start..start + offset].trim_ascii();
+            if !line.is_empty() {
+                lines.push(line.to_vec());
+            }
+            start += offset + 1;
+        }
+        self.buffer.drain(..start);
+
+        lines
+    }
+
+    /// Returns a line left unterminated at the end of the body.
+    pub(crate) fn finish(&mut self) -> Option<Vec<u8>> {
+        let buffer = std::mem::take(&mut self.buffer);
+        let line = buffer.trim_ascii();
+        (!line.is_empty()).then(|| line.to_vec())
+    }
+}
+
+/// A single server-sent event.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct SseEvent {
+    /// The event type, if one was given.
+    pub(crate) event: Option<String>,
+    /// The event data, with multiple `data:` lines joined by newlines.
+    pub(crate) data: String,
+}
+
+/// Incremental decoder for the `text/event-stream` format.
+///
+/// Input is buffered as bytes until a full line is available, so chunks may
+/// split lines and multi-byte characters at arbitrary positions.
+#[derive(Debug, Default)]
+pub(crate) struct SseDecoder {
+    buffer: Vec<u8>,
+    event: Option<String>,
+    data: Option<String>,
+}
+
+impl SseDecoder {
+    /// Feeds a chunk of bytes and returns the events it completed.
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = Vec::new();
+        let mut start = 0;
+        while let Some(offset) = self.buffer[start..].iter().position(|&b| b == b'\n') {
+            let end = start + offset;
+            let line = self.buffer[start..end]
+                .strip_suffix(b"\r")
+                .unwrap_or(&self.buffer[start..end]);
+            let line = String::from_utf8_lossy(line).into_owned();
+            start = end + 1;
+
+            if let Some(event) = self.process_line(&line) {
+                events.push(event);
+            }
+        }
+        self.buffer.drain(..start);
+
+        events
+    }
+
+    /// Flushes an event left unterminated at the end of the body.
+    pub(crate) fn finish(&mut self) -> Option<SseEvent> {
+        if !self.buffer.is_empty() {
+            let line = String::from_utf8_lossy(&std::mem::take(&mut self.buffer)).into_owned();
+            let line = line.strip_suffix('\r').unwrap_or(&line).to_owned();
+            if let Some(event) = self.process_line(&line) {
+                return Some(event);
+            }
+        }
+        self.dispatch()
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<SseEvent> {
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+
+        match field {
+            "data" => match &mut self.data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => self.data = Some(value.to_owned()),
+            },
+            "event" => self.event = Some(value.to_owned()),
+            _ => {}
+        }
+
+        None
+    }
+
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let event = self.event.take();
+        self.data.take().map(|data| SseEvent { event, data })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Frame {
+        n: usize,
+    }
+
+    /// An endless in-memory body that counts how many chunks were read.
+    struct CountingSource {
+        reads: Arc<AtomicUsize>,
+    }
+
+    impl ByteSource for CountingSource {
+        type Chunk = Vec<u8>;
+
+        async fn next_chunk(&mut self) -> Result<Option<Self::Chunk>> {
+            let n = self.reads.fetch_add(1, Ordering::SeqCst);
+            Ok(Some(format!("data: {{\"n\":{n}}}\n\n").into_bytes()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sse_error_frame() {
+        let body = concat!(
+            "data: {\"n\":1}\n\n",
+            "data: {\"n\":2}\n\n",
+            "data: {\"error\":{\"message\":\"Rate limit reached\",\"type\":\"rate_limit_error\"}}\n\n",
+            "data: {\"n\":3}\n\n",
+        );
+        let source = ByteStream::from_items(vec![Ok(Bytes::from(body))]);
+        let mut stream = spawn_sse::<_, Frame>(source, 4);
+
+        assert_eq!(stream.next().await.unwrap().unwrap().n, 1);
+        assert_eq!(stream.next().await.unwrap().unwrap().n, 2);
+        let error = stream.next().await.unwrap().unwrap_err();
+        assert!(error.is_status(429));
+        assert!(error.to_string().contains("Rate limit reached"));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[test]
+    fn test_error_frame_status() {
+        let origin = (
+            "https://api.portkey.ai/v1/chat/completions".to_string(),
+            None,
+        );
+        let status = |data: &str| error_frame(data, &origin).and_then(|error| error.status());
+
+        assert_eq!(
+            status(r#"{"error":{"message":"Overloaded","code":"503"}}"#),
+            Some(StatusCode::SERVICE_UNAVAILABLE)
+        );
+        assert_eq!(
+            status(r#"{"error":{"message":"Boom"}}"#),
+            Some(StatusCode::INTERNAL_SERVER_ERROR)
+        );
+        assert_eq!(status(r#"{"n":1,"error":null}"#), None);
+        assert_eq!(status(r#"{"text":"the word \"error\""}"#), None);
+    }
+
+    #[test]
+    fn test_ndjson_decoder_split_lines() {
+        let mut decoder = NdjsonDecoder::default();
+        assert!(decoder.feed(b"{\"n\":").is_empty());
+        assert_eq!(decoder.feed(b"1}\r\n\n{\"n\""), [b"{\"n\":1}".to_vec()]);
+        assert!(decoder.feed(b":2}").is_empty());
+        assert_eq!(decoder.finish(), Some(b"{\"n\":2}".to_vec()));
+        assert_eq!(decoder.finish(), None);
+    }
+
+    #[tokio::test]
+    async fn test_collect_all() {
+        let items = || EventStream::from_items(vec![Ok(1), Ok(2), Ok(3)]);
+        assert_eq!(collect_all(items(), None).await.unwrap(), [1, 2, 3]);
+        assert_eq!(collect_all(items(), Some(2)).await.unwrap(), [1, 2]);
+
+        let failing =
+            EventStream::from_items(vec![Ok(1), Err(crate::Error::Stream("cut".into())), Ok(3)]);
+        assert!(collect_all(failing, None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_stream_chunk_boundaries() {
+        let body = "{\"n\":1}\n{\"n\":22}\n\n{\"n\":333}";
+        for size in 1..body.len() {
+            let chunks: Vec<Result<Bytes>> = body
+                .as_bytes()
+                .chunks(size)
+                .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+                .collect();
+            let mut stream: EventStream<Frame> =
+                spawn_ndjson(ByteStream::from_items(chunks), DEFAULT_STREAM_BUFFER);
+
+            let mut frames = Vec::new();
+            while let Some(frame) = stream.next().await {
+                frames.push(frame.unwrap().n);
+            }
+            assert_eq!(frames, [1, 22, 333], "chunk size {size}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_stream_invalid_line() {
+        let chunks = vec![Ok(Bytes::from_static(b"{\"n\":1}\nnot json\n{\"n\":2}\n"))];
+        let mut stream: EventStream<Frame> =
+            spawn_ndjson(ByteStream::from_items(chunks), DEFAULT_STREAM_BUFFER);
+
+        assert_eq!(stream.next().await.unwrap().unwrap().n, 1);
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(crate::Error::Serialization(_)))
+        ));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[test]
+    fn test_decoder_split_chunks() {
+        let mut decoder = SseDecoder::default();
+        assert!(decoder.feed(b"event: message\nda").is_empty());
+        assert!(decoder.feed(b"ta: {\"n\":1}\r\n").is_empty());
+
+        let events = decoder.feed(b"\r\n: keep-alive\n\ndata: a\ndata: b\n\n");
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    event: Some("message".to_string()),
+                    data: "{\"n\":1}".to_string(),
+                },
+                SseEvent {
+                    event: None,
+                    data: "a\nb".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decoder_finish_flushes_unterminated_event() {
+        let mut decoder = SseDecoder::default();
+        assert!(decoder.feed(b"data: [DONE]").is_empty());
+        assert_eq!(decoder.finish().unwrap().data, "[DONE]");
+        assert!(decoder.finish().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_slow_consumer_applies_backpressure() {
+        let reads = Arc::new(AtomicUsize::new(0));
+        let source = CountingSource {
+            reads: reads.clone(),
+        };
+        let buffer = 4;
+        let mut stream = spawn_sse::<_, Frame>(source, buffer);
+
+        // Give the reader plenty of opportunities to run ahead of the consumer.
+        for _ in 0..100 {
+            tokio::task::yield_now().await;
+        }
+        let stalled = reads.load(Ordering::SeqCst);
+        assert!(stalled <= buffer + 1, "read {stalled} chunks while stalled");
+
+        for expected in 0..10 {
+            let frame = stream.next().await.unwrap().unwrap();
+            assert_eq!(frame.n, expected);
+        }
+        for _ in 0..100 {
+            tokio::task::yield_now().await;
+        }
+        let resumed = reads.load(Ordering::SeqCst);
+        assert!(resumed > stalled);
+        assert!(
+            resumed <= 10 + buffer + 1,
+            "read {resumed} chunks after 10 frames"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_while_streaming() {
+        let path = std::env::temp_dir().join(format!("portkey-tee-{}.bin", std::process::id()));
+        let input = ByteStream::from_items(vec![
+            Ok(Bytes::from_static(b"hello ")),
+            Ok(Bytes::from_static(b"world")),
+        ]);
+
+        let mut stream = save_while_streaming(input, &path);
+        let mut received = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            received.extend_from_slice(&chunk.unwrap());
+        }
+
+        assert_eq!(received, b"hello world");
+        assert_eq!(std::fs::read(&path).unwrap(), b"hello world");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_save_while_streaming_error() {
+        let path = std::env::temp_dir().join(format!("portkey-tee-err-{}.bin", std::process::id()));
+        let input = ByteStream::from_items(vec![
+            Ok(Bytes::from_static(b"partial")),
+            Err(crate::Error::Stream("connection reset".into())),
+            Ok(Bytes::from_static(b"ignored")),
+        ]);
+
+        let mut stream = save_while_streaming(input, &path);
+        assert_eq!(stream.next().await.unwrap().unwrap(), "partial");
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(stream.next().await.is_none());
+
+        assert_eq!(std::fs::read(&path).unwrap(), b"partial");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_save_while_streaming_dropped() {
+        let path =
+            std::env::temp_dir().join(format!("portkey-tee-drop-{}.bin", std::process::id()));
+        let (input, receiver) = mpsc::channel(1);
+
+        let mut stream = save_while_streaming(ByteStream::new(receiver), &path);
+        input.send(Ok(Bytes::from_static(b"hello"))).await.unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap(), "hello");
+        drop(stream);
+
+        // The chunk written after the drop is flushed before the task ends
+        input.send(Ok(Bytes::from_static(b" world"))).await.unwrap();
+        input.closed().await;
+        assert_eq!(std::fs::read(&path).unwrap(), b"hello world");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_dropping_stream_stops_reader() {
+        let reads = Arc::new(AtomicUsize::new(0));
+        let source = CountingSource {
+            reads: reads.clone(),
+        };
+        let stream = spawn_sse::<_, Frame>(source, 2);
+        drop(stream);
+
+        for _ in 0..100 {
+            tokio::task::yield_now().await;
+        }
+        assert!(reads.load(Ordering::SeqCst) <= 1);
+    }
+}
@@ -0,0 +1,157 @@
+//! Pluggable HTTP transport.
+//!
+//! [`PortkeyClient`](super::PortkeyClient) builds every request with reqwest
+//! and hands it to an [`HttpTransport`] to execute. The default transport is
+//! the reqwest [`Client`]; a custom transport set with
+//! [`PortkeyBuilder::with_transport`](super::PortkeyBuilder::with_transport)
+//! can route requests through a tower stack, an in-memory service or a mock.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+use reqwest::{Client, Request, Response};
+
+use crate::error::Result;
+
+/// A future returned by [`HttpTransport::execute`].
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<Response>> + Send + 'a>>;
+
+/// Executes HTTP requests for a [`PortkeyClient`](super::PortkeyClient).
+///
+/// Requests arrive fully built, with the URL, Portkey headers, body and
+/// timeout already set. Custom transports usually convert an
+/// `http::Response` into the returned [`Response`] with `Response::from`.
+///
+/// # Example
+///
+/// ```no_run
+/// use portkey_sdk::builder::{HttpTransport, TransportFuture};
+/// use reqwest::{Request, Response};
+///
+/// #[derive(Debug)]
+/// struct StaticTransport;
+///
+/// impl HttpTransport for StaticTransport {
+///     fn execute(&self, _request: Request) -> TransportFuture<'_> {
+///         Box::pin(async move {
+///             let response = http::Response::builder()
+///                 .status(200)
+///                 .body(r#"{"object":"list","data":[]}"#)
+///                 .unwrap();
+///             Ok(Response::from(response))
+///         })
+///     }
+/// }
+/// ```
+pub trait HttpTransport: fmt::Debug + Send + Sync + 'static {
+    /// Sends the request and returns the response.
+    ///
+    /// Non-success status codes are returned as responses, not errors.
+    fn execute(&self, request: Request) -> TransportFuture<'_>;
+}
+
+impl HttpTransport for Client {
+    fn execute(&self, request: Request) -> TransportFuture<'_> {
+        Box::pin(async move { Ok(Client::execute(self, request).await?) })
+    }
+}
+
+/// A transport answering every request with the same status and body.
+#[cfg(test)]
+#[derive(Debug)]
+pub(crate) struct MockTransport {
+    status: u16,
+    body: &'static str,
+}
+
+#[cfg(test)]
+impl MockTransport {
+    /// Builds a client whose requests are answered by a `MockTransport`.
+    pub(crate) fn client(status: u16, body: &'static str) -> super::PortkeyClient {
+        super::PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(super::auth::AuthMethod::virtual_key("vk-123"))
+            .with_transport(Self { status, body })
+            .build_client()
+            .unwrap()
+    }
+}
+
+/// A transport answering requests by method and path, recording each
+/// request as `"METHOD /path"`. Routes with a query, such as
+/// `"GET /batches?after=batch_2"`, take precedence for requests with exactly
+/// that query. Unmatched requests get `404 Not Found`.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct RouteTransport {
+    routes: Vec<(String, u16, String)>,
+    requests: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+#[cfg(test)]
+impl RouteTransport {
+    /// Answers requests matching `route`, such as `"POST /files"`, where the
+    /// path is relative to the base URL.
+    pub(crate) fn route(mut self, route: &str, status: u16, body: impl Into<String>) -> Self {
+        self.routes.push((route.to_string(), status, body.into()));
+        self
+    }
+
+    /// Returns the requests recorded so far.
+    pub(crate) fn requests(&self) -> std::sync::Arc<std::sync::Mutex<Vec<String>>> {
+        self.requests.clone()
+    }
+
+    /// Builds a client whose requests are answered by this transport.
+    pub(crate) fn client(self) -> super::PortkeyClient {
+        self.client_with_clock(std::sync::Arc::new(super::clock::SystemClock))
+    }
+
+    /// Builds a client whose requests are answered by this transport and
+    /// that reads time from the given clock.
+    pub(crate) fn client_with_clock(
+        self,
+        clock: std::sync::Arc<dyn super::clock::Clock>,
+    ) -> super::PortkeyClient {
+        let config = super::PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(super::auth::AuthMethod::virtual_key("vk-123"))
+            .with_transport(self)
+            .build()
+            .unwrap();
+        super::PortkeyClient::with_clock(config, clock).unwrap()
+    }
+}
+
+#[cfg(test)]
+impl HttpTransport for RouteTransport {
+    fn execute(&self, request: Request) -> TransportFuture<'_> {
+        let path = request.url().path().trim_start_matches("/v1");
+        let key = format!("{} {path}", request.method());
+        let query_key = request.url().query().map(|query| format!("{key}?{query}"));
+        let (status, body) = self
+            .routes
+            .iter()
+            .find(|(route, _, _)| Some(route) == query_key.as_ref())
+            .or_else(|| self.routes.iter().find(|(route, _, _)| *route == key))
+            .map_or((404, "{}".to_string()), |(_, status, body)| {
+                (*status, body.clone())
+            });
+        self.requests.lock().unwrap().push(key);
+
+        let response = http::Response::builder().status(status).body(body).unwrap();
+        Box::pin(async move { Ok(Response::from(response)) })
+    }
+}
+
+#[cfg(test)]
+impl HttpTransport for MockTransport {
+    fn execute(&self, _request: Request) -> TransportFuture<'_> {
+        let response = http::Response::builder()
+            .status(self.status)
+            .body(self.body)
+            .unwrap();
+        Box::pin(async move { Ok(Response::from(response)) })
+    }
+}
@@ -0,0 +1,121 @@
+//! Pseudonymization of end-user identifiers.
+
+use std::fmt;
+use std::fmt::Write;
+
+use ring::digest::{SHA256, digest};
+
+/// Hashes end-user identifiers before they are sent to providers.
+///
+/// The `user` field of a request is forwarded to the upstream provider for
+/// abuse monitoring. When a hasher is configured on the client, the SDK
+/// replaces that field with the hex-encoded SHA-256 digest of the salt
+/// followed by the raw identifier.
+///
+/// The same salt and input always produce the same hash, so providers can
+/// still correlate requests from one user for abuse detection without
+/// learning the raw identifier. Keep the salt secret and stable: changing
+/// it changes every hashed identifier.
+///
+/// # Example
+///
+/// ```no_run
+/// use portkey_sdk::PortkeyConfig;
+/// use portkey_sdk::builder::{AuthMethod, UserHasher};
+///
+/// let config = PortkeyConfig::builder()
+///     .with_api_key("your-api-key")
+///     .with_auth_method(AuthMethod::virtual_key("your-virtual-key"))
+///     .with_user_hasher(UserHasher::new("your-secret-salt"))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct UserHasher {
+    salt: String,
+}
+
+impl UserHasher {
+    /// Creates a hasher with the given salt.
+    pub fn new(salt: impl Into<String>) -> Self {
+        Self { salt: salt.into() }
+    }
+
+    /// Returns the hashed form of a raw user identifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::builder::UserHasher;
+    ///
+    /// let hasher = UserHasher::new("salt");
+    /// assert_eq!(hasher.hash("user-123"), hasher.hash("user-123"));
+    /// assert_ne!(hasher.hash("user-123"), hasher.hash("user-456"));
+    /// ```
+    pub fn hash(&self, user: &str) -> String {
+        let mut input = Vec::with_capacity(self.salt.len() + user.len());
+        input.extend_from_slice(self.salt.as_bytes());
+        input.extend_from_slice(user.as_bytes());
+
+        digest(&SHA256, &input)
+            .as_ref()
+            .iter()
+            .fold(String::with_capacity(64), |mut hex, byte| {
+                let _ = write!(hex, "{byte:02x}");
+                hex
+            })
+    }
+
+    /// Replaces the top-level `user` string of a JSON request body with its hash.
+    pub(crate) fn apply(&self, body: &mut serde_json::Value) {
+        if let Some(user) = body.get_mut("user")
+            && let Some(raw) = user.as_str()
+        {
+            *user = serde_json::Value::String(self.hash(raw));
+        }
+    }
+}
+
+impl fmt::Debug for UserHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserHasher").field("salt", &"****").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_is_salted_sha256() {
+        let hasher = UserHasher::new("salt");
+        assert_eq!(
+            hasher.hash("user-123"),
+            "858c795e6cac4609e401ced7dcc99ba8246b918a10f65cf9669215fd5061ce85"
+        );
+        assert_ne!(
+            hasher.hash("user-123"),
+            UserHasher::new("pepper").hash("user-123")
+        );
+    }
+
+    #[test]
+    fn test_apply_replaces_user_field() {
+        let hasher = UserHasher::new("salt");
+        let mut body = serde_json::json!({ "model": "gpt-4o", "user": "user-123" });
+        hasher.apply(&mut body);
+
+        assert_eq!(body["model"], "gpt-4o");
+        assert_eq!(body["user"], hasher.hash("user-123"));
+
+        let mut body = serde_json::json!({ "model": "gpt-4o" });
+        hasher.apply(&mut body);
+        assert!(body.get("user").is_none());
+    }
+
+    #[test]
+    fn test_debug_masks_salt() {
+        let debug = format!("{:?}", UserHasher::new("secret"));
+        assert!(!debug.contains("secret"));
+    }
+}
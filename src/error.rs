@@ -1,5 +1,9 @@
 //! Error types for the Portkey SDK.
 
+use std::fmt;
+
+use reqwest::StatusCode;
+
 use crate::builder::PortkeyBuilderError;
 
 /// Error type for Portkey API operations.
@@ -7,6 +11,25 @@ use crate::builder::PortkeyBuilderError;
 /// This enum represents all possible errors that can occur when using the Portkey SDK,
 /// from HTTP transport errors to API-specific failures and configuration issues.
 ///
+/// # Error chains
+///
+/// Errors that wrap a lower-level error, such as [`Error::Http`], return it
+/// from [`source`](std::error::Error::source) instead of repeating it in
+/// their message, so error reporters such as `anyhow` and `eyre` can walk
+/// down to the root cause. The alternate form (`{:#}`) renders the whole
+/// chain:
+///
+/// ```
+/// use std::error::Error as _;
+///
+/// use portkey_sdk::Error;
+///
+/// let error = Error::from(std::io::Error::other("disk full"));
+/// assert_eq!(error.to_string(), "I/O error");
+/// assert_eq!(format!("{error:#}"), "I/O error: disk full");
+/// assert!(error.source().is_some());
+/// ```
+///
 /// # Examples
 ///
 /// Handling different error types:
@@ -21,6 +44,7 @@ use crate::builder::PortkeyBuilderError;
 /// // Example error handling
 /// match client.list_models(None).await {
 ///     Ok(models) => println!("Found {} models", models.data.len()),
+///     Err(e) if e.is_status(429) => println!("Rate limited (trace: {:?})", e.trace_id()),
 ///     Err(Error::Http(e)) => println!("Network error: {}", e),
 ///     Err(Error::Config(e)) => println!("Configuration error: {}", e),
 ///     Err(e) => println!("Other error: {}", e),
@@ -35,32 +59,416 @@ pub enum Error {
     ///
     /// This includes network connectivity issues, DNS resolution failures,
     /// timeout errors, and other transport-layer problems.
-    #[error("HTTP error: {0}")]
     Http(#[from] reqwest::Error),
 
+    /// API error returned for a non-success HTTP status.
+    ///
+    /// This occurs when the request reached Portkey but was rejected by the
+    /// gateway or the upstream provider.
+    Api(ApiError),
+
+    /// I/O error.
+    ///
+    /// This occurs when reading or writing local files or streams fails.
+    Io(#[from] std::io::Error),
+
     /// JSON serialization/deserialization error.
     ///
     /// This occurs when the SDK fails to parse API responses or serialize
     /// request payloads to/from JSON.
-    #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
     /// Configuration error.
     ///
     /// This occurs when configuration parameters are invalid or when using
     /// the configuration builder and validation fails during the build process.
-    #[error("Configuration error: {0}")]
     Config(#[from] PortkeyBuilderError),
 
     /// URL parsing error.
     ///
     /// This occurs when a provided URL string is invalid or cannot be parsed.
-    #[error("URL parse error: {0}")]
     UrlParse(#[from] url::ParseError),
+
+    /// Streaming error.
+    ///
+    /// This occurs when a streamed response ends early or cannot be assembled
+    /// into a complete result.
+    Stream(String),
+
+    /// Request validation error.
+    ///
+    /// This occurs when the SDK rejects a request before sending it because
+    /// it would otherwise fail server-side, or when a request builder is
+    /// missing a required field.
+    Validation(String),
+
+    /// Unexpected response error.
+    ///
+    /// This occurs when strict response checking is enabled and a response
+    /// has a different `object` type than the endpoint returns, for example
+    /// because a proxy routed the request to the wrong upstream, or when a
+    /// convenience method such as
+    /// [`ChatService::chat_text`](crate::service::ChatService::chat_text)
+    /// gets a response without the content it returns, or a logged body
+    /// does not match the type requested from
+    /// [`Response::request_as`](crate::model::Response::request_as).
+    UnexpectedResponse(String),
+
+    /// Deadline exceeded error.
+    ///
+    /// This occurs when a request's
+    /// [`deadline`](crate::RequestOptions::with_deadline) has already passed
+    /// when it is about to be sent, or when its timeout runs out while it
+    /// waits for a slot under
+    /// [`max_concurrent_requests`](crate::builder::PortkeyBuilder::with_max_concurrent_requests),
+    /// so it is never issued.
+    DeadlineExceeded,
+}
+
+impl fmt::Display for Error {
+    /// Writes the error message; the alternate form (`{:#}`) also appends
+    /// each error in the [`source`](std::error::Error::source) chain.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Http(_) => f.write_str("HTTP error")?,
+            Self::Api(error) => write!(f, "API error: {error}")?,
+            Self::Io(_) => f.write_str("I/O error")?,
+            Self::Serialization(_) => f.write_str("Serialization error")?,
+            Self::Config(_) => f.write_str("Configuration error")?,
+            Self::UrlParse(_) => f.write_str("URL parse error")?,
+            Self::Stream(message) => write!(f, "Stream error: {message}")?,
+            Self::Validation(message) => write!(f, "Validation error: {message}")?,
+            Self::UnexpectedResponse(message) => write!(f, "Unexpected response: {message}")?,
+            Self::DeadlineExceeded => f.write_str("Deadline exceeded")?,
+        }
+
+        if f.alternate() {
+            let mut source = std::error::Error::source(self);
+            while let Some(error) = source {
+                write!(f, ": {error}")?;
+                source = error.source();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<derive_builder::UninitializedFieldError> for Error {
+    fn from(error: derive_builder::UninitializedFieldError) -> Self {
+        Self::Validation(format!("missing required field `{}`", error.field_name()))
+    }
+}
+
+impl Error {
+    /// Returns `true` if the error was caused by a timeout or an expired
+    /// deadline.
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Self::Http(e) => e.is_timeout(),
+            Self::Io(e) => e.kind() == std::io::ErrorKind::TimedOut,
+            Self::DeadlineExceeded => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the error carries the given HTTP status code.
+    pub fn is_status(&self, code: u16) -> bool {
+        self.status().is_some_and(|status| status.as_u16() == code)
+    }
+
+    /// Returns `true` if the error is a `404 Not Found` response.
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// Returns the HTTP status code associated with the error, if any.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Api(e) => Some(e.status()),
+            Self::Http(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    /// Returns the Portkey trace ID of the failed request, if known.
+    pub fn trace_id(&self) -> Option<&str> {
+        match self {
+            Self::Api(e) => e.trace_id(),
+            _ => None,
+        }
+    }
+
+    /// Returns the resolved URL of the failed request, if known.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Self::Api(e) => Some(e.url()),
+            Self::Http(e) => e.url().map(url::Url::as_str),
+            _ => None,
+        }
+    }
 }
 
+/// Error response returned by the Portkey API.
+///
+/// Bodies of the form `{"error": {"message", "type", "code", "param"}}` are
+/// parsed into the corresponding accessors, so failures can be matched on:
+///
+/// ```no_run
+/// # use portkey_sdk::{Error, PortkeyClient, Result};
+/// # use portkey_sdk::service::ModelsService;
+/// # async fn example(client: PortkeyClient) -> Result<()> {
+/// match client.list_models(None).await {
+///     Err(Error::Api(e)) if e.code() == Some("invalid_api_key") => {
+///         println!("Check the API key: {}", e.message().unwrap_or_default());
+///     }
+///     result => {
+///         result?;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The raw body is always available from [`body`](Self::body), including
+/// when it has a different shape.
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    status: StatusCode,
+    url: String,
+    trace_id: Option<String>,
+    body: String,
+    details: Option<Box<ErrorDetails>>,
+    attempts: u32,
+}
+
+/// Fields of the `error` object of an API error body.
+#[derive(Debug, Clone)]
+struct ErrorDetails {
+    message: Option<String>,
+    error_type: Option<String>,
+    code: Option<String>,
+    param: Option<String>,
+}
+
+impl ApiError {
+    /// Creates a new API error, parsing the error object of the body.
+    pub(crate) fn new(
+        status: StatusCode,
+        url: String,
+        trace_id: Option<String>,
+        body: String,
+    ) -> Self {
+        let error = serde_json::from_str::<serde_json::Value>(&body)
+            .ok()
+            .and_then(|mut value| value.get_mut("error").map(serde_json::Value::take));
+        // Codes are strings for most providers but numbers for some.
+        let field = |key: &str| match error.as_ref()?.get(key)? {
+            serde_json::Value::String(value) => Some(value.clone()),
+            serde_json::Value::Number(value) => Some(value.to_string()),
+            _ => None,
+        };
+
+        Self {
+            status,
+            url,
+            trace_id,
+            details: error.is_some().then(|| {
+                Box::new(ErrorDetails {
+                    message: field("message"),
+                    error_type: field("type"),
+                    code: field("code"),
+                    param: field("param"),
+                })
+            }),
+            body,
+            attempts: 1,
+        }
+    }
+
+    /// Sets how many times the request was sent.
+    pub(crate) fn with_attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts;
+        self
+    }
+
+    /// Returns the HTTP status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns the resolved URL of the request.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the `x-portkey-trace-id` of the response, if present.
+    pub fn trace_id(&self) -> Option<&str> {
+        self.trace_id.as_deref()
+    }
+
+    /// Returns the raw response body.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Returns the `error.message` of the response body, if present.
+    pub fn message(&self) -> Option<&str> {
+        self.details.as_ref()?.message.as_deref()
+    }
+
+    /// Returns the `error.type` of the response body, if present.
+    pub fn error_type(&self) -> Option<&str> {
+        self.details.as_ref()?.error_type.as_deref()
+    }
+
+    /// Returns the `error.code` of the response body, if present.
+    ///
+    /// Numeric codes are returned in their decimal form.
+    pub fn code(&self) -> Option<&str> {
+        self.details.as_ref()?.code.as_deref()
+    }
+
+    /// Returns the `error.param` of the response body, if present.
+    pub fn param(&self) -> Option<&str> {
+        self.details.as_ref()?.param.as_deref()
+    }
+
+    /// Returns how many times the request was sent, including retries.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} from {}", self.status, self.url)?;
+        if self.attempts > 1 {
+            write!(f, " after {} attempts", self.attempts)?;
+        }
+        match self.message() {
+            Some(message) => write!(f, ": {message}")?,
+            None if !self.body.is_empty() => write!(f, ": {}", self.body)?,
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
 /// Result type for Portkey API operations.
 ///
 /// This is a convenience type alias for `std::result::Result<T, Error>` that is used
 /// throughout the Portkey SDK. All SDK methods that can fail return this Result type.
 pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_error_classification() {
+        let error = Error::Api(ApiError::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            "https://api.portkey.ai/v1/chat/completions".to_string(),
+            Some("trace-123".to_string()),
+            r#"{"error":{"message":"Rate limit exceeded"}}"#.to_string(),
+        ));
+
+        assert!(error.is_status(429));
+        assert!(!error.is_status(500));
+        assert_eq!(error.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+        assert_eq!(error.trace_id(), Some("trace-123"));
+        assert_eq!(
+            error.url(),
+            Some("https://api.portkey.ai/v1/chat/completions")
+        );
+        assert!(!error.is_timeout());
+        assert!(error.to_string().contains("429 Too Many Requests"));
+    }
+
+    #[test]
+    fn test_api_error_body_parsing() {
+        let error = ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "https://api.portkey.ai/v1/chat/completions".to_string(),
+            None,
+            r#"{"error":{"message":"Invalid model","type":"invalid_request_error","code":"model_not_found","param":"model"}}"#.to_string(),
+        );
+        assert_eq!(error.message(), Some("Invalid model"));
+        assert_eq!(error.error_type(), Some("invalid_request_error"));
+        assert_eq!(error.code(), Some("model_not_found"));
+        assert_eq!(error.param(), Some("model"));
+        assert_eq!(
+            error.to_string(),
+            "400 Bad Request from https://api.portkey.ai/v1/chat/completions: Invalid model"
+        );
+
+        let error = ApiError::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            String::new(),
+            None,
+            r#"{"error":{"message":"Slow down","code":429,"param":null}}"#.to_string(),
+        );
+        assert_eq!(error.code(), Some("429"));
+        assert_eq!(error.param(), None);
+
+        let error = ApiError::new(
+            StatusCode::BAD_GATEWAY,
+            String::new(),
+            None,
+            "upstream unavailable".to_string(),
+        );
+        assert_eq!(error.message(), None);
+        assert_eq!(error.code(), None);
+        assert_eq!(error.body(), "upstream unavailable");
+        assert!(error.to_string().ends_with(": upstream unavailable"));
+    }
+
+    #[test]
+    fn test_io_error_classification() {
+        let error = Error::from(std::io::Error::from(std::io::ErrorKind::TimedOut));
+
+        assert!(error.is_timeout());
+        assert_eq!(error.status(), None);
+        assert_eq!(error.trace_id(), None);
+        assert_eq!(error.url(), None);
+    }
+
+    #[test]
+    fn test_transport_error_source_chain() {
+        use std::error::Error as _;
+
+        // An invalid URL fails while building the request, without a network.
+        let transport_error = reqwest::Client::new().get("not a url").build().unwrap_err();
+        let error = Error::from(transport_error);
+
+        let source = error.source().expect("transport errors have a source");
+        assert!(source.is::<reqwest::Error>());
+        assert_eq!(error.to_string(), "HTTP error");
+        assert_eq!(
+            format!("{error:#}"),
+            format!("HTTP error: {source}: {}", source.source().unwrap())
+        );
+
+        let root = source.source().expect("builder errors have a source");
+        assert!(root.is::<url::ParseError>());
+    }
+
+    #[test]
+    fn test_error_chain_rendering() {
+        let error = Error::from(serde_json::from_str::<u32>("x").unwrap_err());
+        assert!(std::error::Error::source(&error).is_some());
+        assert_eq!(error.to_string(), "Serialization error");
+        assert_eq!(
+            format!("{error:#}"),
+            "Serialization error: expected value at line 1 column 1"
+        );
+
+        let error = Error::Validation("bad input".to_string());
+        assert!(std::error::Error::source(&error).is_none());
+        assert_eq!(error.to_string(), "Validation error: bad input");
+        assert_eq!(format!("{error:#}"), "Validation error: bad input");
+    }
+}
@@ -13,12 +13,14 @@ compile_error!(
 mod client;
 mod error;
 pub mod model;
-#[doc(hidden)]
 pub mod prelude;
 pub mod service;
 
-pub use client::{PortkeyClient, PortkeyConfig, builder};
-pub use error::{Error, Result};
+pub use client::{
+    ByteStream, EventStream, GatewayConfig, Paginated, PortkeyClient, PortkeyConfig,
+    RequestOptions, builder, collect_all, ndjson_stream, save_while_streaming,
+};
+pub use error::{ApiError, Error, Result};
 
 /// Tracing target for client-level operations (HTTP requests, client creation).
 #[cfg(feature = "tracing")]
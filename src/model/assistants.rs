@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -9,8 +10,8 @@ use super::chat::ResponseFormat;
 ///
 /// # Example
 ///
-/// ```rust,ignore
-/// use portkey::model::CreateAssistantRequest;
+/// ```
+/// use portkey_sdk::model::CreateAssistantRequest;
 ///
 /// let request = CreateAssistantRequest::builder()
 ///     .model("gpt-4")
@@ -18,51 +19,85 @@ use super::chat::ResponseFormat;
 ///     .instructions("You are a helpful math tutor.")
 ///     .build()
 ///     .unwrap();
+/// assert_eq!(request.name.as_deref(), Some("Math Tutor"));
 /// ```
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(
+    pattern = "owned",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
+#[builder_impl_attr(allow(deprecated))]
 pub struct CreateAssistantRequest {
     /// ID of the model to use.
     pub model: String,
 
     /// The name of the assistant.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub name: Option<String>,
 
     /// The description of the assistant.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub description: Option<String>,
 
     /// The system instructions that the assistant uses.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub instructions: Option<String>,
 
     /// A list of tool enabled on the assistant.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub tools: Option<Vec<AssistantTool>>,
 
     /// A list of file IDs attached to this assistant.
+    ///
+    /// Ignored by the Assistants v2 API, use `tool_resources` instead.
+    #[deprecated(note = "use `tool_resources` instead")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(skip))]
     pub file_ids: Option<Vec<String>>,
 
+    /// Resources made available to the assistant's tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub tool_resources: Option<ToolResources>,
+
     /// Set of key-value pairs that can be attached to an object.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub metadata: Option<HashMap<String, String>>,
 
     /// What sampling temperature to use, between 0 and 2.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub temperature: Option<f32>,
 
     /// An alternative to sampling with temperature.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub top_p: Option<f32>,
 
     /// Specifies the format that the model must output.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub response_format: Option<ResponseFormat>,
 }
 
+impl CreateAssistantRequest {
+    /// Creates a new assistant request builder.
+    ///
+    /// `build` fails with [`Error::Validation`](crate::Error::Validation) if
+    /// a required field is not set.
+    pub fn builder() -> CreateAssistantRequestBuilder {
+        CreateAssistantRequestBuilder::default()
+    }
+}
+
 /// Modifies an existing assistant.
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ModifyAssistantRequest {
     /// ID of the model to use.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -85,9 +120,16 @@ pub struct ModifyAssistantRequest {
     pub tools: Option<Vec<AssistantTool>>,
 
     /// A list of file IDs attached to this assistant.
+    ///
+    /// Ignored by the Assistants v2 API, use `tool_resources` instead.
+    #[deprecated(note = "use `tool_resources` instead")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file_ids: Option<Vec<String>>,
 
+    /// Resources made available to the assistant's tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
     /// Set of key-value pairs that can be attached to an object.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, String>>,
@@ -106,7 +148,7 @@ pub struct ModifyAssistantRequest {
 }
 
 /// An assistant object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Assistant {
     /// The identifier of the assistant.
     pub id: String,
@@ -133,8 +175,16 @@ pub struct Assistant {
     pub tools: Vec<AssistantTool>,
 
     /// A list of file IDs attached to this assistant.
+    ///
+    /// Not returned by the Assistants v2 API, see `tool_resources` instead.
+    #[deprecated(note = "use `tool_resources` instead")]
+    #[serde(default)]
     pub file_ids: Vec<String>,
 
+    /// Resources made available to the assistant's tools.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
     /// Set of key-value pairs that can be attached to an object.
     pub metadata: HashMap<String, String>,
 
@@ -149,19 +199,79 @@ pub struct Assistant {
 }
 
 /// Tool enabled on an assistant.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum AssistantTool {
     #[serde(rename = "code_interpreter")]
     CodeInterpreter,
+    /// Retrieval tool of the Assistants v1 API.
+    #[deprecated(note = "use `FileSearch` with the Assistants v2 API")]
     #[serde(rename = "retrieval")]
     Retrieval,
+    #[serde(rename = "file_search")]
+    FileSearch,
     #[serde(rename = "function")]
     Function { function: FunctionDefinition },
 }
 
+/// Resources made available to the tools of an assistant or thread.
+///
+/// Replaces the `file_ids` of the Assistants v1 API.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolResources {
+    /// Files available to the `code_interpreter` tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_interpreter: Option<CodeInterpreterResources>,
+
+    /// Vector stores available to the `file_search` tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_search: Option<FileSearchResources>,
+}
+
+impl ToolResources {
+    /// Makes files available to the `code_interpreter` tool.
+    pub fn with_code_interpreter_files<I, S>(mut self, file_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.code_interpreter = Some(CodeInterpreterResources {
+            file_ids: file_ids.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Makes vector stores available to the `file_search` tool.
+    pub fn with_vector_stores<I, S>(mut self, vector_store_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.file_search = Some(FileSearchResources {
+            vector_store_ids: vector_store_ids.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+}
+
+/// Files available to the `code_interpreter` tool.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CodeInterpreterResources {
+    /// A list of file IDs made available to the tool.
+    #[serde(default)]
+    pub file_ids: Vec<String>,
+}
+
+/// Vector stores available to the `file_search` tool.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileSearchResources {
+    /// A list of vector store IDs made available to the tool.
+    #[serde(default)]
+    pub vector_store_ids: Vec<String>,
+}
+
 /// Definition of a function tool.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FunctionDefinition {
     /// The name of the function to be called.
     pub name: String,
@@ -174,7 +284,7 @@ pub struct FunctionDefinition {
 }
 
 /// Response containing a list of assistants.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListAssistantsResponse {
     pub object: String,
     pub data: Vec<Assistant>,
@@ -184,7 +294,7 @@ pub struct ListAssistantsResponse {
 }
 
 /// Response from deleting an assistant.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeleteAssistantResponse {
     pub id: String,
     pub object: String,
@@ -192,7 +302,7 @@ pub struct DeleteAssistantResponse {
 }
 
 /// An assistant file object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AssistantFile {
     /// The identifier of the assistant file.
     pub id: String,
@@ -208,14 +318,14 @@ pub struct AssistantFile {
 }
 
 /// Request to create an assistant file.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateAssistantFileRequest {
     /// A File ID that the assistant should use.
     pub file_id: String,
 }
 
 /// Response containing a list of assistant files.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListAssistantFilesResponse {
     pub object: String,
     pub data: Vec<AssistantFile>,
@@ -225,9 +335,63 @@ pub struct ListAssistantFilesResponse {
 }
 
 /// Response from deleting an assistant file.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeleteAssistantFileResponse {
     pub id: String,
     pub object: String,
     pub deleted: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_assistant_tool_resources() {
+        let request = CreateAssistantRequest {
+            model: "gpt-4o".to_string(),
+            tools: Some(vec![AssistantTool::FileSearch]),
+            tool_resources: Some(
+                ToolResources::default()
+                    .with_code_interpreter_files(["file-abc123"])
+                    .with_vector_stores(["vs_abc123"]),
+            ),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["tools"][0]["type"], "file_search");
+        assert_eq!(
+            json["tool_resources"]["code_interpreter"]["file_ids"][0],
+            "file-abc123"
+        );
+        assert_eq!(
+            json["tool_resources"]["file_search"]["vector_store_ids"][0],
+            "vs_abc123"
+        );
+        assert!(json.get("file_ids").is_none());
+    }
+
+    #[test]
+    fn test_assistant_v2_deserialization() {
+        let assistant: Assistant = serde_json::from_value(serde_json::json!({
+            "id": "asst_abc123",
+            "object": "assistant",
+            "created_at": 1698984975,
+            "name": "Math Tutor",
+            "description": null,
+            "model": "gpt-4o",
+            "instructions": null,
+            "tools": [{"type": "file_search"}],
+            "tool_resources": {"file_search": {"vector_store_ids": ["vs_abc123"]}},
+            "metadata": {},
+            "temperature": 1.0,
+            "top_p": 1.0,
+            "response_format": "auto"
+        }))
+        .unwrap();
+
+        let file_search = assistant.tool_resources.unwrap().file_search.unwrap();
+        assert_eq!(file_search.vector_store_ids, ["vs_abc123"]);
+    }
+}
@@ -5,7 +5,17 @@
 use serde::{Deserialize, Serialize};
 
 /// Response format for audio transcription.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Default
+)]
 #[serde(rename_all = "snake_case")]
 pub enum TranscriptionResponseFormat {
     /// JSON format with just the transcribed text.
@@ -22,7 +32,7 @@ pub enum TranscriptionResponseFormat {
 }
 
 /// Timestamp granularity for verbose transcription.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum TimestampGranularity {
     /// Word-level timestamps (incurs additional latency).
@@ -46,7 +56,7 @@ pub enum TimestampGranularity {
 ///     ..Default::default()
 /// };
 /// ```
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateTranscriptionRequest {
     /// ID of the model to use.
     ///
@@ -88,7 +98,7 @@ pub struct CreateTranscriptionRequest {
 }
 
 /// A word with timestamp information.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TranscriptionWord {
     /// The text content of the word.
     pub word: String,
@@ -101,7 +111,7 @@ pub struct TranscriptionWord {
 }
 
 /// A segment of transcribed text with metadata.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TranscriptionSegment {
     /// Unique identifier of the segment.
     pub id: i32,
@@ -144,7 +154,7 @@ pub struct TranscriptionSegment {
 /// Simple JSON transcription response.
 ///
 /// Contains only the transcribed text.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateTranscriptionResponseJson {
     /// The transcribed text.
     pub text: String,
@@ -153,7 +163,7 @@ pub struct CreateTranscriptionResponseJson {
 /// Verbose JSON transcription response.
 ///
 /// Contains the transcribed text along with additional metadata, segments, and optional word-level timestamps.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateTranscriptionResponseVerboseJson {
     /// The language of the input audio.
     pub language: String,
@@ -180,7 +190,7 @@ pub struct CreateTranscriptionResponseVerboseJson {
 /// Response from audio transcription.
 ///
 /// The structure depends on the `response_format` specified in the request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum TranscriptionResponse {
     /// Simple JSON response with just the text.
@@ -189,15 +199,46 @@ pub enum TranscriptionResponse {
     VerboseJson(CreateTranscriptionResponseVerboseJson),
 }
 
+/// An event in a streamed transcription.
+///
+/// Streaming is supported by the `gpt-4o-transcribe` family of models.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum TranscriptionStreamEvent {
+    /// A piece of the transcript was recognized.
+    #[serde(rename = "transcript.text.delta")]
+    Delta {
+        /// The newly transcribed text.
+        delta: String,
+    },
+    /// The transcription is complete.
+    #[serde(rename = "transcript.text.done")]
+    Done(TranscriptionResponse),
+    /// An event type not modeled by this SDK.
+    #[serde(other)]
+    Other,
+}
+
 // ============================================================================
 // Speech (Text-to-Speech) Models
 // ============================================================================
 
 /// Voice options for text-to-speech generation.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Default
+)]
 #[serde(rename_all = "lowercase")]
 pub enum Voice {
-    /// Alloy voice
+    /// Alloy voice (default)
+    #[default]
     Alloy,
     /// Echo voice
     Echo,
@@ -212,7 +253,17 @@ pub enum Voice {
 }
 
 /// Audio format for speech output.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Default
+)]
 #[serde(rename_all = "lowercase")]
 pub enum SpeechResponseFormat {
     /// MP3 format (default)
@@ -230,6 +281,32 @@ pub enum SpeechResponseFormat {
     Pcm,
 }
 
+impl SpeechResponseFormat {
+    /// Returns `true` if audio in this format can be joined by appending the
+    /// bytes of separately generated clips.
+    ///
+    /// MP3 frames and raw PCM samples can be appended. The other formats carry
+    /// a container header, so appended clips do not play as a single file.
+    pub fn is_concatenable(self) -> bool {
+        matches!(self, Self::Mp3 | Self::Pcm)
+    }
+
+    /// Returns the media type of audio in this format.
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Mp3 => "audio/mpeg",
+            Self::Opus => "audio/opus",
+            Self::Aac => "audio/aac",
+            Self::Flac => "audio/flac",
+            Self::Wav => "audio/wav",
+            Self::Pcm => "audio/pcm",
+        }
+    }
+}
+
+/// The maximum number of characters in [`CreateSpeechRequest::input`].
+pub const MAX_SPEECH_INPUT_CHARS: usize = 4096;
+
 /// Request for creating speech from text.
 ///
 /// # Example
@@ -245,14 +322,16 @@ pub enum SpeechResponseFormat {
 ///     speed: Some(1.0),
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateSpeechRequest {
     /// TTS model to use (e.g., "tts-1" or "tts-1-hd")
     pub model: String,
 
     /// The text to generate audio for.
     ///
-    /// Maximum length is 4096 characters.
+    /// Maximum length is 4096 characters. Use
+    /// [`AudioService::create_speech_long`](crate::service::AudioService::create_speech_long)
+    /// for longer text.
     pub input: String,
 
     /// The voice to use for generation.
@@ -287,7 +366,7 @@ pub struct CreateSpeechRequest {
 ///     temperature: Some(0.0),
 /// };
 /// ```
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateTranslationRequest {
     /// ID of the model to use.
     ///
@@ -314,14 +393,14 @@ pub struct CreateTranslationRequest {
 }
 
 /// Simple JSON translation response.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateTranslationResponseJson {
     /// The translated text (always in English).
     pub text: String,
 }
 
 /// Verbose JSON translation response.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateTranslationResponseVerboseJson {
     /// The language of the output translation (always "english").
     pub language: String,
@@ -340,7 +419,7 @@ pub struct CreateTranslationResponseVerboseJson {
 /// Response from audio translation.
 ///
 /// The structure depends on the `response_format` specified in the request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum TranslationResponse {
     /// Simple JSON response with just the text.
@@ -393,4 +472,37 @@ mod tests {
         assert_eq!(request.language, Some("en".to_string()));
         assert_eq!(request.temperature, Some(0.0));
     }
+
+    #[test]
+    fn test_create_speech_request_default() {
+        let request = CreateSpeechRequest {
+            model: "tts-1".to_string(),
+            input: "Hello".to_string(),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["voice"], "alloy");
+        assert!(json.get("response_format").is_none());
+    }
+
+    #[test]
+    fn test_transcription_stream_event() {
+        let event: TranscriptionStreamEvent =
+            serde_json::from_str(r#"{"type":"transcript.text.delta","delta":"Hel"}"#).unwrap();
+        assert!(matches!(event, TranscriptionStreamEvent::Delta { ref delta } if delta == "Hel"));
+
+        let event: TranscriptionStreamEvent = serde_json::from_str(
+            r#"{"type":"transcript.text.done","text":"Hello world","usage":{"total_tokens":12}}"#,
+        )
+        .unwrap();
+        let TranscriptionStreamEvent::Done(TranscriptionResponse::Json(response)) = event else {
+            panic!("expected a done event, got {event:?}");
+        };
+        assert_eq!(response.text, "Hello world");
+
+        let event: TranscriptionStreamEvent =
+            serde_json::from_str(r#"{"type":"transcript.text.segment"}"#).unwrap();
+        assert!(matches!(event, TranscriptionStreamEvent::Other));
+    }
 }
@@ -1,52 +1,200 @@
 use std::collections::HashMap;
+use std::fmt;
 
-use serde::{Deserialize, Serialize};
+use derive_builder::Builder;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The time frame within which a batch should be processed.
+///
+/// The API currently only supports `24h`. [`CompletionWindow::Custom`] is kept
+/// for forward compatibility with windows the API may add later and is sent verbatim.
+///
+/// # Example
+///
+/// ```
+/// use portkey_sdk::model::CompletionWindow;
+///
+/// assert_eq!(CompletionWindow::from(" 24H "), CompletionWindow::TwentyFourHours);
+/// assert_eq!(CompletionWindow::TwentyFourHours.as_str(), "24h");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum CompletionWindow {
+    /// Process the batch within 24 hours (`"24h"`).
+    #[default]
+    TwentyFourHours,
+    /// Any other completion window, not yet known to this SDK.
+    Custom(String),
+}
+
+impl CompletionWindow {
+    /// Returns the wire representation of the completion window.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::TwentyFourHours => "24h",
+            Self::Custom(window) => window,
+        }
+    }
+
+    /// Returns `true` if this window is known to be supported by the API.
+    pub fn is_supported(&self) -> bool {
+        matches!(self, Self::TwentyFourHours)
+    }
+}
+
+impl fmt::Display for CompletionWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for CompletionWindow {
+    /// Normalizes surrounding whitespace and case, so `" 24H "` maps to
+    /// [`CompletionWindow::TwentyFourHours`].
+    fn from(window: &str) -> Self {
+        let normalized = window.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            "24h" => Self::TwentyFourHours,
+            _ => Self::Custom(normalized),
+        }
+    }
+}
+
+impl From<String> for CompletionWindow {
+    fn from(window: String) -> Self {
+        Self::from(window.as_str())
+    }
+}
+
+impl Serialize for CompletionWindow {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CompletionWindow {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// The endpoint used for all requests in a batch.
+///
+/// Every line of the batch input file must target the same endpoint.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize
+)]
+pub enum BatchEndpoint {
+    /// Chat completions (`/v1/chat/completions`).
+    #[default]
+    #[serde(rename = "/v1/chat/completions")]
+    ChatCompletions,
+    /// Embeddings (`/v1/embeddings`).
+    #[serde(rename = "/v1/embeddings")]
+    Embeddings,
+    /// Legacy completions (`/v1/completions`).
+    #[serde(rename = "/v1/completions")]
+    Completions,
+}
+
+impl BatchEndpoint {
+    /// Returns the endpoint path as sent to the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ChatCompletions => "/v1/chat/completions",
+            Self::Embeddings => "/v1/embeddings",
+            Self::Completions => "/v1/completions",
+        }
+    }
+}
+
+impl fmt::Display for BatchEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 /// Request to create a batch.
 ///
 /// # Example
 ///
-/// ```rust,ignore
-/// use portkey::model::CreateBatchRequest;
+/// ```
+/// use portkey_sdk::model::{BatchEndpoint, CompletionWindow, CreateBatchRequest};
 ///
 /// let request = CreateBatchRequest::builder()
 ///     .input_file_id("file-abc123")
-///     .endpoint("/v1/chat/completions")
-///     .completion_window("24h")
+///     .endpoint(BatchEndpoint::ChatCompletions)
 ///     .build()
 ///     .unwrap();
+/// assert_eq!(request.completion_window, CompletionWindow::TwentyFourHours);
 /// ```
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(
+    pattern = "owned",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
 pub struct CreateBatchRequest {
     /// The ID of an uploaded file that contains requests for the new batch.
     pub input_file_id: String,
 
     /// The endpoint to be used for all requests in the batch.
-    /// Currently supported: /v1/chat/completions, /v1/embeddings, /v1/completions
-    pub endpoint: String,
+    pub endpoint: BatchEndpoint,
 
     /// The time frame within which the batch should be processed.
     /// Currently only "24h" is supported.
-    pub completion_window: String,
+    #[builder(default)]
+    pub completion_window: CompletionWindow,
 
     /// Optional custom metadata for the batch.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub metadata: Option<HashMap<String, String>>,
 }
 
-impl Default for CreateBatchRequest {
-    fn default() -> Self {
+impl CreateBatchRequest {
+    /// Creates a new batch request builder.
+    ///
+    /// `build` fails with [`Error::Validation`](crate::Error::Validation) if
+    /// a required field is not set.
+    pub fn builder() -> CreateBatchRequestBuilder {
+        CreateBatchRequestBuilder::default()
+    }
+
+    /// Creates a new batch request with the default 24 hour completion window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::{BatchEndpoint, CreateBatchRequest};
+    ///
+    /// let request = CreateBatchRequest::new("file-abc123", BatchEndpoint::ChatCompletions);
+    /// ```
+    pub fn new(input_file_id: impl Into<String>, endpoint: BatchEndpoint) -> Self {
         Self {
-            input_file_id: String::new(),
-            endpoint: String::new(),
-            completion_window: "24h".to_string(),
+            input_file_id: input_file_id.into(),
+            endpoint,
+            completion_window: CompletionWindow::TwentyFourHours,
             metadata: None,
         }
     }
 }
 
+impl Default for CreateBatchRequest {
+    fn default() -> Self {
+        Self::new(String::new(), BatchEndpoint::default())
+    }
+}
+
 /// The batch object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Batch {
     /// The batch identifier.
     pub id: String,
@@ -123,7 +271,7 @@ pub struct Batch {
 }
 
 /// Errors that occurred during batch processing.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchErrors {
     /// The object type.
     pub object: String,
@@ -133,7 +281,7 @@ pub struct BatchErrors {
 }
 
 /// A single error in a batch.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchError {
     /// An error code identifying the error type.
     pub code: String,
@@ -151,7 +299,7 @@ pub struct BatchError {
 }
 
 /// Request counts for different statuses within the batch.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchRequestCounts {
     /// Total number of requests in the batch.
     pub total: i64,
@@ -163,8 +311,61 @@ pub struct BatchRequestCounts {
     pub failed: i64,
 }
 
+/// A single line of a batch output or error file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchResultLine {
+    /// The identifier of the result line.
+    pub id: String,
+
+    /// The `custom_id` of the request this line answers.
+    pub custom_id: String,
+
+    /// The response to the request, if one was received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<BatchResultResponse>,
+
+    /// The error for the request, if it could not be completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<BatchResultError>,
+}
+
+impl BatchResultLine {
+    /// Returns `true` if the request received a successful response.
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+            && self
+                .response
+                .as_ref()
+                .is_some_and(|response| (200..300).contains(&response.status_code))
+    }
+}
+
+/// The response to a single request in a batch.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchResultResponse {
+    /// The HTTP status code of the response.
+    pub status_code: u16,
+
+    /// The unique identifier of the request.
+    pub request_id: String,
+
+    /// The JSON body of the response.
+    pub body: serde_json::Value,
+}
+
+/// An error for a single request in a batch.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchResultError {
+    /// An error code identifying the error type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// A human-readable message providing more details about the error.
+    pub message: String,
+}
+
 /// Response containing a list of batches.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListBatchesResponse {
     pub object: String,
     pub data: Vec<Batch>,
@@ -172,3 +373,42 @@ pub struct ListBatchesResponse {
     pub last_id: Option<String>,
     pub has_more: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_completion_window_serialization() {
+        let json = serde_json::to_string(&CompletionWindow::TwentyFourHours).unwrap();
+        assert_eq!(json, "\"24h\"");
+
+        let json = serde_json::to_string(&CompletionWindow::Custom("48h".to_string())).unwrap();
+        assert_eq!(json, "\"48h\"");
+    }
+
+    #[test]
+    fn test_completion_window_deserialization() {
+        let window: CompletionWindow = serde_json::from_str("\"24h\"").unwrap();
+        assert_eq!(window, CompletionWindow::TwentyFourHours);
+
+        let window: CompletionWindow = serde_json::from_str("\"7d\"").unwrap();
+        assert_eq!(window, CompletionWindow::Custom("7d".to_string()));
+        assert!(!window.is_supported());
+    }
+
+    #[test]
+    fn test_batch_endpoint_serialization() {
+        let endpoint: BatchEndpoint = serde_json::from_str("\"/v1/completions\"").unwrap();
+        assert_eq!(endpoint, BatchEndpoint::Completions);
+        assert!(serde_json::from_str::<BatchEndpoint>("\"/v1/images\"").is_err());
+    }
+
+    #[test]
+    fn test_create_batch_request_new() {
+        let request = CreateBatchRequest::new("file-abc123", BatchEndpoint::Embeddings);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["endpoint"], "/v1/embeddings");
+        assert_eq!(json["completion_window"], "24h");
+    }
+}
@@ -3,12 +3,17 @@
 //! This module contains all data models for chat completions, including
 //! request and response types following the OpenAI-compatible format.
 
+use std::path::Path;
+
+use base64::Engine;
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "strum")]
 use strum::{Display, EnumString};
 
+use super::PortkeyResponse;
+
 /// A chat completion message in a conversation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "role", rename_all = "lowercase")]
 pub enum ChatCompletionRequestMessage {
     /// System message that sets the behavior of the assistant
@@ -116,10 +121,53 @@ impl ChatCompletionRequestMessage {
             function_call: None,
         }
     }
+
+    /// Creates an assistant message that only contains tool calls.
+    ///
+    /// The message has no text content, so `content` is omitted when serialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::{ChatCompletionMessageToolCall, ChatCompletionRequestMessage, FunctionCall};
+    ///
+    /// let msg = ChatCompletionRequestMessage::assistant_tool_calls(vec![ChatCompletionMessageToolCall {
+    ///     id: "call_abc123".to_string(),
+    ///     tool_type: "function".to_string(),
+    ///     function: FunctionCall {
+    ///         name: "get_weather".to_string(),
+    ///         arguments: r#"{"location":"Paris"}"#.to_string(),
+    ///     },
+    /// }]);
+    /// ```
+    pub fn assistant_tool_calls(tool_calls: Vec<ChatCompletionMessageToolCall>) -> Self {
+        Self::Assistant {
+            content: None,
+            name: None,
+            tool_calls: Some(tool_calls),
+            function_call: None,
+        }
+    }
+
+    /// Creates a tool message with the result of a tool call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::ChatCompletionRequestMessage;
+    ///
+    /// let msg = ChatCompletionRequestMessage::tool("call_abc123", r#"{"temperature":22}"#);
+    /// ```
+    pub fn tool(tool_call_id: impl Into<String>, content: impl Into<String>) -> Self {
+        Self::Tool {
+            content: content.into(),
+            tool_call_id: tool_call_id.into(),
+        }
+    }
 }
 
 /// Content of a user message (can be text or multimodal)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ChatCompletionUserMessageContent {
     /// Plain text content
@@ -129,7 +177,7 @@ pub enum ChatCompletionUserMessageContent {
 }
 
 /// A content part in a multimodal message
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ChatCompletionContentPart {
     /// Text content part
@@ -144,8 +192,91 @@ pub enum ChatCompletionContentPart {
     },
 }
 
+impl ChatCompletionContentPart {
+    /// Creates a text content part.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
+
+    /// Creates an image content part from a URL or data URL.
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self::ImageUrl {
+            image_url: ImageUrl {
+                url: url.into(),
+                detail: None,
+            },
+        }
+    }
+
+    /// Creates an image content part from raw image bytes.
+    ///
+    /// The bytes are base64-encoded into a `data:<mime>;base64,...` URL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::ChatCompletionContentPart;
+    ///
+    /// let part = ChatCompletionContentPart::image_from_bytes(b"\x89PNG", "image/png");
+    /// ```
+    pub fn image_from_bytes(bytes: impl AsRef<[u8]>, mime: &str) -> Self {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
+        Self::image_url(format!("data:{mime};base64,{encoded}"))
+    }
+
+    /// Creates an image content part from an image file.
+    ///
+    /// The MIME type is inferred from the file extension (`png`, `jpg`,
+    /// `jpeg`, `gif` or `webp`). The file is read synchronously.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`](crate::Error::Validation) if the extension
+    /// is not a supported image type, or an I/O error if the file cannot be read.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use portkey_sdk::model::{
+    ///     ChatCompletionContentPart, ChatCompletionRequestMessage, ChatCompletionUserMessageContent,
+    /// };
+    ///
+    /// # fn example() -> portkey_sdk::Result<()> {
+    /// let message = ChatCompletionRequestMessage::User {
+    ///     content: ChatCompletionUserMessageContent::Parts(vec![
+    ///         ChatCompletionContentPart::text("What is in this image?"),
+    ///         ChatCompletionContentPart::image_from_path("photo.jpg")?,
+    ///     ]),
+    ///     name: None,
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn image_from_path(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        let mime = match extension.as_deref() {
+            Some("png") => "image/png",
+            Some("jpg" | "jpeg") => "image/jpeg",
+            Some("gif") => "image/gif",
+            Some("webp") => "image/webp",
+            _ => {
+                return Err(crate::Error::Validation(format!(
+                    "cannot infer an image type from {}",
+                    path.display()
+                )));
+            }
+        };
+        let bytes = std::fs::read(path)?;
+        Ok(Self::image_from_bytes(bytes, mime))
+    }
+}
+
 /// Image URL configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImageUrl {
     /// URL of the image or base64 encoded image data
     pub url: String,
@@ -155,7 +286,17 @@ pub struct ImageUrl {
 }
 
 /// Image detail level for vision models
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Default
+)]
 #[cfg_attr(feature = "strum", derive(Display, EnumString))]
 #[serde(rename_all = "lowercase")]
 #[cfg_attr(feature = "strum", strum(serialize_all = "lowercase"))]
@@ -170,7 +311,7 @@ pub enum ImageDetail {
 }
 
 /// Function call information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FunctionCall {
     /// The name of the function to call
     pub name: String,
@@ -179,7 +320,7 @@ pub struct FunctionCall {
 }
 
 /// Tool call made by the model
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChatCompletionMessageToolCall {
     /// The ID of the tool call
     pub id: String,
@@ -191,9 +332,13 @@ pub struct ChatCompletionMessageToolCall {
 }
 
 /// Response format configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+///
+/// Serializes to the object form (`{"type": "json_object"}`), except for
+/// [`ResponseFormat::Auto`], which serializes to the bare string `"auto"`.
+#[derive(Debug, Clone, PartialEq)]
 pub enum ResponseFormat {
+    /// Let the model pick the format (assistants and runs only)
+    Auto,
     /// Text response format (default)
     Text,
     /// JSON object response format
@@ -205,6 +350,61 @@ pub enum ResponseFormat {
     },
 }
 
+/// Object form of a response format, borrowed for serialization
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum TaggedResponseFormatRef<'a> {
+    Text,
+    JsonObject,
+    JsonSchema { json_schema: &'a JsonSchema },
+}
+
+/// Object form of a response format, owned for deserialization
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum TaggedResponseFormat {
+    Text,
+    JsonObject,
+    JsonSchema { json_schema: JsonSchema },
+}
+
+/// Wire representation of a response format: the `"auto"` string or an object
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ResponseFormatRepr {
+    String(String),
+    Tagged(TaggedResponseFormat),
+}
+
+impl Serialize for ResponseFormat {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let tagged = match self {
+            Self::Auto => return serializer.serialize_str("auto"),
+            Self::Text => TaggedResponseFormatRef::Text,
+            Self::JsonObject => TaggedResponseFormatRef::JsonObject,
+            Self::JsonSchema { json_schema } => TaggedResponseFormatRef::JsonSchema { json_schema },
+        };
+        tagged.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ResponseFormat {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match ResponseFormatRepr::deserialize(deserializer)? {
+            ResponseFormatRepr::String(value) if value == "auto" => Ok(Self::Auto),
+            ResponseFormatRepr::String(value) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&value),
+                &"\"auto\" or a response format object",
+            )),
+            ResponseFormatRepr::Tagged(TaggedResponseFormat::Text) => Ok(Self::Text),
+            ResponseFormatRepr::Tagged(TaggedResponseFormat::JsonObject) => Ok(Self::JsonObject),
+            ResponseFormatRepr::Tagged(TaggedResponseFormat::JsonSchema { json_schema }) => {
+                Ok(Self::JsonSchema { json_schema })
+            }
+        }
+    }
+}
+
 impl ResponseFormat {
     /// Creates a JSON schema from a type implementing `schemars::JsonSchema`.
     ///
@@ -241,7 +441,7 @@ impl ResponseFormat {
 }
 
 /// JSON Schema configuration for Structured Outputs
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JsonSchema {
     /// A description of what the response format is for
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -323,7 +523,7 @@ impl JsonSchema {
 }
 
 /// Stream options for streaming responses
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StreamOptions {
     /// If set, include usage information in the final chunk
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -331,7 +531,7 @@ pub struct StreamOptions {
 }
 
 /// Thinking mode configuration for Claude models
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ThinkingConfig {
     /// Type of thinking mode (enabled or disabled)
     #[serde(rename = "type")]
@@ -342,7 +542,7 @@ pub struct ThinkingConfig {
 }
 
 /// Thinking mode type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "strum", derive(Display, EnumString))]
 #[serde(rename_all = "lowercase")]
 #[cfg_attr(feature = "strum", strum(serialize_all = "lowercase"))]
@@ -354,7 +554,7 @@ pub enum ThinkingType {
 }
 
 /// Function definition for function calling
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FunctionObject {
     /// The name of the function
     pub name: String,
@@ -370,7 +570,7 @@ pub struct FunctionObject {
 }
 
 /// Tool definition
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tool {
     /// The type of tool (currently only "function")
     #[serde(rename = "type")]
@@ -379,8 +579,24 @@ pub struct Tool {
     pub function: FunctionObject,
 }
 
+impl Tool {
+    /// Creates a function tool.
+    pub fn function(function: FunctionObject) -> Self {
+        Self {
+            tool_type: "function".to_string(),
+            function,
+        }
+    }
+}
+
+impl From<FunctionObject> for Tool {
+    fn from(function: FunctionObject) -> Self {
+        Self::function(function)
+    }
+}
+
 /// Tool choice option
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ToolChoice {
     /// Simple string choice (none, auto, required)
@@ -389,8 +605,33 @@ pub enum ToolChoice {
     Named(NamedToolChoice),
 }
 
+impl ToolChoice {
+    /// The model will not call any tool.
+    pub fn none() -> Self {
+        Self::Simple(ToolChoiceSimple::None)
+    }
+
+    /// The model decides whether to call a tool.
+    pub fn auto() -> Self {
+        Self::Simple(ToolChoiceSimple::Auto)
+    }
+
+    /// The model must call one or more tools.
+    pub fn required() -> Self {
+        Self::Simple(ToolChoiceSimple::Required)
+    }
+
+    /// The model must call the named function.
+    pub fn function(name: impl Into<String>) -> Self {
+        Self::Named(NamedToolChoice {
+            tool_type: "function".to_string(),
+            function: NamedFunction { name: name.into() },
+        })
+    }
+}
+
 /// Simple tool choice options
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "strum", derive(Display, EnumString))]
 #[serde(rename_all = "lowercase")]
 #[cfg_attr(feature = "strum", strum(serialize_all = "lowercase"))]
@@ -404,7 +645,7 @@ pub enum ToolChoiceSimple {
 }
 
 /// Named tool choice to force a specific tool
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NamedToolChoice {
     /// The type of tool
     #[serde(rename = "type")]
@@ -414,14 +655,14 @@ pub struct NamedToolChoice {
 }
 
 /// Named function for tool choice
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NamedFunction {
     /// The name of the function to call
     pub name: String,
 }
 
 /// Chat completion request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ChatCompletionRequest {
     /// ID of the model to use
     pub model: String,
@@ -481,6 +722,12 @@ pub struct ChatCompletionRequest {
     /// Whether to enable parallel tool calls
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parallel_tool_calls: Option<bool>,
+    /// Predicted output, used to speed up regenerating mostly known content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prediction: Option<PredictionContent>,
+    /// Whether to store the completion for later retrieval
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<bool>,
     /// A unique identifier for the end-user
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
@@ -528,13 +775,133 @@ impl ChatCompletionRequest {
             tools: None,
             tool_choice: None,
             parallel_tool_calls: None,
+            prediction: None,
+            store: None,
             user: None,
         }
     }
+
+    /// Adds a function the model may call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage, FunctionObject};
+    ///
+    /// let request = ChatCompletionRequest::new(
+    ///     "gpt-4o",
+    ///     vec![ChatCompletionRequestMessage::user("What's the weather in Paris?")],
+    /// )
+    /// .with_tool(FunctionObject {
+    ///     name: "get_weather".to_string(),
+    ///     description: Some("Get the current weather for a city".to_string()),
+    ///     parameters: Some(serde_json::json!({
+    ///         "type": "object",
+    ///         "properties": { "city": { "type": "string" } },
+    ///         "required": ["city"]
+    ///     })),
+    ///     strict: None,
+    /// })
+    /// .with_forced_tool("get_weather");
+    /// ```
+    pub fn with_tool(mut self, function: FunctionObject) -> Self {
+        self.tools
+            .get_or_insert_with(Vec::new)
+            .push(Tool::function(function));
+        self
+    }
+
+    /// Adds several functions the model may call.
+    pub fn with_tools(mut self, functions: impl IntoIterator<Item = FunctionObject>) -> Self {
+        self.tools
+            .get_or_insert_with(Vec::new)
+            .extend(functions.into_iter().map(Tool::function));
+        self
+    }
+
+    /// Lets the model decide whether to call a tool.
+    pub fn with_tool_choice_auto(mut self) -> Self {
+        self.tool_choice = Some(ToolChoice::auto());
+        self
+    }
+
+    /// Requires the model to call one or more tools.
+    pub fn with_tool_choice_required(mut self) -> Self {
+        self.tool_choice = Some(ToolChoice::required());
+        self
+    }
+
+    /// Forces the model to call the named function.
+    pub fn with_forced_tool(mut self, name: impl Into<String>) -> Self {
+        self.tool_choice = Some(ToolChoice::function(name));
+        self
+    }
+
+    /// Sets whether the model may call several tools in one turn.
+    pub fn with_parallel_tool_calls(mut self, parallel: bool) -> Self {
+        self.parallel_tool_calls = Some(parallel);
+        self
+    }
+
+    /// Sets the predicted output of the completion.
+    ///
+    /// When most of the response is known in advance, such as a file being
+    /// edited, the model can reuse the matching parts of the prediction and
+    /// respond faster. Predicted tokens that do not appear in the completion
+    /// are still billed; see [`Usage::rejected_prediction_tokens`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage};
+    ///
+    /// let code = "fn main() {\n    println!(\"Hello\");\n}\n";
+    /// let request = ChatCompletionRequest::new(
+    ///     "gpt-4o",
+    ///     vec![ChatCompletionRequestMessage::user(format!("Rename main to run:\n{code}"))],
+    /// )
+    /// .with_prediction(code);
+    /// ```
+    pub fn with_prediction(mut self, content: impl Into<String>) -> Self {
+        self.prediction = Some(PredictionContent::content(content));
+        self
+    }
+
+    /// Sets whether the completion is stored for later retrieval.
+    ///
+    /// Stored completions can be fetched again with
+    /// [`ChatService::retrieve_chat_completion`](crate::service::ChatService::retrieve_chat_completion)
+    /// and listed with
+    /// [`ChatService::list_chat_completions`](crate::service::ChatService::list_chat_completions),
+    /// for example to build evaluation datasets.
+    pub fn with_store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+/// Predicted output for a chat completion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PredictionContent {
+    /// Static content the completion is expected to match
+    Content {
+        /// The predicted text
+        content: String,
+    },
+}
+
+impl PredictionContent {
+    /// Creates a prediction of static text content.
+    pub fn content(content: impl Into<String>) -> Self {
+        Self::Content {
+            content: content.into(),
+        }
+    }
 }
 
 /// Stop sequences (can be a string or array of strings)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Stop {
     /// Single stop sequence
@@ -544,7 +911,7 @@ pub enum Stop {
 }
 
 /// Chat completion response message
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChatCompletionResponseMessage {
     /// The role of the message author
     pub role: String,
@@ -556,9 +923,13 @@ pub struct ChatCompletionResponseMessage {
     /// Deprecated: Function call (use tool_calls instead)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub function_call: Option<FunctionCall>,
-    /// Content blocks (for providers with strict_openai_compliance=false)
+    /// Content blocks, returned when strict OpenAI compliance is disabled
+    /// with [`PortkeyBuilder::with_strict_openai_compliance`](crate::builder::PortkeyBuilder::with_strict_openai_compliance)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_blocks: Option<Vec<ContentBlock>>,
+    /// The refusal message, if the model declined to answer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refusal: Option<String>,
 }
 
 impl ChatCompletionResponseMessage {
@@ -660,7 +1031,7 @@ impl ChatCompletionResponseMessage {
 }
 
 /// Content block in a response message
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ContentBlock {
     /// Text content block
@@ -681,7 +1052,7 @@ pub enum ContentBlock {
 }
 
 /// Token log probability information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TokenLogprob {
     /// The token
     pub token: String,
@@ -694,7 +1065,7 @@ pub struct TokenLogprob {
 }
 
 /// Top token log probability
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TopLogprob {
     /// The token
     pub token: String,
@@ -705,14 +1076,14 @@ pub struct TopLogprob {
 }
 
 /// Log probability information for a choice
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Logprobs {
     /// List of message content tokens with log probability information
     pub content: Option<Vec<TokenLogprob>>,
 }
 
 /// A chat completion choice
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChatCompletionChoice {
     /// The reason the model stopped generating tokens
     pub finish_reason: String,
@@ -724,8 +1095,23 @@ pub struct ChatCompletionChoice {
     pub logprobs: Option<Logprobs>,
 }
 
+/// Response from listing stored chat completions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListChatCompletionsResponse {
+    /// The object type (always "list")
+    pub object: String,
+    /// The stored chat completions
+    pub data: Vec<ChatCompletionResponse>,
+    /// The ID of the first completion in the page
+    pub first_id: Option<String>,
+    /// The ID of the last completion in the page
+    pub last_id: Option<String>,
+    /// Whether more completions follow this page
+    pub has_more: bool,
+}
+
 /// Token usage statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Usage {
     /// Number of tokens in the prompt
     pub prompt_tokens: i32,
@@ -733,10 +1119,87 @@ pub struct Usage {
     pub completion_tokens: i32,
     /// Total number of tokens used
     pub total_tokens: i32,
+    /// Breakdown of the prompt tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_tokens_details: Option<PromptTokensDetails>,
+    /// Breakdown of the completion tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_tokens_details: Option<CompletionTokensDetails>,
+}
+
+impl Usage {
+    /// Returns the number of prompt tokens served from the provider's prompt cache.
+    ///
+    /// Returns 0 if the provider did not report a breakdown.
+    pub fn cached_tokens(&self) -> i32 {
+        self.prompt_tokens_details
+            .as_ref()
+            .and_then(|details| details.cached_tokens)
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of completion tokens spent on reasoning.
+    ///
+    /// Returns 0 if the provider did not report a breakdown.
+    pub fn reasoning_tokens(&self) -> i32 {
+        self.completion_tokens_details
+            .as_ref()
+            .and_then(|details| details.reasoning_tokens)
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of predicted tokens that appeared in the completion.
+    ///
+    /// Returns 0 if the provider did not report a breakdown.
+    pub fn accepted_prediction_tokens(&self) -> i32 {
+        self.completion_tokens_details
+            .as_ref()
+            .and_then(|details| details.accepted_prediction_tokens)
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of predicted tokens that did not appear in the
+    /// completion. These are billed as completion tokens.
+    ///
+    /// Returns 0 if the provider did not report a breakdown.
+    pub fn rejected_prediction_tokens(&self) -> i32 {
+        self.completion_tokens_details
+            .as_ref()
+            .and_then(|details| details.rejected_prediction_tokens)
+            .unwrap_or(0)
+    }
+}
+
+/// Breakdown of the tokens in a prompt
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PromptTokensDetails {
+    /// Number of prompt tokens served from the prompt cache
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cached_tokens: Option<i32>,
+    /// Number of audio input tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_tokens: Option<i32>,
+}
+
+/// Breakdown of the tokens in a completion
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompletionTokensDetails {
+    /// Number of tokens generated by the model for reasoning
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning_tokens: Option<i32>,
+    /// Number of predicted output tokens that appeared in the completion
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_prediction_tokens: Option<i32>,
+    /// Number of predicted output tokens that did not appear in the completion
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejected_prediction_tokens: Option<i32>,
+    /// Number of audio output tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_tokens: Option<i32>,
 }
 
 /// Chat completion response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChatCompletionResponse {
     /// A unique identifier for the chat completion
     pub id: String,
@@ -746,6 +1209,9 @@ pub struct ChatCompletionResponse {
     pub created: i64,
     /// The model used for the chat completion
     pub model: String,
+    /// The provider that served the completion, as reported by the gateway
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
     /// A list of chat completion choices
     pub choices: Vec<ChatCompletionChoice>,
     /// Usage statistics
@@ -755,3 +1221,504 @@ pub struct ChatCompletionResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub system_fingerprint: Option<String>,
 }
+
+impl PortkeyResponse<ChatCompletionResponse> {
+    /// Returns the provider that served this response.
+    ///
+    /// With fallback or load-balancing configs this can differ from the
+    /// provider the request was routed to first; the body's
+    /// [`model`](ChatCompletionResponse::model) is likewise the model that
+    /// actually answered. Reads the `provider` field of the body, falling back
+    /// to the `x-portkey-provider` header.
+    pub fn effective_provider(&self) -> Option<&str> {
+        self.body
+            .provider
+            .as_deref()
+            .or(self.meta.provider.as_deref())
+    }
+}
+
+impl ChatCompletionResponse {
+    /// Returns the refusal message of the first choice, if the model
+    /// declined to answer.
+    pub fn refusal(&self) -> Option<&str> {
+        self.choices.first()?.message.refusal.as_deref()
+    }
+
+    /// Returns `true` if the first choice was refused by the model or
+    /// stopped by a content filter.
+    pub fn is_refusal(&self) -> bool {
+        self.choices.first().is_some_and(|choice| {
+            choice.message.refusal.is_some() || choice.finish_reason == "content_filter"
+        })
+    }
+
+    /// Returns the text content of the first choice.
+    ///
+    /// Returns `None` if there is no choice, the message has no content, or
+    /// the response [is a refusal](Self::is_refusal), so that refusals are
+    /// not mistaken for empty output.
+    pub fn first_message_text(&self) -> Option<&str> {
+        if self.is_refusal() {
+            return None;
+        }
+        self.choices.first()?.message.content.as_deref()
+    }
+}
+
+/// A streamed chunk of a chat completion response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatCompletionChunk {
+    /// A unique identifier for the chat completion, shared by all chunks
+    pub id: String,
+    /// The object type (always "chat.completion.chunk")
+    pub object: String,
+    /// The Unix timestamp when the completion was created
+    pub created: i64,
+    /// The model used for the chat completion
+    pub model: String,
+    /// The provider that served the completion, as reported by the gateway
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    /// A list of chat completion choice deltas
+    pub choices: Vec<ChatCompletionChunkChoice>,
+    /// Usage statistics, sent in the final chunk when requested via `stream_options`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<Usage>,
+    /// System fingerprint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_fingerprint: Option<String>,
+}
+
+impl ChatCompletionChunk {
+    /// Returns the response metadata carried by the chunk.
+    pub fn metadata(&self) -> ChatCompletionStreamMetadata {
+        ChatCompletionStreamMetadata {
+            id: self.id.clone(),
+            created: self.created,
+            model: self.model.clone(),
+            provider: self.provider.clone(),
+            system_fingerprint: self.system_fingerprint.clone(),
+        }
+    }
+}
+
+/// Response metadata shared by every chunk of a streamed chat completion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatCompletionStreamMetadata {
+    /// A unique identifier for the chat completion
+    pub id: String,
+    /// The Unix timestamp when the completion was created
+    pub created: i64,
+    /// The model used for the chat completion
+    pub model: String,
+    /// The provider that served the completion, as reported by the gateway
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    /// System fingerprint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_fingerprint: Option<String>,
+}
+
+/// A chat completion choice delta within a streamed chunk
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatCompletionChunkChoice {
+    /// The index of this choice
+    pub index: i32,
+    /// The incremental message content
+    pub delta: ChatCompletionStreamDelta,
+    /// The reason the model stopped generating tokens, set on the last chunk of the choice
+    pub finish_reason: Option<String>,
+    /// Log probability information
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logprobs: Option<Logprobs>,
+}
+
+/// Incremental message content within a streamed chunk
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChatCompletionStreamDelta {
+    /// The role of the message author, set on the first chunk
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /// The next piece of the message content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The next piece of the refusal message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refusal: Option<String>,
+    /// Fragments of tool calls, merged by [`ChatCompletionChunkToolCall::index`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<ChatCompletionChunkToolCall>>,
+}
+
+/// A fragment of a tool call within a streamed chunk
+///
+/// The `id` and function `name` are usually only sent with the first fragment
+/// of a tool call; the `arguments` arrive in pieces that must be concatenated
+/// in order. Fragments of the same tool call share an `index`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatCompletionChunkToolCall {
+    /// The position of the tool call in the message's tool calls
+    pub index: i32,
+    /// The ID of the tool call, set on the first fragment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The type of tool (currently only "function"), set on the first fragment
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub tool_type: Option<String>,
+    /// The function name and the next piece of its arguments
+    #[serde(default)]
+    pub function: ChatCompletionChunkFunctionCall,
+}
+
+/// A fragment of a function call within a streamed tool call
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChatCompletionChunkFunctionCall {
+    /// The name of the function, set on the first fragment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The next piece of the arguments (JSON format)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(format: &ResponseFormat) -> serde_json::Value {
+        let json = serde_json::to_value(format).unwrap();
+        let parsed: ResponseFormat = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+        json
+    }
+
+    #[test]
+    fn test_response_format_auto() {
+        assert_eq!(round_trip(&ResponseFormat::Auto), serde_json::json!("auto"));
+    }
+
+    #[test]
+    fn test_response_format_text() {
+        assert_eq!(
+            round_trip(&ResponseFormat::Text),
+            serde_json::json!({ "type": "text" })
+        );
+    }
+
+    #[test]
+    fn test_response_format_json_object() {
+        assert_eq!(
+            round_trip(&ResponseFormat::JsonObject),
+            serde_json::json!({ "type": "json_object" })
+        );
+    }
+
+    #[test]
+    fn test_response_format_json_schema() {
+        let format = ResponseFormat::JsonSchema {
+            json_schema: JsonSchema {
+                description: None,
+                name: "answer".to_string(),
+                schema: serde_json::json!({ "type": "object" }),
+                strict: Some(true),
+            },
+        };
+        assert_eq!(
+            round_trip(&format),
+            serde_json::json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": "answer",
+                    "schema": { "type": "object" },
+                    "strict": true
+                }
+            })
+        );
+    }
+
+    fn weather_function() -> FunctionObject {
+        FunctionObject {
+            name: "get_weather".to_string(),
+            description: None,
+            parameters: Some(serde_json::json!({ "type": "object" })),
+            strict: None,
+        }
+    }
+
+    fn request() -> ChatCompletionRequest {
+        ChatCompletionRequest::new("gpt-4o", vec![ChatCompletionRequestMessage::user("Hi")])
+    }
+
+    #[test]
+    fn test_with_store() {
+        let json = serde_json::to_value(request().with_store(true)).unwrap();
+        assert_eq!(json["store"], true);
+
+        let json = serde_json::to_value(request()).unwrap();
+        assert!(json.get("store").is_none());
+    }
+
+    #[test]
+    fn test_with_prediction() {
+        let json = serde_json::to_value(request().with_prediction("fn main() {}")).unwrap();
+
+        assert_eq!(
+            json["prediction"],
+            serde_json::json!({ "type": "content", "content": "fn main() {}" })
+        );
+        assert!(
+            serde_json::to_value(request())
+                .unwrap()
+                .get("prediction")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_with_tools() {
+        let request = request()
+            .with_tool(weather_function())
+            .with_tools(vec![weather_function()])
+            .with_parallel_tool_calls(false);
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["tools"].as_array().unwrap().len(), 2);
+        assert_eq!(json["tools"][0]["type"], "function");
+        assert_eq!(json["tools"][0]["function"]["name"], "get_weather");
+        assert_eq!(json["parallel_tool_calls"], false);
+    }
+
+    #[test]
+    fn test_tool_choice_auto() {
+        let json = serde_json::to_value(request().with_tool_choice_auto()).unwrap();
+        assert_eq!(json["tool_choice"], "auto");
+    }
+
+    #[test]
+    fn test_tool_choice_required() {
+        let json = serde_json::to_value(request().with_tool_choice_required()).unwrap();
+        assert_eq!(json["tool_choice"], "required");
+    }
+
+    #[test]
+    fn test_forced_tool() {
+        let json = serde_json::to_value(request().with_forced_tool("get_weather")).unwrap();
+        assert_eq!(
+            json["tool_choice"],
+            serde_json::json!({ "type": "function", "function": { "name": "get_weather" } })
+        );
+    }
+
+    #[test]
+    fn test_response_format_rejects_unknown_string() {
+        assert!(serde_json::from_value::<ResponseFormat>(serde_json::json!("json")).is_err());
+    }
+
+    #[test]
+    fn test_tool_only_assistant_message() {
+        let message = ChatCompletionRequestMessage::assistant_tool_calls(vec![
+            ChatCompletionMessageToolCall {
+                id: "call_abc123".to_string(),
+                tool_type: "function".to_string(),
+                function: FunctionCall {
+                    name: "get_weather".to_string(),
+                    arguments: r#"{"location":"Paris"}"#.to_string(),
+                },
+            },
+        ]);
+        let json = serde_json::to_value(&message).unwrap();
+
+        assert_eq!(json["role"], "assistant");
+        assert!(json.get("content").is_none());
+        assert_eq!(json["tool_calls"][0]["id"], "call_abc123");
+        assert_eq!(json["tool_calls"][0]["type"], "function");
+
+        let parsed: ChatCompletionRequestMessage = serde_json::from_value(serde_json::json!({
+            "role": "assistant",
+            "content": null,
+            "tool_calls": json["tool_calls"],
+        }))
+        .unwrap();
+        assert!(matches!(
+            parsed,
+            ChatCompletionRequestMessage::Assistant { content: None, tool_calls: Some(ref calls), .. } if calls.len() == 1
+        ));
+    }
+
+    #[test]
+    fn test_tool_message_round_trip() {
+        let message = ChatCompletionRequestMessage::tool("call_abc123", "22 degrees");
+        let json = serde_json::to_value(&message).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "role": "tool",
+                "content": "22 degrees",
+                "tool_call_id": "call_abc123",
+            })
+        );
+
+        let parsed: ChatCompletionRequestMessage = serde_json::from_value(json).unwrap();
+        assert!(matches!(
+            parsed,
+            ChatCompletionRequestMessage::Tool { ref tool_call_id, ref content }
+                if tool_call_id == "call_abc123" && content == "22 degrees"
+        ));
+    }
+
+    #[test]
+    fn test_usage_token_details() {
+        let usage: Usage = serde_json::from_value(serde_json::json!({
+            "prompt_tokens": 1200,
+            "completion_tokens": 300,
+            "total_tokens": 1500,
+            "prompt_tokens_details": { "cached_tokens": 1024 },
+            "completion_tokens_details": {
+                "reasoning_tokens": 256,
+                "accepted_prediction_tokens": 0,
+                "rejected_prediction_tokens": 12
+            }
+        }))
+        .unwrap();
+        assert_eq!(usage.cached_tokens(), 1024);
+        assert_eq!(usage.reasoning_tokens(), 256);
+        assert_eq!(usage.rejected_prediction_tokens(), 12);
+        assert_eq!(
+            usage
+                .completion_tokens_details
+                .as_ref()
+                .unwrap()
+                .accepted_prediction_tokens,
+            Some(0)
+        );
+
+        let usage: Usage = serde_json::from_value(serde_json::json!({
+            "prompt_tokens": 10,
+            "completion_tokens": 5,
+            "total_tokens": 15
+        }))
+        .unwrap();
+        assert!(usage.prompt_tokens_details.is_none());
+        assert_eq!(usage.cached_tokens(), 0);
+        assert_eq!(usage.reasoning_tokens(), 0);
+        assert_eq!(usage.accepted_prediction_tokens(), 0);
+        assert_eq!(
+            serde_json::to_value(&usage).unwrap(),
+            serde_json::json!({ "prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15 })
+        );
+    }
+
+    #[test]
+    fn test_response_equality() {
+        let json = serde_json::json!({
+            "id": "chatcmpl-123",
+            "object": "chat.completion",
+            "created": 1700000000,
+            "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "finish_reason": "stop",
+                "message": { "role": "assistant", "content": "Hi!" },
+                "logprobs": null
+            }],
+            "usage": { "prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7 }
+        });
+        let response: ChatCompletionResponse = serde_json::from_value(json).unwrap();
+        let round_tripped: ChatCompletionResponse =
+            serde_json::from_value(serde_json::to_value(&response).unwrap()).unwrap();
+        assert_eq!(response, round_tripped);
+
+        let mut changed = response.clone();
+        changed.usage.as_mut().unwrap().total_tokens = 8;
+        assert_ne!(response, changed);
+    }
+
+    fn response_with(message: serde_json::Value, finish_reason: &str) -> ChatCompletionResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": "chatcmpl-123",
+            "object": "chat.completion",
+            "created": 1700000000,
+            "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "finish_reason": finish_reason,
+                "message": message,
+                "logprobs": null
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_image_from_bytes_round_trip() {
+        let bytes = b"\x89PNG\r\n\x1a\n\x00\xff";
+        let part = ChatCompletionContentPart::image_from_bytes(bytes, "image/png");
+
+        let ChatCompletionContentPart::ImageUrl { image_url } = &part else {
+            panic!("expected an image part");
+        };
+        let encoded = image_url
+            .url
+            .strip_prefix("data:image/png;base64,")
+            .unwrap();
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .unwrap();
+        assert_eq!(decoded, bytes);
+
+        let json = serde_json::to_value(&part).unwrap();
+        assert_eq!(json["type"], "image_url");
+        let parsed: ChatCompletionContentPart = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, part);
+    }
+
+    #[test]
+    fn test_image_from_path() {
+        let path = std::env::temp_dir().join(format!("portkey-image-{}.JPG", std::process::id()));
+        std::fs::write(&path, b"jpeg").unwrap();
+        let part = ChatCompletionContentPart::image_from_path(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            part,
+            ChatCompletionContentPart::image_url("data:image/jpeg;base64,anBlZw==")
+        );
+        assert!(matches!(
+            ChatCompletionContentPart::image_from_path("notes.txt"),
+            Err(crate::Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_refusal() {
+        let response = response_with(
+            serde_json::json!({ "role": "assistant", "content": "Hi!" }),
+            "stop",
+        );
+        assert!(!response.is_refusal());
+        assert_eq!(response.refusal(), None);
+        assert_eq!(response.first_message_text(), Some("Hi!"));
+
+        let response = response_with(
+            serde_json::json!({
+                "role": "assistant",
+                "content": null,
+                "refusal": "I can't help with that."
+            }),
+            "stop",
+        );
+        assert!(response.is_refusal());
+        assert_eq!(response.refusal(), Some("I can't help with that."));
+        assert_eq!(response.first_message_text(), None);
+
+        let response = response_with(
+            serde_json::json!({ "role": "assistant", "content": "" }),
+            "content_filter",
+        );
+        assert!(response.is_refusal());
+        assert_eq!(response.refusal(), None);
+        assert_eq!(response.first_message_text(), None);
+    }
+}
@@ -1,10 +1,13 @@
 //! Common model types shared across the API.
 
+use std::collections::HashMap;
+use std::time::Duration;
+
 /// Pagination parameters for list endpoints.
 ///
 /// This struct provides common pagination options used across various list endpoints
 /// in the Portkey API.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct PaginationParams<'a> {
     /// A limit on the number of objects to be returned.
     /// Limit can range between 1 and 100, and the default is 20.
@@ -21,6 +24,12 @@ pub struct PaginationParams<'a> {
     /// A cursor for use in pagination. `before` is an object ID that defines
     /// your place in the list.
     pub before: Option<&'a str>,
+
+    /// Filters objects by metadata, sent as `metadata[key]=value`.
+    ///
+    /// Only supported by some list endpoints, such as assistants, runs and
+    /// messages.
+    pub metadata: Option<HashMap<String, String>>,
 }
 
 impl<'a> PaginationParams<'a> {
@@ -53,26 +62,185 @@ impl<'a> PaginationParams<'a> {
         self
     }
 
+    /// Adds a metadata filter.
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
     /// Converts the pagination params into query parameter tuples.
     ///
     /// Returns a vector of (key, value) tuples that can be used with
-    /// URL query builders.
-    pub(crate) fn to_query_params(&self) -> Vec<(&str, String)> {
+    /// URL query builders. Metadata filters are sorted by key.
+    pub(crate) fn to_query_params(&self) -> Vec<(String, String)> {
         let mut params = Vec::new();
 
         if let Some(limit) = self.limit {
-            params.push(("limit", limit.to_string()));
+            params.push(("limit".to_string(), limit.to_string()));
         }
         if let Some(order) = self.order {
-            params.push(("order", order.to_string()));
+            params.push(("order".to_string(), order.to_string()));
         }
         if let Some(after) = self.after {
-            params.push(("after", after.to_string()));
+            params.push(("after".to_string(), after.to_string()));
         }
         if let Some(before) = self.before {
-            params.push(("before", before.to_string()));
+            params.push(("before".to_string(), before.to_string()));
+        }
+        if let Some(metadata) = &self.metadata {
+            let mut metadata: Vec<_> = metadata.iter().collect();
+            metadata.sort();
+            for (key, value) in metadata {
+                params.push((format!("metadata[{key}]"), value.clone()));
+            }
         }
 
         params
     }
 }
+
+/// A response that is either fully typed or, if it did not match the SDK's
+/// types, the raw JSON value.
+///
+/// Returned by lenient variants of service methods such as
+/// [`ChatService::create_chat_completion_lenient`](crate::service::ChatService::create_chat_completion_lenient).
+/// This is a stopgap for when the API changes its response shape before the
+/// SDK is updated; prefer the typed methods otherwise.
+#[derive(Debug)]
+pub enum LenientResponse<T> {
+    /// The response matched the expected type.
+    Typed(T),
+    /// The response is valid JSON but did not match the expected type.
+    Raw {
+        /// The raw response body.
+        value: serde_json::Value,
+        /// Why the typed deserialization failed.
+        error: serde_json::Error,
+    },
+}
+
+impl<T: serde::de::DeserializeOwned> LenientResponse<T> {
+    /// Deserializes `value` into `T`, keeping the raw value on failure.
+    pub fn from_value(value: serde_json::Value) -> Self {
+        match serde_json::from_value(value.clone()) {
+            Ok(typed) => Self::Typed(typed),
+            Err(error) => Self::Raw { value, error },
+        }
+    }
+}
+
+impl<T> LenientResponse<T> {
+    /// Returns the typed response, if deserialization succeeded.
+    pub fn typed(self) -> Option<T> {
+        match self {
+            Self::Typed(typed) => Some(typed),
+            Self::Raw { .. } => None,
+        }
+    }
+
+    /// Returns the typed response, or the deserialization error.
+    pub fn into_result(self) -> crate::Result<T> {
+        match self {
+            Self::Typed(typed) => Ok(typed),
+            Self::Raw { error, .. } => Err(error.into()),
+        }
+    }
+
+    /// Returns `true` if the response did not match the expected type.
+    pub fn is_raw(&self) -> bool {
+        matches!(self, Self::Raw { .. })
+    }
+}
+
+/// Portkey metadata read from the headers of a response.
+///
+/// Lets a response be correlated with the trace, prompt template and gateway
+/// config that served it, and tells how close the caller is to the
+/// provider's rate limits. Headers the gateway did not send are `None`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResponseMeta {
+    /// The `x-portkey-trace-id` of the request.
+    pub trace_id: Option<String>,
+    /// The `x-portkey-prompt-slug` of the prompt template that was used.
+    pub prompt_slug: Option<String>,
+    /// The `x-portkey-config` ID of the gateway config that was applied.
+    pub config: Option<String>,
+    /// The `x-portkey-provider` that served the request.
+    pub provider: Option<String>,
+    /// The `x-ratelimit-remaining-requests` left in the provider's window.
+    pub ratelimit_remaining: Option<u64>,
+    /// The `x-ratelimit-reset-requests` time until the request limit resets.
+    pub ratelimit_reset: Option<Duration>,
+    /// The `x-portkey-cost` of the request, as reported by the gateway.
+    pub cost: Option<f64>,
+}
+
+/// A response body together with the metadata from its headers.
+///
+/// Returned by the `*_with_meta` variants of service methods.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortkeyResponse<T> {
+    /// The deserialized response body.
+    pub body: T,
+    /// Metadata read from the response headers.
+    pub meta: ResponseMeta,
+}
+
+impl<T> PortkeyResponse<T> {
+    /// Returns the response body, dropping the metadata.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_query_params() {
+        let params = PaginationParams::new()
+            .with_limit(10)
+            .with_metadata("team", "search")
+            .with_metadata("env", "prod");
+
+        assert_eq!(
+            params.to_query_params(),
+            [
+                ("limit".to_string(), "10".to_string()),
+                ("metadata[env]".to_string(), "prod".to_string()),
+                ("metadata[team]".to_string(), "search".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_metadata_query_string_encoding() {
+        let params = PaginationParams::new().with_metadata("team", "a b");
+        let mut url = url::Url::parse("https://api.portkey.ai/v1/assistants").unwrap();
+        url.query_pairs_mut().extend_pairs(params.to_query_params());
+
+        assert_eq!(url.query(), Some("metadata%5Bteam%5D=a+b"));
+    }
+
+    #[test]
+    fn test_lenient_response() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Typed {
+            id: String,
+        }
+
+        let response = LenientResponse::<Typed>::from_value(serde_json::json!({ "id": "a" }));
+        assert!(!response.is_raw());
+        assert_eq!(response.typed().unwrap().id, "a");
+
+        let response = LenientResponse::<Typed>::from_value(serde_json::json!({ "id": 1 }));
+        let LenientResponse::Raw { value, error } = response else {
+            panic!("expected a raw response");
+        };
+        assert_eq!(value, serde_json::json!({ "id": 1 }));
+        assert!(error.is_data());
+    }
+}
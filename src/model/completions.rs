@@ -6,8 +6,10 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use super::{CompletionTokensDetails, PromptTokensDetails};
+
 /// Request body for creating a completion.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateCompletionRequest {
     /// ID of the model to use.
     pub model: String,
@@ -77,8 +79,32 @@ pub struct CreateCompletionRequest {
     pub seed: Option<i64>,
 }
 
+impl Default for CreateCompletionRequest {
+    fn default() -> Self {
+        Self {
+            model: String::new(),
+            prompt: None,
+            max_tokens: Some(16),
+            temperature: Some(1.0),
+            top_p: Some(1.0),
+            n: Some(1),
+            stream: Some(false),
+            logprobs: None,
+            echo: Some(false),
+            stop: None,
+            frequency_penalty: Some(0.0),
+            presence_penalty: Some(0.0),
+            best_of: Some(1),
+            logit_bias: None,
+            user: None,
+            suffix: None,
+            seed: None,
+        }
+    }
+}
+
 /// Prompt can be a string or array of strings.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum CompletionPrompt {
     /// Single string prompt.
@@ -88,7 +114,7 @@ pub enum CompletionPrompt {
 }
 
 /// Stop sequences can be a string or array of strings.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum CompletionStop {
     /// Single stop sequence.
@@ -98,7 +124,7 @@ pub enum CompletionStop {
 }
 
 /// Response from the completions endpoint.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CompletionResponse {
     /// Unique identifier for the completion.
     pub id: String,
@@ -125,7 +151,7 @@ pub struct CompletionResponse {
 }
 
 /// A single completion choice.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CompletionChoice {
     /// Generated completion text.
     pub text: String,
@@ -143,7 +169,7 @@ pub struct CompletionChoice {
 }
 
 /// Log probability information for tokens.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CompletionLogprobs {
     /// List of tokens.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -163,7 +189,7 @@ pub struct CompletionLogprobs {
 }
 
 /// Token usage statistics.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CompletionUsage {
     /// Number of tokens in the prompt.
     pub prompt_tokens: u32,
@@ -173,4 +199,12 @@ pub struct CompletionUsage {
 
     /// Total tokens used (prompt + completion).
     pub total_tokens: u32,
+
+    /// Breakdown of the prompt tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_tokens_details: Option<PromptTokensDetails>,
+
+    /// Breakdown of the completion tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_tokens_details: Option<CompletionTokensDetails>,
 }
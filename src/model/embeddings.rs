@@ -1,10 +1,17 @@
+use ring::digest::{Context, SHA256};
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Error, Result};
+
+/// Scale applied to embedding values before they are fingerprinted, which
+/// keeps four decimal places.
+const FINGERPRINT_SCALE: f64 = 10_000.0;
+
 /// Input for the embeddings API.
 ///
 /// The input can be a single string, an array of strings, an array of token integers,
 /// or an array of token arrays.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum EmbeddingInput {
     /// A single string input
@@ -21,7 +28,17 @@ pub enum EmbeddingInput {
 }
 
 /// The format to return embeddings in.
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize
+)]
 #[serde(rename_all = "lowercase")]
 pub enum EncodingFormat {
     /// Return embeddings as floating point arrays (default)
@@ -47,7 +64,7 @@ pub enum EncodingFormat {
 ///     user: None,
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateEmbeddingRequest {
     /// ID of the model to use.
     ///
@@ -73,9 +90,10 @@ pub struct CreateEmbeddingRequest {
 
     /// The number of dimensions the resulting output embeddings should have.
     ///
-    /// Only supported in `text-embedding-3` and later models.
+    /// Only supported in `text-embedding-3` and later models. Must be positive
+    /// and no larger than the model's native dimension.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dimensions: Option<i32>,
+    pub dimensions: Option<u32>,
 
     /// A unique identifier representing your end-user.
     ///
@@ -111,6 +129,66 @@ impl CreateEmbeddingRequest {
             user: None,
         }
     }
+
+    /// Sets the number of dimensions to truncate the embeddings to.
+    pub fn with_dimensions(mut self, dimensions: u32) -> Self {
+        self.dimensions = Some(dimensions);
+        self
+    }
+
+    /// Checks the request for configuration mistakes before it is sent.
+    ///
+    /// Returns [`Error::Validation`] if `dimensions` is zero, or larger than
+    /// the native dimension of a known model.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::CreateEmbeddingRequest;
+    ///
+    /// let request = CreateEmbeddingRequest::new("text-embedding-3-small", "Hello")
+    ///     .with_dimensions(4096);
+    /// assert!(request.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        let Some(dimensions) = self.dimensions else {
+            return Ok(());
+        };
+
+        if dimensions == 0 {
+            return Err(Error::Validation(
+                "embedding dimensions must be positive".to_string(),
+            ));
+        }
+
+        if let Some(max) = max_dimensions(&self.model)
+            && dimensions > max
+        {
+            return Err(Error::Validation(format!(
+                "model {} supports at most {max} embedding dimensions, got {dimensions}",
+                self.model
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the native embedding dimension of known models.
+fn max_dimensions(model: &str) -> Option<u32> {
+    // Accept provider-prefixed ids such as `openai/text-embedding-3-small`.
+    let model = model.rsplit('/').next().unwrap_or(model);
+    match model {
+        "text-embedding-3-small" => Some(1536),
+        "text-embedding-3-large" => Some(3072),
+        _ => None,
+    }
+}
+
+impl Default for CreateEmbeddingRequest {
+    fn default() -> Self {
+        Self::new(String::new(), String::new())
+    }
 }
 
 impl From<String> for EmbeddingInput {
@@ -147,7 +225,7 @@ impl From<Vec<String>> for EmbeddingInput {
 ///   "index": 0
 /// }
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Embedding {
     /// The index of the embedding in the list of embeddings.
     pub index: i32,
@@ -163,7 +241,7 @@ pub struct Embedding {
 }
 
 /// Usage statistics for an embeddings request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EmbeddingUsage {
     /// The number of tokens used by the prompt.
     pub prompt_tokens: i32,
@@ -195,7 +273,7 @@ pub struct EmbeddingUsage {
 ///     },
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateEmbeddingResponse {
     /// The object type, which is always "list".
     pub object: String,
@@ -209,3 +287,168 @@ pub struct CreateEmbeddingResponse {
     /// The usage information for the request.
     pub usage: EmbeddingUsage,
 }
+
+impl CreateEmbeddingResponse {
+    /// Checks that every returned embedding has exactly `dimensions` values.
+    ///
+    /// Returns [`Error::Validation`] naming the first mismatching embedding.
+    /// Useful after requesting truncated embeddings with
+    /// [`CreateEmbeddingRequest::with_dimensions`], to catch a model or
+    /// provider that ignored the setting before the vectors are stored.
+    pub fn ensure_dimensions(&self, dimensions: u32) -> Result<()> {
+        match self
+            .data
+            .iter()
+            .find(|embedding| embedding.embedding.len() != dimensions as usize)
+        {
+            Some(embedding) => Err(Error::Validation(format!(
+                "embedding {} has {} dimensions, expected {dimensions}",
+                embedding.index,
+                embedding.embedding.len()
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns a stable fingerprint of the returned vectors.
+    ///
+    /// The vectors are rounded to four decimal places and hashed in order
+    /// with SHA-256, so the fingerprint is the same across runs, platforms
+    /// and SDK versions as long as the model returns the same embeddings.
+    /// Snapshot it in retrieval regression tests to detect a silent model
+    /// change; [`model`](Self::model) names the model that produced it.
+    ///
+    /// Rounding absorbs most floating-point noise, but a value that lands on
+    /// a rounding boundary can still change the fingerprint.
+    pub fn fingerprint(&self) -> u64 {
+        let mut context = Context::new(&SHA256);
+        for embedding in &self.data {
+            context.update(&embedding.index.to_le_bytes());
+            context.update(&(embedding.embedding.len() as u64).to_le_bytes());
+            for value in &embedding.embedding {
+                let quantized = (value * FINGERPRINT_SCALE).round() as i64;
+                context.update(&quantized.to_le_bytes());
+            }
+        }
+
+        let digest = context.finish();
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&digest.as_ref()[..8]);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Checks that the [`fingerprint`](Self::fingerprint) matches a baseline.
+    ///
+    /// Returns [`Error::Validation`] naming the model when the embeddings
+    /// changed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::CreateEmbeddingRequest;
+    /// # use portkey_sdk::service::EmbeddingsService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// const BASELINE: u64 = 0x1f2e_3d4c_5b6a_7988;
+    ///
+    /// let request = CreateEmbeddingRequest::new("text-embedding-3-small", "The quick brown fox");
+    /// let response = client.create_embedding(request).await?;
+    /// response.ensure_fingerprint(BASELINE)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ensure_fingerprint(&self, baseline: u64) -> Result<()> {
+        let fingerprint = self.fingerprint();
+        if fingerprint == baseline {
+            return Ok(());
+        }
+
+        Err(Error::Validation(format!(
+            "embeddings from model {} changed: fingerprint {fingerprint:#018x}, expected {baseline:#018x}",
+            self.model
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_dimensions() {
+        let request = CreateEmbeddingRequest::new("text-embedding-3-large", "Hello");
+        assert!(request.validate().is_ok());
+        assert!(request.clone().with_dimensions(256).validate().is_ok());
+        assert!(request.clone().with_dimensions(3072).validate().is_ok());
+        assert!(matches!(
+            request.clone().with_dimensions(3073).validate(),
+            Err(Error::Validation(_))
+        ));
+        assert!(matches!(
+            request.with_dimensions(0).validate(),
+            Err(Error::Validation(_))
+        ));
+
+        let request = CreateEmbeddingRequest::new("openai/text-embedding-3-small", "Hello");
+        assert!(request.with_dimensions(2048).validate().is_err());
+
+        let request = CreateEmbeddingRequest::new("custom-embedder", "Hello");
+        assert!(request.with_dimensions(8192).validate().is_ok());
+    }
+
+    #[test]
+    fn test_ensure_dimensions() {
+        let embedding = |index: i32, len: usize| Embedding {
+            index,
+            object: "embedding".to_string(),
+            embedding: vec![0.0; len],
+        };
+        let mut response = CreateEmbeddingResponse {
+            object: "list".to_string(),
+            model: "text-embedding-3-small".to_string(),
+            data: vec![embedding(0, 256), embedding(1, 256)],
+            usage: EmbeddingUsage {
+                prompt_tokens: 4,
+                total_tokens: 4,
+            },
+        };
+        assert!(response.ensure_dimensions(256).is_ok());
+
+        response.data.push(embedding(2, 1536));
+        let err = response.ensure_dimensions(256).unwrap_err();
+        assert!(matches!(err, Error::Validation(ref msg) if msg.starts_with("embedding 2 ")));
+    }
+
+    /// Pinned so that any change to the fingerprint format fails loudly.
+    const FINGERPRINT: u64 = 0xab15_ea76_f36e_7df2;
+
+    #[test]
+    fn test_fingerprint() {
+        let response = |values: Vec<f64>| CreateEmbeddingResponse {
+            object: "list".to_string(),
+            model: "text-embedding-3-small".to_string(),
+            data: vec![Embedding {
+                index: 0,
+                object: "embedding".to_string(),
+                embedding: values,
+            }],
+            usage: EmbeddingUsage {
+                prompt_tokens: 4,
+                total_tokens: 4,
+            },
+        };
+
+        let baseline = response(vec![0.0023064255, -0.009327292, 0.5]);
+        let noisy = response(vec![0.0023064251, -0.009327297, 0.5]);
+        let drifted = response(vec![0.0023064255, -0.009427292, 0.5]);
+
+        assert_eq!(baseline.fingerprint(), FINGERPRINT);
+        assert_eq!(noisy.fingerprint(), baseline.fingerprint());
+        assert!(baseline.ensure_fingerprint(FINGERPRINT).is_ok());
+
+        let err = drifted.ensure_fingerprint(FINGERPRINT).unwrap_err();
+        assert!(
+            matches!(err, Error::Validation(ref msg) if msg.contains("text-embedding-3-small"))
+        );
+    }
+}
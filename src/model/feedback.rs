@@ -20,7 +20,7 @@ use serde::{Deserialize, Serialize};
 ///     metadata: Some(metadata),
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateFeedbackRequest {
     /// Unique identifier for the request trace
     pub trace_id: String,
@@ -50,7 +50,7 @@ pub struct CreateFeedbackRequest {
 ///     metadata: None,
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct UpdateFeedbackRequest {
     /// Updated feedback value (integer between -10 and 10)
     pub value: i32,
@@ -65,7 +65,7 @@ pub struct UpdateFeedbackRequest {
 }
 
 /// Response from creating or updating feedback.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FeedbackResponse {
     /// Status of the operation (success or failure)
     pub status: String,
@@ -5,7 +5,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Request body for uploading a file.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct UploadFileRequest {
     /// File to upload (bytes).
     pub file: Vec<u8>,
@@ -18,10 +18,50 @@ pub struct UploadFileRequest {
     /// "vision" for Assistants image file inputs,
     /// "batch" for Batch API, and "fine-tune" for Fine-tuning.
     pub purpose: String,
+
+    /// Optional expiration policy; the file is deleted once it expires.
+    pub expires_after: Option<ExpiresAfter>,
+}
+
+/// Expiration policy for an uploaded file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ExpiresAfter {
+    /// The timestamp the expiration is relative to.
+    pub anchor: ExpiresAfterAnchor,
+
+    /// Seconds after the anchor at which the file expires.
+    pub seconds: u64,
+}
+
+impl ExpiresAfter {
+    /// Expires the file the given number of seconds after it was created.
+    pub fn from_creation(seconds: u64) -> Self {
+        Self {
+            anchor: ExpiresAfterAnchor::CreatedAt,
+            seconds,
+        }
+    }
+}
+
+/// The timestamp a file expiration is relative to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpiresAfterAnchor {
+    /// The file creation time.
+    CreatedAt,
+}
+
+impl ExpiresAfterAnchor {
+    /// Returns the anchor as sent to the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "created_at",
+        }
+    }
 }
 
 /// Response from uploading a file.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FileObject {
     /// The file identifier.
     pub id: String,
@@ -41,6 +81,10 @@ pub struct FileObject {
     /// The intended purpose of the file.
     pub purpose: String,
 
+    /// The Unix timestamp (in seconds) for when the file will expire.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+
     /// Deprecated. The current status of the file (always "processed").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
@@ -51,7 +95,7 @@ pub struct FileObject {
 }
 
 /// Response from listing files.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ListFilesResponse {
     /// List of file objects.
     pub data: Vec<FileObject>,
@@ -61,7 +105,7 @@ pub struct ListFilesResponse {
 }
 
 /// Response from deleting a file.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeleteFileResponse {
     /// The ID of the deleted file.
     pub id: String,
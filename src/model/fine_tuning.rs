@@ -1,21 +1,29 @@
 use std::collections::HashMap;
 
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 /// Request to create a fine-tuning job.
 ///
 /// # Example
 ///
-/// ```rust,ignore
-/// use portkey::model::CreateFineTuningJobRequest;
+/// ```
+/// use portkey_sdk::model::CreateFineTuningJobRequest;
 ///
 /// let request = CreateFineTuningJobRequest::builder()
 ///     .model("gpt-3.5-turbo")
 ///     .training_file("file-abc123")
+///     .suffix("support")
 ///     .build()
 ///     .unwrap();
+/// assert_eq!(request.suffix.as_deref(), Some("support"));
 /// ```
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(
+    pattern = "owned",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
 pub struct CreateFineTuningJobRequest {
     /// The name of the model to fine-tune.
     pub model: String,
@@ -25,27 +33,42 @@ pub struct CreateFineTuningJobRequest {
 
     /// The hyperparameters used for the fine-tuning job.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub hyperparameters: Option<Hyperparameters>,
 
     /// A string of up to 18 characters that will be added to your fine-tuned model name.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub suffix: Option<String>,
 
     /// The ID of an uploaded file that contains validation data.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub validation_file: Option<String>,
 
     /// A list of integrations to enable for your fine-tuning job.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub integrations: Option<Vec<Integration>>,
 
     /// The seed controls the reproducibility of the job.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub seed: Option<i64>,
 }
 
+impl CreateFineTuningJobRequest {
+    /// Creates a new fine-tuning job request builder.
+    ///
+    /// `build` fails with [`Error::Validation`](crate::Error::Validation) if
+    /// a required field is not set.
+    pub fn builder() -> CreateFineTuningJobRequestBuilder {
+        CreateFineTuningJobRequestBuilder::default()
+    }
+}
+
 /// The hyperparameters used for the fine-tuning job.
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Hyperparameters {
     /// Number of examples in each batch.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,7 +84,7 @@ pub struct Hyperparameters {
 }
 
 /// A hyperparameter value can be either "auto" or a specific number.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum HyperparameterValue {
     Auto(String),
@@ -69,7 +92,7 @@ pub enum HyperparameterValue {
 }
 
 /// Integration configuration for a fine-tuning job.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Integration {
     /// The type of integration to enable.
     #[serde(rename = "type")]
@@ -80,7 +103,7 @@ pub struct Integration {
 }
 
 /// Weights & Biases integration configuration.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WandbIntegration {
     /// The name of the project that the new run will be created under.
     pub project: String,
@@ -99,7 +122,7 @@ pub struct WandbIntegration {
 }
 
 /// The fine-tuning job object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FineTuningJob {
     /// The object identifier, which can be referenced in the API endpoints.
     pub id: String,
@@ -162,7 +185,7 @@ pub struct FineTuningJob {
 }
 
 /// Error information for a failed fine-tuning job.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FineTuningError {
     /// A machine-readable error code.
     pub code: String,
@@ -176,7 +199,7 @@ pub struct FineTuningError {
 }
 
 /// Response containing a list of fine-tuning jobs.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListFineTuningJobsResponse {
     pub object: String,
     pub data: Vec<FineTuningJob>,
@@ -184,7 +207,7 @@ pub struct ListFineTuningJobsResponse {
 }
 
 /// A fine-tuning job event object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FineTuningJobEvent {
     pub id: String,
     pub created_at: i64,
@@ -194,14 +217,14 @@ pub struct FineTuningJobEvent {
 }
 
 /// Response containing a list of fine-tuning job events.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListFineTuningJobEventsResponse {
     pub object: String,
     pub data: Vec<FineTuningJobEvent>,
 }
 
 /// A fine-tuning job checkpoint object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FineTuningJobCheckpoint {
     /// The checkpoint identifier, which can be referenced in the API endpoints.
     pub id: String,
@@ -226,7 +249,7 @@ pub struct FineTuningJobCheckpoint {
 }
 
 /// Response containing a list of fine-tuning job checkpoints.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListFineTuningJobCheckpointsResponse {
     pub object: String,
     pub data: Vec<FineTuningJobCheckpoint>,
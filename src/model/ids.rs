@@ -0,0 +1,126 @@
+//! Typed identifiers for API objects.
+//!
+//! Service methods that take several IDs in a row accept these types, so
+//! passing an ID in the wrong position fails to compile when the caller holds
+//! typed IDs. Plain strings convert into any of them.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Creates an ID from a string.
+            pub fn new(id: impl Into<String>) -> Self {
+                Self(id.into())
+            }
+
+            /// Returns the ID as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Consumes the ID and returns the underlying string.
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self(id.to_owned())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<&String> for $name {
+            fn from(id: &String) -> Self {
+                Self(id.clone())
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(id: &$name) -> Self {
+                id.clone()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+    };
+}
+
+id_type! {
+    /// ID of a thread, such as `thread_abc123`.
+    ThreadId
+}
+
+id_type! {
+    /// ID of a run, such as `run_abc123`.
+    RunId
+}
+
+id_type! {
+    /// ID of a run step, such as `step_abc123`.
+    RunStepId
+}
+
+id_type! {
+    /// ID of a message, such as `msg_abc123`.
+    MessageId
+}
+
+id_type! {
+    /// ID of an assistant, such as `asst_abc123`.
+    AssistantId
+}
+
+id_type! {
+    /// ID of a file, such as `file-abc123`.
+    FileId
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_id_conversions() {
+        let thread_id = ThreadId::from("thread_abc123");
+        assert_eq!(thread_id.as_str(), "thread_abc123");
+        assert_eq!(thread_id.to_string(), "thread_abc123");
+        assert_eq!(ThreadId::from(&thread_id), thread_id);
+        assert_eq!(ThreadId::from("thread_abc123".to_string()), thread_id);
+        assert_eq!(String::from(thread_id), "thread_abc123");
+
+        let file_id: FileId = serde_json::from_str(r#""file-abc123""#).unwrap();
+        assert_eq!(file_id, FileId::new("file-abc123"));
+        assert_eq!(serde_json::to_string(&file_id).unwrap(), r#""file-abc123""#);
+    }
+}
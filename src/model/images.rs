@@ -1,7 +1,19 @@
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Error, Result};
+
 /// Image quality options for DALL-E 3
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize
+)]
 #[serde(rename_all = "lowercase")]
 pub enum ImageQuality {
     /// Standard quality
@@ -13,7 +25,17 @@ pub enum ImageQuality {
 }
 
 /// Image response format
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize
+)]
 #[serde(rename_all = "snake_case")]
 pub enum ImageResponseFormat {
     /// URL to the generated image (valid for 60 minutes)
@@ -25,7 +47,17 @@ pub enum ImageResponseFormat {
 }
 
 /// Image size options
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Default
+)]
 pub enum ImageSize {
     /// 256x256 pixels (DALL-E 2 only)
     #[serde(rename = "256x256")]
@@ -49,8 +81,31 @@ pub enum ImageSize {
     Size1024x1792,
 }
 
+impl ImageSize {
+    /// Returns the size as sent to the API, such as `1024x1024`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Size256x256 => "256x256",
+            Self::Size512x512 => "512x512",
+            Self::Size1024x1024 => "1024x1024",
+            Self::Size1792x1024 => "1792x1024",
+            Self::Size1024x1792 => "1024x1792",
+        }
+    }
+}
+
 /// Image style for DALL-E 3
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize
+)]
 #[serde(rename_all = "lowercase")]
 pub enum ImageStyle {
     /// Hyper-real and dramatic images
@@ -79,7 +134,7 @@ pub enum ImageStyle {
 ///     user: None,
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateImageRequest {
     /// A text description of the desired image(s).
     ///
@@ -127,8 +182,118 @@ pub struct CreateImageRequest {
     pub user: Option<String>,
 }
 
+impl CreateImageRequest {
+    /// Checks the request against the limits of known image models.
+    ///
+    /// Rejects an image count outside what the model generates per request
+    /// (only one for DALL-E 3), and a `size`, `quality` or `style` the model
+    /// does not support. Requests without a model or for other models are
+    /// not checked. Called by
+    /// [`generate_image`](crate::service::ImagesService::generate_image)
+    /// when [strict validation](crate::builder::PortkeyBuilder::with_strict_validation)
+    /// is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::CreateImageRequest;
+    ///
+    /// let request = CreateImageRequest {
+    ///     prompt: "A cute baby sea otter".to_string(),
+    ///     model: Some("dall-e-3".to_string()),
+    ///     n: Some(2),
+    ///     ..Default::default()
+    /// };
+    /// assert!(request.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        let Some(model) = self.model.as_deref() else {
+            return Ok(());
+        };
+        let Some(limits) = image_model_limits(model) else {
+            return Ok(());
+        };
+
+        if let Some(n) = self.n {
+            if n < 1 {
+                return Err(Error::Validation(format!(
+                    "image count must be positive, got {n}"
+                )));
+            }
+            if n > limits.max_images {
+                return Err(Error::Validation(format!(
+                    "model {model} generates at most {} image(s) per request, got {n}",
+                    limits.max_images
+                )));
+            }
+        }
+
+        if let Some(size) = self.size
+            && !limits.sizes.contains(&size)
+        {
+            return Err(Error::Validation(format!(
+                "model {model} does not support image size {}",
+                size.as_str()
+            )));
+        }
+
+        if self.quality.is_some() && !limits.quality {
+            return Err(Error::Validation(format!(
+                "model {model} does not support image quality"
+            )));
+        }
+
+        if self.style.is_some() && !limits.style {
+            return Err(Error::Validation(format!(
+                "model {model} does not support image style"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Image generation limits of a known model.
+struct ImageModelLimits {
+    max_images: i32,
+    sizes: &'static [ImageSize],
+    quality: bool,
+    style: bool,
+}
+
+/// Returns the image generation limits of known models.
+fn image_model_limits(model: &str) -> Option<ImageModelLimits> {
+    // Accept provider-prefixed ids such as `openai/dall-e-3`.
+    let model = model.rsplit('/').next().unwrap_or(model);
+    if model.starts_with("dall-e-3") {
+        Some(ImageModelLimits {
+            max_images: 1,
+            sizes: &[
+                ImageSize::Size1024x1024,
+                ImageSize::Size1792x1024,
+                ImageSize::Size1024x1792,
+            ],
+            quality: true,
+            style: true,
+        })
+    } else if model.starts_with("dall-e-2") {
+        Some(ImageModelLimits {
+            max_images: 10,
+            sizes: &[
+                ImageSize::Size256x256,
+                ImageSize::Size512x512,
+                ImageSize::Size1024x1024,
+            ],
+            quality: false,
+            style: false,
+        })
+    } else {
+        None
+    }
+}
+
 /// A single generated image.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Image {
     /// Base64-encoded JSON of the image (if response_format is b64_json)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -161,7 +326,7 @@ pub struct Image {
 ///     ],
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImagesResponse {
     /// Unix timestamp of when the images were created
     pub created: i64,
@@ -186,7 +351,7 @@ pub struct ImagesResponse {
 ///     user: None,
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateImageEditRequest {
     /// A text description of the desired image(s).
     ///
@@ -233,7 +398,7 @@ pub struct CreateImageEditRequest {
 ///     user: None,
 /// };
 /// ```
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateImageVariationRequest {
     /// The model to use for image generation.
     ///
@@ -259,3 +424,106 @@ pub struct CreateImageVariationRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(model: &str) -> CreateImageRequest {
+        CreateImageRequest {
+            prompt: "A cute baby sea otter".to_string(),
+            model: Some(model.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_image_count() {
+        let dalle3 = CreateImageRequest {
+            n: Some(2),
+            ..request("dall-e-3")
+        };
+        assert!(matches!(dalle3.validate(), Err(Error::Validation(_))));
+
+        let prefixed = CreateImageRequest {
+            n: Some(2),
+            ..request("openai/dall-e-3")
+        };
+        assert!(prefixed.validate().is_err());
+
+        let dalle2 = CreateImageRequest {
+            n: Some(4),
+            ..request("dall-e-2")
+        };
+        assert!(dalle2.validate().is_ok());
+        assert!(
+            CreateImageRequest {
+                n: Some(0),
+                ..dalle2
+            }
+            .validate()
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_image_options() {
+        let wide = CreateImageRequest {
+            size: Some(ImageSize::Size1792x1024),
+            quality: Some(ImageQuality::Hd),
+            style: Some(ImageStyle::Natural),
+            ..request("dall-e-3")
+        };
+        assert!(wide.validate().is_ok());
+        assert!(
+            CreateImageRequest {
+                size: Some(ImageSize::Size1792x1024),
+                ..request("dall-e-2")
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            CreateImageRequest {
+                style: Some(ImageStyle::Vivid),
+                ..request("dall-e-2")
+            }
+            .validate()
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_skips_unknown_models() {
+        let custom = CreateImageRequest {
+            n: Some(4),
+            size: Some(ImageSize::Size1792x1024),
+            style: Some(ImageStyle::Vivid),
+            ..request("stable-diffusion-xl")
+        };
+        assert!(custom.validate().is_ok());
+        assert!(
+            CreateImageRequest {
+                model: None,
+                ..custom
+            }
+            .validate()
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_image_size_as_map_key() {
+        let mut counts = std::collections::HashMap::new();
+        for size in [
+            ImageSize::Size1024x1024,
+            ImageSize::Size512x512,
+            ImageSize::Size1024x1024,
+        ] {
+            *counts.entry(size).or_insert(0) += 1;
+        }
+
+        assert_eq!(counts[&ImageSize::Size1024x1024], 2);
+        assert_eq!(counts[&ImageSize::Size512x512], 1);
+    }
+}
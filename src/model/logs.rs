@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 /// Export status enum
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ExportStatus {
     Draft,
@@ -14,7 +14,7 @@ pub enum ExportStatus {
 }
 
 /// Requested data fields for log exports
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum LogExportField {
     Id,
@@ -40,7 +40,7 @@ pub enum LogExportField {
 }
 
 /// Filters for log generation queries
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct GenerationsFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_of_generation_min: Option<String>,
@@ -75,8 +75,13 @@ pub struct GenerationsFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub completion_token_max: Option<i32>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_code: Option<String>,
+    /// Response status codes to match, sent as a comma-separated list
+    #[serde(
+        default,
+        skip_serializing_if = "comma_separated::is_empty",
+        with = "comma_separated"
+    )]
+    pub status_code: Option<Vec<u16>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, serde_json::Value>>,
@@ -90,14 +95,24 @@ pub struct GenerationsFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub weighted_feedback_max: Option<f64>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_keys: Option<String>,
+    /// Virtual keys to match, sent as a comma-separated list
+    #[serde(
+        default,
+        skip_serializing_if = "comma_separated::is_empty",
+        with = "comma_separated"
+    )]
+    pub virtual_keys: Option<Vec<String>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trace_id: Option<String>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub configs: Option<String>,
+    /// Configs to match, sent as a comma-separated list
+    #[serde(
+        default,
+        skip_serializing_if = "comma_separated::is_empty",
+        with = "comma_separated"
+    )]
+    pub configs: Option<Vec<String>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub workspace_slug: Option<String>,
@@ -114,8 +129,117 @@ pub struct GenerationsFilter {
     pub current_page: Option<i32>,
 }
 
+impl GenerationsFilter {
+    /// Creates an empty filter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches logs whose metadata `key` equals `value`.
+    ///
+    /// Can be called repeatedly to match several keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::GenerationsFilter;
+    ///
+    /// let filter = GenerationsFilter::new()
+    ///     .with_metadata_eq("environment", "production")
+    ///     .with_virtual_keys(["vk-openai", "vk-anthropic"]);
+    /// ```
+    pub fn with_metadata_eq(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Matches logs made with any of the given virtual keys.
+    pub fn with_virtual_keys<I, K>(mut self, virtual_keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<String>,
+    {
+        self.virtual_keys = Some(virtual_keys.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Matches logs made with any of the given configs.
+    pub fn with_configs<I, K>(mut self, configs: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<String>,
+    {
+        self.configs = Some(configs.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Matches logs with any of the given response status codes.
+    pub fn with_status_codes(mut self, status_codes: impl IntoIterator<Item = u16>) -> Self {
+        self.status_code = Some(status_codes.into_iter().collect());
+        self
+    }
+}
+
+/// (De)serializes a list as the comma-separated string the API expects.
+///
+/// Values are trimmed and empty values are dropped, so stray whitespace
+/// cannot silently break a filter.
+mod comma_separated {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn is_empty<T>(values: &Option<Vec<T>>) -> bool {
+        values.as_ref().is_none_or(Vec::is_empty)
+    }
+
+    pub fn serialize<T, S>(values: &Option<Vec<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        let Some(values) = values else {
+            return serializer.serialize_none();
+        };
+
+        let joined = values
+            .iter()
+            .map(|value| value.to_string().trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect::<Vec<_>>()
+            .join(",");
+        serializer.serialize_str(&joined)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let Some(joined) = Option::<String>::deserialize(deserializer)? else {
+            return Ok(None);
+        };
+
+        joined
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| value.parse().map_err(serde::de::Error::custom))
+            .collect::<Result<_, _>>()
+            .map(Some)
+    }
+}
+
 /// Request to create a log export
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateLogExportRequest {
     /// Workspace ID
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -133,7 +257,7 @@ pub struct CreateLogExportRequest {
 }
 
 /// Response from creating or updating a log export
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateLogExportResponse {
     /// Export ID
     pub id: String,
@@ -146,7 +270,7 @@ pub struct CreateLogExportResponse {
 }
 
 /// Log export item details
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogExport {
     /// Export ID
     pub id: String,
@@ -183,7 +307,7 @@ pub struct LogExport {
 }
 
 /// Response from export task operations (start/cancel)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExportTaskResponse {
     /// Status message
     pub message: String,
@@ -193,14 +317,14 @@ pub struct ExportTaskResponse {
 }
 
 /// Response from download request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DownloadLogExportResponse {
     /// Pre-signed URL for downloading the export
     pub signed_url: String,
 }
 
 /// HTTP request data for a log entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogRequest {
     /// Request URL
     pub url: String,
@@ -218,7 +342,7 @@ pub struct LogRequest {
 }
 
 /// HTTP response data for a log entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogResponse {
     /// HTTP status code
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -237,7 +361,7 @@ pub struct LogResponse {
 }
 
 /// Metadata for a log entry
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct LogMetadata {
     /// Trace ID for distributed tracing
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -257,7 +381,7 @@ pub struct LogMetadata {
 }
 
 /// A custom log entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CustomLog {
     /// Request data
     pub request: LogRequest,
@@ -271,7 +395,7 @@ pub struct CustomLog {
 }
 
 /// Request to insert one or more log entries
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum InsertLogRequest {
     /// Single log entry
@@ -281,7 +405,7 @@ pub enum InsertLogRequest {
 }
 
 /// Response from inserting log entries
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InsertLogResponse {
     /// Status message
     pub status: String,
@@ -289,10 +413,66 @@ pub struct InsertLogResponse {
     /// Log IDs
     #[serde(skip_serializing_if = "Option::is_none")]
     pub log_ids: Option<Vec<String>>,
+
+    /// Outcome of each log, in the order the logs were sent.
+    ///
+    /// Only present when the API reports per-log results. Otherwise the
+    /// response carries just the aggregate `status`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<InsertLogResult>>,
+}
+
+impl InsertLogResponse {
+    /// Returns the index and error message of every log that failed.
+    ///
+    /// Indices refer to the logs of the request. Empty when every log was
+    /// stored, or when the API reported only an aggregate `status`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::InsertLogResponse;
+    ///
+    /// let response: InsertLogResponse = serde_json::from_str(r#"{
+    ///     "status": "partial",
+    ///     "results": [
+    ///         {"log_id": "log_1"},
+    ///         {"error": "missing response body"}
+    ///     ]
+    /// }"#).unwrap();
+    /// assert_eq!(response.failures(), [(1, "missing response body")]);
+    /// ```
+    pub fn failures(&self) -> Vec<(usize, &str)> {
+        self.results
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter_map(|(index, result)| Some((index, result.error.as_deref()?)))
+            .collect()
+    }
+}
+
+/// Outcome of inserting one log of a batch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InsertLogResult {
+    /// ID of the stored log, if it was stored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_id: Option<String>,
+
+    /// Why the log was rejected, if it failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl InsertLogResult {
+    /// Returns `true` if the log was stored.
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
 }
 
 /// Request to update a log export
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct UpdateLogExportRequest {
     /// Workspace ID
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -307,7 +487,7 @@ pub struct UpdateLogExportRequest {
 }
 
 /// Response from updating a log export
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UpdateLogExportResponse {
     /// Export ID
     pub id: String,
@@ -324,7 +504,7 @@ pub struct UpdateLogExportResponse {
 // ============================================================================
 
 /// Parameters for listing log exports
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ListLogExportsParams {
     /// Workspace ID to filter by
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -332,7 +512,7 @@ pub struct ListLogExportsParams {
 }
 
 /// A log export item in a list
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogExportListItem {
     /// Export ID
     pub id: String,
@@ -369,7 +549,7 @@ pub struct LogExportListItem {
 }
 
 /// Response from listing log exports
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ListLogExportsResponse {
     /// Object type (always "list")
     pub object: String,
@@ -380,3 +560,84 @@ pub struct ListLogExportsResponse {
     /// Array of export items
     pub data: Vec<LogExportListItem>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generations_filter_helpers() {
+        let filter = GenerationsFilter::new()
+            .with_metadata_eq("environment", "production")
+            .with_metadata_eq("tier", 2)
+            .with_virtual_keys(vec!["vk-openai".to_string(), "vk-anthropic".to_string()])
+            .with_configs(["cfg-1"]);
+        let json = serde_json::to_value(&filter).unwrap();
+
+        assert_eq!(json["metadata"]["environment"], "production");
+        assert_eq!(json["metadata"]["tier"], 2);
+        assert_eq!(json["virtual_keys"], "vk-openai,vk-anthropic");
+        assert_eq!(json["configs"], "cfg-1");
+    }
+
+    #[test]
+    fn test_generations_filter_comma_separated() {
+        let filter = GenerationsFilter {
+            virtual_keys: Some(vec!["vk1".to_string(), " vk2 ".to_string()]),
+            status_code: Some(vec![200, 429]),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&filter).unwrap();
+
+        assert_eq!(json["virtual_keys"], "vk1,vk2");
+        assert_eq!(json["status_code"], "200,429");
+        assert!(json.get("configs").is_none());
+
+        let parsed: GenerationsFilter = serde_json::from_value(serde_json::json!({
+            "virtual_keys": "vk1, vk2,",
+            "status_code": "500",
+        }))
+        .unwrap();
+        assert_eq!(
+            parsed.virtual_keys,
+            Some(vec!["vk1".to_string(), "vk2".to_string()])
+        );
+        assert_eq!(parsed.status_code, Some(vec![500]));
+        assert_eq!(parsed.configs, None);
+    }
+
+    #[test]
+    fn test_generations_filter_empty_values() {
+        let filter = GenerationsFilter::new().with_virtual_keys(Vec::<String>::new());
+        let json = serde_json::to_value(&filter).unwrap();
+        assert!(json.get("virtual_keys").is_none());
+    }
+
+    #[test]
+    fn test_insert_log_response_failures() {
+        let response: InsertLogResponse = serde_json::from_value(serde_json::json!({
+            "status": "partial",
+            "results": [
+                {"log_id": "log_1"},
+                {"error": "invalid request body"},
+                {"log_id": "log_3"},
+                {"error": "missing response"},
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.failures(),
+            [(1, "invalid request body"), (3, "missing response")]
+        );
+        assert!(response.results.as_ref().unwrap()[0].is_success());
+
+        let aggregate: InsertLogResponse = serde_json::from_value(serde_json::json!({
+            "status": "success",
+            "log_ids": ["log_1", "log_2"],
+        }))
+        .unwrap();
+        assert_eq!(aggregate.results, None);
+        assert!(aggregate.failures().is_empty());
+    }
+}
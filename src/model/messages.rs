@@ -1,50 +1,110 @@
 use std::collections::HashMap;
 
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 /// Request to create a message.
 ///
 /// # Example
 ///
-/// ```rust,ignore
-/// use portkey::model::CreateMessageRequest;
+/// ```
+/// use portkey_sdk::model::CreateMessageRequest;
 ///
 /// let request = CreateMessageRequest::builder()
-///     .role("user")
 ///     .content("Hello, how are you?")
 ///     .build()
 ///     .unwrap();
+/// assert_eq!(request.role, "user");
 /// ```
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(
+    pattern = "owned",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
+#[builder_impl_attr(allow(deprecated))]
 pub struct CreateMessageRequest {
     /// The role of the entity that is creating the message.
+    #[builder(default = "\"user\".to_string()")]
     pub role: String,
 
     /// The content of the message.
     pub content: String,
 
     /// A list of File IDs that the message should use.
+    ///
+    /// Ignored by the Assistants v2 API, use `attachments` instead.
+    #[deprecated(note = "use `attachments` instead")]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(skip))]
     pub file_ids: Option<Vec<String>>,
 
+    /// Files attached to the message and the tools they are added to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub attachments: Option<Vec<MessageAttachment>>,
+
     /// Set of key-value pairs that can be attached to an object.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub metadata: Option<HashMap<String, String>>,
 }
 
+impl CreateMessageRequest {
+    /// Creates a new message request builder.
+    ///
+    /// `build` fails with [`Error::Validation`](crate::Error::Validation) if
+    /// a required field is not set.
+    pub fn builder() -> CreateMessageRequestBuilder {
+        CreateMessageRequestBuilder::default()
+    }
+}
+
 impl Default for CreateMessageRequest {
+    #[allow(deprecated)]
     fn default() -> Self {
         Self {
             role: "user".to_string(),
             content: String::new(),
             file_ids: None,
+            attachments: None,
             metadata: None,
         }
     }
 }
 
+/// A file attached to a message.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageAttachment {
+    /// The ID of the file to attach.
+    pub file_id: String,
+
+    /// The tools to add the file to.
+    pub tools: Vec<AttachmentTool>,
+}
+
+impl MessageAttachment {
+    /// Creates an attachment that adds the file to the given tools.
+    pub fn new(file_id: impl Into<String>, tools: Vec<AttachmentTool>) -> Self {
+        Self {
+            file_id: file_id.into(),
+            tools,
+        }
+    }
+}
+
+/// A tool that a message attachment is added to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AttachmentTool {
+    /// Makes the file available to the `code_interpreter` tool.
+    CodeInterpreter,
+    /// Makes the file searchable by the `file_search` tool.
+    FileSearch,
+}
+
 /// Modifies a message.
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ModifyMessageRequest {
     /// Set of key-value pairs that can be attached to an object.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -52,7 +112,7 @@ pub struct ModifyMessageRequest {
 }
 
 /// A message object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Message {
     /// The identifier of the message.
     pub id: String,
@@ -79,14 +139,22 @@ pub struct Message {
     pub run_id: Option<String>,
 
     /// A list of file IDs that the assistant should use.
+    ///
+    /// Not returned by the Assistants v2 API, see `attachments` instead.
+    #[deprecated(note = "use `attachments` instead")]
+    #[serde(default)]
     pub file_ids: Vec<String>,
 
+    /// Files attached to the message and the tools they were added to.
+    #[serde(default)]
+    pub attachments: Vec<MessageAttachment>,
+
     /// Set of key-value pairs that can be attached to an object.
     pub metadata: HashMap<String, String>,
 }
 
 /// Content of a message.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum MessageContent {
     #[serde(rename = "text")]
@@ -96,7 +164,7 @@ pub enum MessageContent {
 }
 
 /// Text content in a message.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TextContent {
     /// The data that makes up the text.
     pub value: String,
@@ -106,14 +174,14 @@ pub struct TextContent {
 }
 
 /// Image file content in a message.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ImageFileContent {
     /// The File ID of the image.
     pub file_id: String,
 }
 
 /// An annotation in text content.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum Annotation {
     #[serde(rename = "file_citation")]
@@ -133,7 +201,7 @@ pub enum Annotation {
 }
 
 /// A citation within the message that points to a specific quote from a specific File.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileCitation {
     /// The ID of the specific File the citation is from.
     pub file_id: String,
@@ -143,14 +211,14 @@ pub struct FileCitation {
 }
 
 /// A URL for the file that's generated when the assistant used the code_interpreter tool.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FilePathAnnotation {
     /// The ID of the file that was generated.
     pub file_id: String,
 }
 
 /// Response containing a list of messages.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListMessagesResponse {
     pub object: String,
     pub data: Vec<Message>,
@@ -160,7 +228,7 @@ pub struct ListMessagesResponse {
 }
 
 /// A message file object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageFile {
     /// The identifier of the message file.
     pub id: String,
@@ -176,7 +244,7 @@ pub struct MessageFile {
 }
 
 /// Response containing a list of message files.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListMessageFilesResponse {
     pub object: String,
     pub data: Vec<MessageFile>,
@@ -184,3 +252,30 @@ pub struct ListMessageFilesResponse {
     pub last_id: Option<String>,
     pub has_more: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_message_attachments() {
+        let request = CreateMessageRequest {
+            content: "Summarize this report.".to_string(),
+            attachments: Some(vec![MessageAttachment::new(
+                "file-abc123",
+                vec![AttachmentTool::FileSearch, AttachmentTool::CodeInterpreter],
+            )]),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json["attachments"],
+            serde_json::json!([{
+                "file_id": "file-abc123",
+                "tools": [{"type": "file_search"}, {"type": "code_interpreter"}]
+            }])
+        );
+        assert!(json.get("file_ids").is_none());
+    }
+}
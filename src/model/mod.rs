@@ -13,15 +13,18 @@ mod embeddings;
 mod feedback;
 mod files;
 mod fine_tuning;
+mod ids;
 mod images;
 mod logs;
 mod messages;
 mod models;
 mod moderations;
 mod prompts;
+mod response_generations;
 mod responses;
 mod runs;
 mod threads;
+mod tokenizer;
 
 pub use assistants::*;
 pub use audio::*;
@@ -33,12 +36,15 @@ pub use embeddings::*;
 pub use feedback::*;
 pub use files::*;
 pub use fine_tuning::*;
+pub use ids::*;
 pub use images::*;
 pub use logs::*;
 pub use messages::*;
 pub use models::*;
 pub use moderations::*;
 pub use prompts::*;
+pub use response_generations::*;
 pub use responses::*;
 pub use runs::*;
 pub use threads::*;
+pub use tokenizer::*;
@@ -5,7 +5,17 @@
 use serde::{Deserialize, Serialize};
 
 /// Sort field for models.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Default
+)]
 #[serde(rename_all = "lowercase")]
 pub enum ModelSortField {
     /// Sort by model name
@@ -19,7 +29,17 @@ pub enum ModelSortField {
 }
 
 /// Sort order for models.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Default
+)]
 #[serde(rename_all = "lowercase")]
 pub enum SortOrder {
     /// Ascending order
@@ -45,7 +65,7 @@ pub enum SortOrder {
 ///     order: Some(SortOrder::Asc),
 /// };
 /// ```
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ListModelsParams {
     /// Filter models by the AI service (e.g., 'openai', 'anthropic').
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -73,7 +93,7 @@ pub struct ListModelsParams {
 }
 
 /// Describes a model offering that can be used with the API.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Model {
     /// The model identifier, which can be referenced in the API endpoints.
     pub id: String,
@@ -97,7 +117,7 @@ pub struct Model {
 ///
 /// // This would typically be deserialized from the API response
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ListModelsResponse {
     /// The object type, which is always "list".
     pub object: String,
@@ -106,6 +126,89 @@ pub struct ListModelsResponse {
     pub data: Vec<Model>,
 }
 
+/// Response from deleting a fine-tuned model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeleteModelResponse {
+    /// The ID of the deleted model.
+    pub id: String,
+
+    /// The object type, which is always "model".
+    pub object: String,
+
+    /// Whether the model was successfully deleted.
+    pub deleted: bool,
+}
+
+/// An in-memory snapshot of the models available through Portkey.
+///
+/// Returned by [`ModelsService::models_catalog`](crate::service::ModelsService::models_catalog)
+/// and queried locally, without further API calls.
+///
+/// # Example
+///
+/// ```
+/// use portkey_sdk::model::{ListModelsResponse, Model, ModelsCatalog};
+///
+/// let catalog = ModelsCatalog::from(ListModelsResponse {
+///     object: "list".to_string(),
+///     data: vec![Model {
+///         id: "gpt-4o".to_string(),
+///         created: 0,
+///         object: "model".to_string(),
+///         owned_by: "openai".to_string(),
+///     }],
+/// });
+///
+/// assert!(catalog.contains("gpt-4o"));
+/// assert_eq!(catalog.by_provider("openai").count(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModelsCatalog {
+    models: Vec<Model>,
+}
+
+impl ModelsCatalog {
+    /// Returns the model with the given id.
+    pub fn get(&self, id: &str) -> Option<&Model> {
+        self.models.iter().find(|model| model.id == id)
+    }
+
+    /// Returns `true` if a model with the given id is available.
+    pub fn contains(&self, id: &str) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Returns the models owned by the given provider.
+    pub fn by_provider<'a>(&'a self, provider: &'a str) -> impl Iterator<Item = &'a Model> {
+        self.models
+            .iter()
+            .filter(move |model| model.owned_by == provider)
+    }
+
+    /// Returns all models in the catalog.
+    pub fn models(&self) -> &[Model] {
+        &self.models
+    }
+
+    /// Returns the number of models in the catalog.
+    pub fn len(&self) -> usize {
+        self.models.len()
+    }
+
+    /// Returns `true` if the catalog has no models.
+    pub fn is_empty(&self) -> bool {
+        self.models.is_empty()
+    }
+}
+
+impl From<ListModelsResponse> for ModelsCatalog {
+    fn from(response: ListModelsResponse) -> Self {
+        Self {
+            models: response.data,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +246,35 @@ mod tests {
         assert_eq!(params.provider, Some("openai".to_string()));
         assert_eq!(params.limit, Some(10));
     }
+
+    #[test]
+    fn test_models_catalog_lookup() {
+        let model = |id: &str, owned_by: &str| Model {
+            id: id.to_string(),
+            created: 0,
+            object: "model".to_string(),
+            owned_by: owned_by.to_string(),
+        };
+        let catalog = ModelsCatalog::from(ListModelsResponse {
+            object: "list".to_string(),
+            data: vec![
+                model("gpt-4o", "openai"),
+                model("gpt-4o-mini", "openai"),
+                model("claude-sonnet", "anthropic"),
+            ],
+        });
+
+        assert_eq!(catalog.len(), 3);
+        assert!(catalog.contains("claude-sonnet"));
+        assert!(!catalog.contains("gpt-5"));
+        assert_eq!(catalog.get("gpt-4o").unwrap().owned_by, "openai");
+
+        let openai: Vec<_> = catalog
+            .by_provider("openai")
+            .map(|m| m.id.as_str())
+            .collect();
+        assert_eq!(openai, ["gpt-4o", "gpt-4o-mini"]);
+        assert_eq!(catalog.by_provider("mistral").count(), 0);
+        assert!(ModelsCatalog::default().is_empty());
+    }
 }
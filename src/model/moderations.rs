@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreateModerationRequest {
     /// The input text to classify.
     pub input: ModerationInput,
@@ -33,7 +33,7 @@ impl Default for CreateModerationRequest {
 }
 
 /// Input for moderation can be a single string or an array of strings.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ModerationInput {
     String(String),
@@ -59,7 +59,7 @@ impl From<Vec<String>> for ModerationInput {
 }
 
 /// Response from the moderation endpoint.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ModerationResponse {
     /// The unique identifier for the moderation request.
     pub id: String,
@@ -72,7 +72,7 @@ pub struct ModerationResponse {
 }
 
 /// A single moderation result.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ModerationResult {
     /// Whether any of the below categories are flagged.
     pub flagged: bool,
@@ -85,7 +85,7 @@ pub struct ModerationResult {
 }
 
 /// Categories that were checked in the moderation.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ModerationCategories {
     /// Content that expresses, incites, or promotes hate based on race, gender, ethnicity, religion, nationality, sexual orientation, disability status, or caste.
     pub hate: bool,
@@ -129,7 +129,7 @@ pub struct ModerationCategories {
 }
 
 /// Scores for each moderation category.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ModerationCategoryScores {
     /// Score for hate content.
     pub hate: f64,
@@ -2,6 +2,106 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use super::chat::ChatCompletionRequest;
+use super::completions::CreateCompletionRequest;
+use crate::error::{Error, Result};
+
+/// Variables substituted into a prompt template.
+///
+/// Build them one at a time with [`with_var`](Self::with_var), or from a
+/// struct with [`from_serializable`](Self::from_serializable) so that variable
+/// names and types are checked at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use portkey_sdk::model::{PromptCompletionRequest, PromptVariables};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct SupportVariables {
+///     customer_name: String,
+///     ticket_count: u32,
+/// }
+///
+/// let variables = PromptVariables::from_serializable(&SupportVariables {
+///     customer_name: "Alice".to_string(),
+///     ticket_count: 3,
+/// })?
+/// .with_var("tone", "friendly");
+///
+/// let request = PromptCompletionRequest::default().with_variables(variables);
+/// # Ok::<(), portkey_sdk::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PromptVariables(HashMap<String, serde_json::Value>);
+
+impl PromptVariables {
+    /// Creates an empty set of variables.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates variables from the fields of a serializable value.
+    ///
+    /// Returns [`Error::Validation`] if the value does not serialize to a JSON
+    /// object, such as a plain string or a list.
+    pub fn from_serializable<T: Serialize + ?Sized>(value: &T) -> Result<Self> {
+        match serde_json::to_value(value)? {
+            serde_json::Value::Object(map) => Ok(Self(map.into_iter().collect())),
+            other => Err(Error::Validation(format!(
+                "prompt variables must serialize to a JSON object, got {}",
+                json_type_name(&other)
+            ))),
+        }
+    }
+
+    /// Sets a variable, replacing any previous value with the same name.
+    pub fn with_var(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.0.insert(name.into(), value.into());
+        self
+    }
+
+    /// Returns the value of a variable, if set.
+    pub fn get(&self, name: &str) -> Option<&serde_json::Value> {
+        self.0.get(name)
+    }
+
+    /// Returns the variables as a map.
+    pub fn into_map(self) -> HashMap<String, serde_json::Value> {
+        self.0
+    }
+}
+
+impl From<HashMap<String, serde_json::Value>> for PromptVariables {
+    fn from(variables: HashMap<String, serde_json::Value>) -> Self {
+        Self(variables)
+    }
+}
+
+impl From<PromptVariables> for HashMap<String, serde_json::Value> {
+    fn from(variables: PromptVariables) -> Self {
+        variables.0
+    }
+}
+
+/// Returns the JSON type name of a value for error messages.
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "a boolean",
+        serde_json::Value::Number(_) => "a number",
+        serde_json::Value::String(_) => "a string",
+        serde_json::Value::Array(_) => "an array",
+        serde_json::Value::Object(_) => "an object",
+    }
+}
+
 /// Request to execute a prompt template with completions.
 ///
 /// This allows you to execute saved prompt templates on Portkey, substituting
@@ -33,7 +133,7 @@ use serde::{Deserialize, Serialize};
 ///     user: None,
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct PromptCompletionRequest {
     /// Variables to substitute in the prompt template
     pub variables: HashMap<String, serde_json::Value>,
@@ -92,12 +192,20 @@ pub struct PromptCompletionRequest {
     pub user: Option<String>,
 }
 
+impl PromptCompletionRequest {
+    /// Sets the variables to substitute in the prompt template.
+    pub fn with_variables(mut self, variables: impl Into<PromptVariables>) -> Self {
+        self.variables = variables.into().into_map();
+        self
+    }
+}
+
 /// Response from executing a prompt completion.
 ///
 /// The response contains the status, headers, and body. The body can be
 /// either a chat completion or text completion response depending on the
 /// prompt template configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PromptCompletionResponse {
     /// Response status
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -116,7 +224,7 @@ pub struct PromptCompletionResponse {
 /// This endpoint renders a prompt template by substituting variables and
 /// applying hyperparameters, returning the fully rendered prompt configuration
 /// without executing it.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct PromptRenderRequest {
     /// Variables to substitute in the prompt template
     pub variables: HashMap<String, serde_json::Value>,
@@ -171,11 +279,19 @@ pub struct PromptRenderRequest {
     pub user: Option<String>,
 }
 
+impl PromptRenderRequest {
+    /// Sets the variables to substitute in the prompt template.
+    pub fn with_variables(mut self, variables: impl Into<PromptVariables>) -> Self {
+        self.variables = variables.into().into_map();
+        self
+    }
+}
+
 /// Response from rendering a prompt template.
 ///
 /// Contains the rendered prompt configuration with variables substituted
 /// and hyperparameters applied.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PromptRenderResponse {
     /// Whether the render was successful
     pub success: bool,
@@ -183,3 +299,125 @@ pub struct PromptRenderResponse {
     /// The rendered prompt data - can be chat completion or text completion request
     pub data: serde_json::Value,
 }
+
+impl PromptRenderResponse {
+    /// Returns the rendered prompt as a chat completion request.
+    ///
+    /// The request can be modified and then sent with
+    /// [`ChatService`](crate::service::ChatService). Returns
+    /// [`Error::Serialization`] if the prompt does not render to a chat request.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::{PromptRenderRequest, PromptVariables};
+    /// # use portkey_sdk::service::{ChatService, PromptsService};
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = PromptRenderRequest::default()
+    ///     .with_variables(PromptVariables::new().with_var("user_input", "Hello"));
+    /// let rendered = client.render_prompt("pp-example-123", request).await?;
+    ///
+    /// let mut chat_request = rendered.as_chat_request()?;
+    /// chat_request.temperature = Some(0.2);
+    /// let response = client.create_chat_completion(chat_request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_chat_request(&self) -> Result<ChatCompletionRequest> {
+        Ok(serde_json::from_value(self.data.clone())?)
+    }
+
+    /// Returns the rendered prompt as a text completion request.
+    ///
+    /// Returns [`Error::Serialization`] if the prompt does not render to a
+    /// completion request.
+    pub fn as_completion_request(&self) -> Result<CreateCompletionRequest> {
+        Ok(serde_json::from_value(self.data.clone())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct TemplateVariables {
+        user_input: String,
+        max_items: u32,
+    }
+
+    #[test]
+    fn test_prompt_variables_from_serializable() -> Result<()> {
+        let variables = PromptVariables::from_serializable(&TemplateVariables {
+            user_input: "Hello world".to_string(),
+            max_items: 3,
+        })?
+        .with_var("tone", "friendly");
+
+        let request = PromptCompletionRequest::default().with_variables(variables);
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json["variables"],
+            serde_json::json!({"user_input": "Hello world", "max_items": 3, "tone": "friendly"})
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_prompt_variables_rejects_non_object() {
+        assert!(matches!(
+            PromptVariables::from_serializable("Hello world"),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_render_response_as_chat_request() -> Result<()> {
+        let response: PromptRenderResponse = serde_json::from_value(serde_json::json!({
+            "success": true,
+            "data": {
+                "model": "gpt-4o",
+                "messages": [
+                    {"role": "system", "content": "You are a helpful assistant."},
+                    {"role": "user", "content": "Hello world"}
+                ],
+                "max_tokens": 250,
+                "temperature": 0.7
+            }
+        }))
+        .unwrap();
+
+        let request = response.as_chat_request()?;
+        assert_eq!(request.model, "gpt-4o");
+        assert_eq!(request.messages.len(), 2);
+        assert_eq!(request.max_tokens, Some(250));
+
+        assert!(matches!(
+            response.as_completion_request(),
+            Ok(CreateCompletionRequest { prompt: None, .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_response_as_completion_request() -> Result<()> {
+        let response: PromptRenderResponse = serde_json::from_value(serde_json::json!({
+            "success": true,
+            "data": {"model": "gpt-3.5-turbo-instruct", "prompt": "Say hello", "max_tokens": 16}
+        }))
+        .unwrap();
+
+        let request = response.as_completion_request()?;
+        assert_eq!(request.model, "gpt-3.5-turbo-instruct");
+        assert!(matches!(
+            response.as_chat_request(),
+            Err(Error::Serialization(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,429 @@
+//! Responses API generation models.
+//!
+//! These types model OpenAI's Responses API (`POST /responses` with `input`),
+//! which generates model output. They are unrelated to the logging-oriented
+//! [`Response`](super::Response) records managed by
+//! [`ResponsesService`](crate::service::ResponsesService).
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Request to generate a response with the Responses API.
+///
+/// # Example
+///
+/// ```
+/// use portkey_sdk::model::CreateResponseGenerationRequest;
+///
+/// let request = CreateResponseGenerationRequest::new("gpt-4o", "Write a haiku about Rust.")
+///     .with_instructions("You are a poet.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateResponseGenerationRequest {
+    /// ID of the model to use
+    pub model: String,
+    /// Text or messages used as input to the model
+    pub input: ResponseInput,
+    /// System instructions inserted into the model's context
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// Tools the model may call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<ResponseTool>>,
+    /// Controls which tool is called
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<serde_json::Value>,
+    /// ID of a previous response to continue a conversation from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_response_id: Option<String>,
+    /// Upper bound on the number of generated tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<i32>,
+    /// Sampling temperature (0-2)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// Nucleus sampling parameter (0-1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    /// Whether to store the response for later retrieval
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<bool>,
+    /// Whether to stream the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+    /// Set of key-value pairs attached to the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    /// A unique identifier for the end-user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl CreateResponseGenerationRequest {
+    /// Creates a new request with the minimum required fields.
+    pub fn new(model: impl Into<String>, input: impl Into<ResponseInput>) -> Self {
+        Self {
+            model: model.into(),
+            input: input.into(),
+            instructions: None,
+            tools: None,
+            tool_choice: None,
+            previous_response_id: None,
+            max_output_tokens: None,
+            temperature: None,
+            top_p: None,
+            store: None,
+            stream: None,
+            metadata: None,
+            user: None,
+        }
+    }
+
+    /// Sets the system instructions.
+    pub fn with_instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.instructions = Some(instructions.into());
+        self
+    }
+
+    /// Adds a tool the model may call.
+    pub fn with_tool(mut self, tool: ResponseTool) -> Self {
+        self.tools.get_or_insert_with(Vec::new).push(tool);
+        self
+    }
+
+    /// Continues the conversation from a previous response.
+    pub fn with_previous_response_id(mut self, id: impl Into<String>) -> Self {
+        self.previous_response_id = Some(id.into());
+        self
+    }
+}
+
+impl Default for CreateResponseGenerationRequest {
+    fn default() -> Self {
+        Self::new(String::new(), String::new())
+    }
+}
+
+/// Input to the Responses API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ResponseInput {
+    /// A single text input, treated as a user message
+    Text(String),
+    /// A list of messages
+    Messages(Vec<ResponseInputMessage>),
+}
+
+impl From<String> for ResponseInput {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for ResponseInput {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<Vec<ResponseInputMessage>> for ResponseInput {
+    fn from(messages: Vec<ResponseInputMessage>) -> Self {
+        Self::Messages(messages)
+    }
+}
+
+/// A message in the input of a Responses API request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseInputMessage {
+    /// The role of the message author (user, assistant, system, or developer)
+    pub role: String,
+    /// The text content of the message
+    pub content: String,
+}
+
+impl ResponseInputMessage {
+    /// Creates a user message.
+    pub fn user(content: impl Into<String>) -> Self {
+        Self {
+            role: "user".to_string(),
+            content: content.into(),
+        }
+    }
+
+    /// Creates an assistant message.
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self {
+            role: "assistant".to_string(),
+            content: content.into(),
+        }
+    }
+}
+
+/// A tool available to the Responses API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseTool {
+    /// A function defined by the caller
+    Function {
+        /// The name of the function
+        name: String,
+        /// A description of what the function does
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// The parameters the function accepts (JSON Schema)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parameters: Option<serde_json::Value>,
+        /// Whether to enable strict schema adherence
+        #[serde(skip_serializing_if = "Option::is_none")]
+        strict: Option<bool>,
+    },
+    /// Search over uploaded files
+    FileSearch {
+        /// The vector stores to search
+        vector_store_ids: Vec<String>,
+    },
+    /// Search the web
+    WebSearchPreview,
+}
+
+/// A response generated by the Responses API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseGeneration {
+    /// Unique identifier for the response
+    pub id: String,
+    /// The object type (always "response")
+    pub object: String,
+    /// The Unix timestamp when the response was created
+    pub created_at: i64,
+    /// The status of the response (completed, failed, in_progress, or incomplete)
+    pub status: String,
+    /// The model used to generate the response
+    pub model: String,
+    /// The generated output items
+    #[serde(default)]
+    pub output: Vec<ResponseOutputItem>,
+    /// The instructions used for the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// ID of the previous response in the conversation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_response_id: Option<String>,
+    /// Error details if the response failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<serde_json::Value>,
+    /// Token usage statistics
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<ResponseGenerationUsage>,
+    /// Set of key-value pairs attached to the response
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, String>,
+}
+
+impl ResponseGeneration {
+    /// Returns the concatenated text of all output messages.
+    pub fn output_text(&self) -> String {
+        self.output
+            .iter()
+            .filter_map(|item| match item {
+                ResponseOutputItem::Message { content, .. } => Some(content),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|content| match content {
+                ResponseOutputContent::OutputText { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// An item in the output of a generated response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseOutputItem {
+    /// A message from the model
+    Message {
+        /// The ID of the output item
+        id: String,
+        /// The role of the message author (always "assistant")
+        role: String,
+        /// The content of the message
+        content: Vec<ResponseOutputContent>,
+    },
+    /// A call to a function tool
+    FunctionCall {
+        /// The ID of the tool call, used when submitting its output
+        call_id: String,
+        /// The name of the function
+        name: String,
+        /// The arguments to the function, as a JSON string
+        arguments: String,
+    },
+    /// An output item type not modeled by this SDK
+    #[serde(other)]
+    Other,
+}
+
+/// Content of an output message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseOutputContent {
+    /// Generated text
+    OutputText {
+        /// The text content
+        text: String,
+        /// Annotations on the text, such as citations
+        #[serde(default)]
+        annotations: Vec<serde_json::Value>,
+    },
+    /// A refusal from the model
+    Refusal {
+        /// The refusal explanation
+        refusal: String,
+    },
+    /// A content type not modeled by this SDK
+    #[serde(other)]
+    Other,
+}
+
+/// Token usage of a generated response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseGenerationUsage {
+    /// Number of input tokens
+    pub input_tokens: i32,
+    /// Number of output tokens
+    pub output_tokens: i32,
+    /// Total number of tokens used
+    pub total_tokens: i32,
+}
+
+/// An event in a streamed Responses API generation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ResponseStreamEvent {
+    /// The response was created
+    #[serde(rename = "response.created")]
+    Created {
+        /// The response in its initial state
+        response: ResponseGeneration,
+    },
+    /// A piece of output text was generated
+    #[serde(rename = "response.output_text.delta")]
+    OutputTextDelta {
+        /// The ID of the output item
+        item_id: String,
+        /// The index of the output item
+        output_index: i32,
+        /// The index of the content part
+        content_index: i32,
+        /// The generated text
+        delta: String,
+    },
+    /// An output text part is complete
+    #[serde(rename = "response.output_text.done")]
+    OutputTextDone {
+        /// The ID of the output item
+        item_id: String,
+        /// The index of the output item
+        output_index: i32,
+        /// The index of the content part
+        content_index: i32,
+        /// The full text of the content part
+        text: String,
+    },
+    /// The response completed
+    #[serde(rename = "response.completed")]
+    Completed {
+        /// The final response
+        response: ResponseGeneration,
+    },
+    /// The response failed
+    #[serde(rename = "response.failed")]
+    Failed {
+        /// The failed response, including its error
+        response: ResponseGeneration,
+    },
+    /// An error occurred during generation
+    #[serde(rename = "error")]
+    Error {
+        /// The error code
+        code: Option<String>,
+        /// A human-readable description of the error
+        message: String,
+    },
+    /// An event type not modeled by this SDK
+    #[serde(other)]
+    Other,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_serialization() {
+        let request = CreateResponseGenerationRequest::new("gpt-4o", "Hello!").with_tool(
+            ResponseTool::Function {
+                name: "get_weather".to_string(),
+                description: None,
+                parameters: None,
+                strict: None,
+            },
+        );
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["input"], "Hello!");
+        assert_eq!(json["tools"][0]["type"], "function");
+        assert_eq!(json["tools"][0]["name"], "get_weather");
+        assert!(json.get("instructions").is_none());
+    }
+
+    #[test]
+    fn test_response_output_text() {
+        let response: ResponseGeneration = serde_json::from_value(serde_json::json!({
+            "id": "resp_123",
+            "object": "response",
+            "created_at": 1741476542,
+            "status": "completed",
+            "model": "gpt-4o",
+            "output": [
+                { "type": "reasoning", "id": "rs_1", "summary": [] },
+                {
+                    "type": "message",
+                    "id": "msg_1",
+                    "status": "completed",
+                    "role": "assistant",
+                    "content": [{ "type": "output_text", "text": "Hello there!", "annotations": [] }]
+                }
+            ],
+            "usage": { "input_tokens": 5, "output_tokens": 3, "total_tokens": 8 }
+        }))
+        .unwrap();
+
+        assert!(matches!(response.output[0], ResponseOutputItem::Other));
+        assert_eq!(response.output_text(), "Hello there!");
+    }
+
+    #[test]
+    fn test_stream_event_deserialization() {
+        let event: ResponseStreamEvent = serde_json::from_value(serde_json::json!({
+            "type": "response.output_text.delta",
+            "item_id": "msg_1",
+            "output_index": 0,
+            "content_index": 0,
+            "delta": "Hel"
+        }))
+        .unwrap();
+        assert!(
+            matches!(event, ResponseStreamEvent::OutputTextDelta { ref delta, .. } if delta == "Hel")
+        );
+
+        let event: ResponseStreamEvent = serde_json::from_value(serde_json::json!({
+            "type": "response.in_progress",
+            "response": {}
+        }))
+        .unwrap();
+        assert!(matches!(event, ResponseStreamEvent::Other));
+    }
+}
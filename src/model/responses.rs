@@ -1,12 +1,15 @@
 use std::collections::HashMap;
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Error, Result};
+
 /// Represents a response from the Portkey API.
 ///
 /// A response contains information about API calls made through Portkey,
 /// including metadata, status, and associated input/output items.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Response {
     /// Unique identifier for the response
     pub id: String,
@@ -68,6 +71,53 @@ pub struct Response {
     pub cost: Option<f64>,
 }
 
+impl Response {
+    /// Deserializes the logged request body into a typed request.
+    ///
+    /// Fails with [`Error::UnexpectedResponse`] if no request body was logged
+    /// or if it does not match `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::{ChatCompletionRequest, Response};
+    ///
+    /// let logged: Response = serde_json::from_value(serde_json::json!({
+    ///     "id": "resp_abc123",
+    ///     "request": {
+    ///         "model": "gpt-4o",
+    ///         "messages": [{"role": "user", "content": "Hello"}],
+    ///     },
+    /// })).unwrap();
+    ///
+    /// let request: ChatCompletionRequest = logged.request_as().unwrap();
+    /// assert_eq!(request.model, "gpt-4o");
+    /// ```
+    pub fn request_as<T: DeserializeOwned>(&self) -> Result<T> {
+        body_as(self.request.as_ref(), "request")
+    }
+
+    /// Deserializes the logged response body into a typed response.
+    ///
+    /// Fails with [`Error::UnexpectedResponse`] if no response body was
+    /// logged or if it does not match `T`.
+    pub fn response_as<T: DeserializeOwned>(&self) -> Result<T> {
+        body_as(self.response.as_ref(), "response")
+    }
+}
+
+/// Deserializes a logged request or response body.
+fn body_as<T: DeserializeOwned>(body: Option<&serde_json::Value>, kind: &str) -> Result<T> {
+    let body =
+        body.ok_or_else(|| Error::UnexpectedResponse(format!("no {kind} body was logged")))?;
+    T::deserialize(body).map_err(|error| {
+        Error::UnexpectedResponse(format!(
+            "logged {kind} body is not a `{}`: {error}",
+            std::any::type_name::<T>()
+        ))
+    })
+}
+
 /// Request body for creating a new response.
 ///
 /// # Example
@@ -94,7 +144,7 @@ pub struct Response {
 ///     cost: Some(0.003),
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateResponseRequest {
     /// The trace ID to associate with this response
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -149,7 +199,7 @@ pub struct CreateResponseRequest {
 ///
 /// Input items contain the individual messages or prompts that were
 /// part of the request to the AI provider.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputItem {
     /// Unique identifier for the input item
     pub id: String,
@@ -178,7 +228,7 @@ pub struct InputItem {
 /// Response containing a list of input items.
 ///
 /// Returned by the list input items endpoint.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ListInputItemsResponse {
     /// List of input items
     pub data: Vec<InputItem>,
@@ -206,7 +256,7 @@ pub struct ListInputItemsResponse {
 ///     offset: Some(0),
 /// };
 /// ```
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ListInputItemsParams {
     /// Maximum number of items to return
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -216,3 +266,58 @@ pub struct ListInputItemsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<i32>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{ChatCompletionRequest, ChatCompletionResponse};
+
+    fn logged(request: serde_json::Value, response: Option<serde_json::Value>) -> Response {
+        serde_json::from_value(serde_json::json!({
+            "id": "resp_abc123",
+            "request": request,
+            "response": response,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_typed_bodies() {
+        let logged = logged(
+            serde_json::json!({
+                "model": "gpt-4o",
+                "messages": [{"role": "user", "content": "Hello"}],
+            }),
+            Some(serde_json::json!({
+                "id": "chatcmpl-123",
+                "object": "chat.completion",
+                "created": 1677652288,
+                "model": "gpt-4o",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "Hi there!"},
+                    "finish_reason": "stop",
+                }],
+            })),
+        );
+
+        let request: ChatCompletionRequest = logged.request_as().unwrap();
+        assert_eq!(request.model, "gpt-4o");
+        assert_eq!(request.messages.len(), 1);
+
+        let response: ChatCompletionResponse = logged.response_as().unwrap();
+        assert_eq!(response.id, "chatcmpl-123");
+    }
+
+    #[test]
+    fn test_typed_body_errors() {
+        let logged = logged(serde_json::json!({"input": "Hello"}), None);
+
+        let error = logged.request_as::<ChatCompletionRequest>().unwrap_err();
+        assert!(matches!(error, Error::UnexpectedResponse(_)));
+        assert!(error.to_string().contains("ChatCompletionRequest"));
+
+        let error = logged.response_as::<ChatCompletionResponse>().unwrap_err();
+        assert!(error.to_string().contains("no response body was logged"));
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
+use std::fmt;
 
-use serde::{Deserialize, Serialize};
+use derive_builder::Builder;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::assistants::AssistantTool;
 use super::chat::{FunctionCall as ChatFunctionCall, ResponseFormat, ToolChoice as ChatToolChoice};
@@ -9,70 +11,99 @@ use super::chat::{FunctionCall as ChatFunctionCall, ResponseFormat, ToolChoice a
 ///
 /// # Example
 ///
-/// ```rust,ignore
-/// use portkey::model::CreateRunRequest;
+/// ```
+/// use portkey_sdk::model::CreateRunRequest;
 ///
 /// let request = CreateRunRequest::builder()
 ///     .assistant_id("asst_abc123")
+///     .instructions("Address the user as Jane Doe.")
 ///     .build()
 ///     .unwrap();
+/// assert_eq!(request.model, None);
 /// ```
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(
+    pattern = "owned",
+    setter(into, strip_option),
+    build_fn(error = "crate::Error")
+)]
 pub struct CreateRunRequest {
     /// The ID of the assistant to use to execute this run.
     pub assistant_id: String,
 
     /// The ID of the Model to be used to execute this run.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub model: Option<String>,
 
     /// Override the default system message of the assistant.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub instructions: Option<String>,
 
     /// Appends additional instructions at the end of the instructions for the run.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub additional_instructions: Option<String>,
 
     /// Override the tools the assistant can use for this run.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub tools: Option<Vec<AssistantTool>>,
 
     /// Set of key-value pairs that can be attached to an object.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub metadata: Option<HashMap<String, String>>,
 
     /// What sampling temperature to use, between 0 and 2.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub temperature: Option<f32>,
 
     /// An alternative to sampling with temperature.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub top_p: Option<f32>,
 
     /// The maximum number of prompt tokens that may be used over the course of the run.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub max_prompt_tokens: Option<i32>,
 
     /// The maximum number of completion tokens that may be used over the course of the run.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub max_completion_tokens: Option<i32>,
 
     /// Controls for how a thread will be truncated.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub truncation_strategy: Option<TruncationStrategy>,
 
     /// Controls which (if any) tool is called by the model.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub tool_choice: Option<ChatToolChoice>,
 
     /// Specifies the format that the model must output.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
     pub response_format: Option<ResponseFormat>,
 }
 
+impl CreateRunRequest {
+    /// Creates a new run request builder.
+    ///
+    /// `build` fails with [`Error::Validation`](crate::Error::Validation) if
+    /// a required field is not set.
+    pub fn builder() -> CreateRunRequestBuilder {
+        CreateRunRequestBuilder::default()
+    }
+}
+
 /// Modifies a run.
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ModifyRunRequest {
     /// Set of key-value pairs that can be attached to an object.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -80,14 +111,14 @@ pub struct ModifyRunRequest {
 }
 
 /// Request to submit tool outputs to run.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct SubmitToolOutputsRequest {
     /// A list of tools for which the outputs are being submitted.
     pub tool_outputs: Vec<ToolOutput>,
 }
 
 /// Output from a tool.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ToolOutput {
     /// The ID of the tool call.
     pub tool_call_id: String,
@@ -97,7 +128,7 @@ pub struct ToolOutput {
 }
 
 /// A run object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Run {
     /// The identifier of the run.
     pub id: String,
@@ -115,7 +146,7 @@ pub struct Run {
     pub assistant_id: String,
 
     /// The status of the run.
-    pub status: String,
+    pub status: RunStatus,
 
     /// Details on the action required to continue the run.
     pub required_action: Option<RequiredAction>,
@@ -148,6 +179,10 @@ pub struct Run {
     pub tools: Vec<AssistantTool>,
 
     /// The list of File IDs the assistant used for this run.
+    ///
+    /// Not returned by the Assistants v2 API.
+    #[deprecated(note = "not returned by the Assistants v2 API")]
+    #[serde(default)]
     pub file_ids: Vec<String>,
 
     /// Set of key-value pairs that can be attached to an object.
@@ -178,8 +213,207 @@ pub struct Run {
     pub response_format: Option<ResponseFormat>,
 }
 
+impl Run {
+    /// Returns the state of the run as a single value to branch on.
+    ///
+    /// Combines `status`, `required_action`, and `last_error`. Statuses that
+    /// are not terminal (`queued`, `in_progress`, `cancelling`, or any status
+    /// this SDK does not know yet) map to [`RunOutcome::InProgress`], as does
+    /// `requires_action` if the response carries no action details.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::model::{Run, RunOutcome};
+    /// # fn example(run: Run) {
+    /// match run.outcome() {
+    ///     RunOutcome::Completed => println!("Run completed"),
+    ///     RunOutcome::RequiresAction(action) => {
+    ///         for call in action.submit_tool_outputs.tool_calls {
+    ///             println!("Call {} with {}", call.function.name, call.function.arguments);
+    ///         }
+    ///     }
+    ///     RunOutcome::Failed(error) => println!("Run failed: {error:?}"),
+    ///     RunOutcome::InProgress => println!("Still running"),
+    ///     other => println!("Run ended: {other:?}"),
+    /// }
+    /// # }
+    /// ```
+    pub fn outcome(&self) -> RunOutcome {
+        match self.status {
+            RunStatus::Completed => RunOutcome::Completed,
+            RunStatus::RequiresAction => match &self.required_action {
+                Some(action) => RunOutcome::RequiresAction(action.clone()),
+                None => RunOutcome::InProgress,
+            },
+            RunStatus::Failed => RunOutcome::Failed(self.last_error.clone()),
+            RunStatus::Cancelled => RunOutcome::Cancelled,
+            RunStatus::Expired => RunOutcome::Expired,
+            RunStatus::Incomplete => RunOutcome::Incomplete,
+            RunStatus::Queued
+            | RunStatus::InProgress
+            | RunStatus::Cancelling
+            | RunStatus::Other(_) => RunOutcome::InProgress,
+        }
+    }
+
+    /// Returns `true` if the run is queued, in progress or waiting for tool
+    /// outputs, and so can still be cancelled.
+    pub fn is_cancellable(&self) -> bool {
+        self.status.is_cancellable()
+    }
+
+    /// Returns `true` if the run will not change state anymore.
+    pub fn is_terminal(&self) -> bool {
+        self.status.is_terminal()
+    }
+}
+
+/// The status of a run.
+///
+/// Statuses this SDK does not know yet deserialize as
+/// [`RunStatus::Other`] and serialize back to the same string.
+///
+/// # Example
+///
+/// ```
+/// use portkey_sdk::model::RunStatus;
+///
+/// assert_eq!(RunStatus::from("requires_action"), RunStatus::RequiresAction);
+/// assert_eq!(RunStatus::from("paused").as_str(), "paused");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RunStatus {
+    /// The run is waiting to be picked up (`"queued"`).
+    Queued,
+    /// The run is executing (`"in_progress"`).
+    InProgress,
+    /// The run is waiting for tool outputs to be submitted
+    /// (`"requires_action"`).
+    RequiresAction,
+    /// The run is being cancelled (`"cancelling"`).
+    Cancelling,
+    /// The run was cancelled (`"cancelled"`).
+    Cancelled,
+    /// The run failed (`"failed"`).
+    Failed,
+    /// The run completed successfully (`"completed"`).
+    Completed,
+    /// The run ended early, for example after reaching a token limit
+    /// (`"incomplete"`).
+    Incomplete,
+    /// The run expired before it completed (`"expired"`).
+    Expired,
+    /// Any other status, not yet known to this SDK.
+    Other(String),
+}
+
+impl RunStatus {
+    /// Returns the wire representation of the status.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Queued => "queued",
+            Self::InProgress => "in_progress",
+            Self::RequiresAction => "requires_action",
+            Self::Cancelling => "cancelling",
+            Self::Cancelled => "cancelled",
+            Self::Failed => "failed",
+            Self::Completed => "completed",
+            Self::Incomplete => "incomplete",
+            Self::Expired => "expired",
+            Self::Other(status) => status,
+        }
+    }
+
+    /// Returns `true` if the run will not change state anymore.
+    ///
+    /// [`Other`](Self::Other) is treated as not terminal, so polling
+    /// continues until a known final status is reached.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Cancelled | Self::Failed | Self::Completed | Self::Incomplete | Self::Expired
+        )
+    }
+
+    /// Returns `true` if a run with this status can still be cancelled.
+    pub fn is_cancellable(&self) -> bool {
+        matches!(self, Self::Queued | Self::InProgress | Self::RequiresAction)
+    }
+}
+
+impl fmt::Display for RunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for RunStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "queued" => Self::Queued,
+            "in_progress" => Self::InProgress,
+            "requires_action" => Self::RequiresAction,
+            "cancelling" => Self::Cancelling,
+            "cancelled" => Self::Cancelled,
+            "failed" => Self::Failed,
+            "completed" => Self::Completed,
+            "incomplete" => Self::Incomplete,
+            "expired" => Self::Expired,
+            _ => Self::Other(status.to_string()),
+        }
+    }
+}
+
+impl From<String> for RunStatus {
+    fn from(status: String) -> Self {
+        match Self::from(status.as_str()) {
+            Self::Other(_) => Self::Other(status),
+            known => known,
+        }
+    }
+}
+
+impl Serialize for RunStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RunStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// The state of a run, as returned by [`Run::outcome`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum RunOutcome {
+    /// The run completed successfully.
+    Completed,
+    /// The run is waiting for tool outputs to be submitted.
+    RequiresAction(RequiredAction),
+    /// The run failed, with the error reported by the API if any.
+    Failed(Option<RunError>),
+    /// The run was cancelled.
+    Cancelled,
+    /// The run expired before it completed.
+    Expired,
+    /// The run ended early, for example after reaching a token limit.
+    Incomplete,
+    /// The run is queued or still executing.
+    InProgress,
+}
+
+impl RunOutcome {
+    /// Returns `true` if the run will not change state anymore.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, Self::RequiresAction(_) | Self::InProgress)
+    }
+}
+
 /// Details on the action required to continue the run.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RequiredAction {
     /// The type of action required.
     #[serde(rename = "type")]
@@ -190,14 +424,14 @@ pub struct RequiredAction {
 }
 
 /// Details on the tool outputs needed for this run to continue.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SubmitToolOutputs {
     /// A list of the relevant tool calls.
     pub tool_calls: Vec<ToolCall>,
 }
 
 /// A tool call that the assistant made.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ToolCall {
     /// The ID of the tool call.
     pub id: String,
@@ -211,7 +445,7 @@ pub struct ToolCall {
 }
 
 /// Error information for a failed run.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RunError {
     /// One of server_error, rate_limit_exceeded, or invalid_prompt.
     pub code: String,
@@ -221,7 +455,7 @@ pub struct RunError {
 }
 
 /// Usage statistics for a run.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RunUsage {
     /// Number of completion tokens used.
     pub completion_tokens: i32,
@@ -234,7 +468,7 @@ pub struct RunUsage {
 }
 
 /// Controls for how a thread will be truncated prior to the run.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum TruncationStrategy {
     #[serde(rename = "auto")]
@@ -244,7 +478,7 @@ pub enum TruncationStrategy {
 }
 
 /// Response containing a list of runs.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListRunsResponse {
     pub object: String,
     pub data: Vec<Run>,
@@ -254,7 +488,7 @@ pub struct ListRunsResponse {
 }
 
 /// A run step object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RunStep {
     /// The identifier of the run step.
     pub id: String,
@@ -279,7 +513,7 @@ pub struct RunStep {
     pub step_type: String,
 
     /// The status of the run step.
-    pub status: String,
+    pub status: RunStepStatus,
 
     /// The details of the run step.
     pub step_details: StepDetails,
@@ -307,7 +541,7 @@ pub struct RunStep {
 }
 
 /// Details of a run step.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum StepDetails {
     #[serde(rename = "message_creation")]
@@ -316,15 +550,71 @@ pub enum StepDetails {
     ToolCalls { tool_calls: Vec<ToolCall> },
 }
 
+impl RunStep {
+    /// Returns the ID of the message created by this step, if it is a
+    /// message creation step.
+    pub fn created_message_id(&self) -> Option<&str> {
+        match &self.step_details {
+            StepDetails::MessageCreation { message_creation } => Some(&message_creation.message_id),
+            StepDetails::ToolCalls { .. } => None,
+        }
+    }
+
+    /// Returns the tool calls made in this step, or an empty slice if it is
+    /// not a tool calls step.
+    pub fn tool_calls(&self) -> &[ToolCall] {
+        match &self.step_details {
+            StepDetails::ToolCalls { tool_calls } => tool_calls,
+            StepDetails::MessageCreation { .. } => &[],
+        }
+    }
+
+    /// Returns `true` if the step will not change state anymore.
+    pub fn is_terminal(&self) -> bool {
+        self.status.is_terminal()
+    }
+}
+
+/// The status of a run step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStepStatus {
+    /// The step is still executing.
+    InProgress,
+    /// The step was cancelled.
+    Cancelled,
+    /// The step failed.
+    Failed,
+    /// The step completed successfully.
+    Completed,
+    /// The step expired before it completed.
+    Expired,
+    /// A status this SDK does not know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+impl RunStepStatus {
+    /// Returns `true` if the step will not change state anymore.
+    ///
+    /// [`Unknown`](Self::Unknown) is treated as not terminal.
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            Self::Cancelled | Self::Failed | Self::Completed | Self::Expired
+        )
+    }
+}
+
 /// Details of a message creation step.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageCreation {
     /// The ID of the message that was created by this run step.
     pub message_id: String,
 }
 
 /// Response containing a list of run steps.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListRunStepsResponse {
     pub object: String,
     pub data: Vec<RunStep>,
@@ -332,3 +622,204 @@ pub struct ListRunStepsResponse {
     pub last_id: Option<String>,
     pub has_more: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_run_request_builder() {
+        let request = CreateRunRequest::builder()
+            .assistant_id("asst_abc123")
+            .temperature(0.2)
+            .build()
+            .unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"assistant_id": "asst_abc123", "temperature": 0.2f32})
+        );
+
+        let error = CreateRunRequest::builder()
+            .model("gpt-4o")
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, crate::Error::Validation(_)));
+        assert!(error.to_string().contains("assistant_id"));
+    }
+
+    fn run(extra: serde_json::Value) -> Run {
+        let mut json = serde_json::json!({
+            "id": "run_abc123",
+            "object": "thread.run",
+            "created_at": 1699063290,
+            "thread_id": "thread_abc123",
+            "assistant_id": "asst_abc123",
+            "status": "in_progress",
+            "required_action": null,
+            "last_error": null,
+            "expires_at": null,
+            "started_at": null,
+            "cancelled_at": null,
+            "failed_at": null,
+            "completed_at": null,
+            "model": "gpt-4o",
+            "instructions": "",
+            "tools": [],
+            "file_ids": [],
+            "metadata": {},
+            "usage": null
+        });
+        for (key, value) in extra.as_object().unwrap() {
+            json[key] = value.clone();
+        }
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_outcome_requires_action() {
+        let run = run(serde_json::json!({
+            "status": "requires_action",
+            "required_action": {
+                "type": "submit_tool_outputs",
+                "submit_tool_outputs": {
+                    "tool_calls": [{
+                        "id": "call_abc123",
+                        "type": "function",
+                        "function": { "name": "get_weather", "arguments": "{}" }
+                    }]
+                }
+            }
+        }));
+
+        match run.outcome() {
+            RunOutcome::RequiresAction(action) => {
+                assert_eq!(action.submit_tool_outputs.tool_calls[0].id, "call_abc123");
+            }
+            other => panic!("unexpected outcome: {other:?}"),
+        }
+        assert!(!run.outcome().is_terminal());
+    }
+
+    #[test]
+    fn test_outcome_failed() {
+        let run = run(serde_json::json!({
+            "status": "failed",
+            "last_error": { "code": "rate_limit_exceeded", "message": "Slow down" }
+        }));
+
+        match run.outcome() {
+            RunOutcome::Failed(Some(error)) => assert_eq!(error.code, "rate_limit_exceeded"),
+            other => panic!("unexpected outcome: {other:?}"),
+        }
+        assert!(run.outcome().is_terminal());
+    }
+
+    #[test]
+    fn test_outcome_statuses() {
+        assert!(matches!(
+            run(serde_json::json!({ "status": "completed" })).outcome(),
+            RunOutcome::Completed
+        ));
+        assert!(matches!(
+            run(serde_json::json!({ "status": "queued" })).outcome(),
+            RunOutcome::InProgress
+        ));
+        assert!(matches!(
+            run(serde_json::json!({ "status": "expired" })).outcome(),
+            RunOutcome::Expired
+        ));
+    }
+
+    #[test]
+    fn test_run_status() {
+        let waiting = run(serde_json::json!({ "status": "requires_action" }));
+        assert_eq!(waiting.status, RunStatus::RequiresAction);
+        assert!(!waiting.is_terminal());
+
+        for status in ["completed", "failed", "cancelled", "expired", "incomplete"] {
+            assert!(run(serde_json::json!({ "status": status })).is_terminal());
+        }
+
+        let status: RunStatus = serde_json::from_str(r#""paused""#).unwrap();
+        assert_eq!(status, RunStatus::Other("paused".to_string()));
+        assert!(!status.is_terminal());
+        assert!(!status.is_cancellable());
+        assert_eq!(serde_json::to_value(&status).unwrap(), "paused");
+        assert_eq!(
+            serde_json::to_value(RunStatus::InProgress).unwrap(),
+            "in_progress"
+        );
+    }
+
+    #[test]
+    fn test_is_cancellable() {
+        for status in ["queued", "in_progress", "requires_action"] {
+            assert!(run(serde_json::json!({ "status": status })).is_cancellable());
+        }
+        for status in ["cancelling", "cancelled", "completed", "failed", "expired"] {
+            assert!(!run(serde_json::json!({ "status": status })).is_cancellable());
+        }
+    }
+
+    fn run_step(status: &str, step_details: serde_json::Value) -> RunStep {
+        serde_json::from_value(serde_json::json!({
+            "id": "step_abc123",
+            "object": "thread.run.step",
+            "created_at": 1699063291,
+            "assistant_id": "asst_abc123",
+            "thread_id": "thread_abc123",
+            "run_id": "run_abc123",
+            "type": step_details["type"],
+            "status": status,
+            "step_details": step_details,
+            "last_error": null,
+            "expired_at": null,
+            "cancelled_at": null,
+            "failed_at": null,
+            "completed_at": null,
+            "metadata": {},
+            "usage": null
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_run_step_message_creation() {
+        let step = run_step(
+            "completed",
+            serde_json::json!({
+                "type": "message_creation",
+                "message_creation": { "message_id": "msg_abc123" }
+            }),
+        );
+
+        assert_eq!(step.status, RunStepStatus::Completed);
+        assert!(step.is_terminal());
+        assert_eq!(step.created_message_id(), Some("msg_abc123"));
+        assert!(step.tool_calls().is_empty());
+    }
+
+    #[test]
+    fn test_run_step_tool_calls() {
+        let step = run_step(
+            "in_progress",
+            serde_json::json!({
+                "type": "tool_calls",
+                "tool_calls": [{
+                    "id": "call_abc123",
+                    "type": "function",
+                    "function": { "name": "get_weather", "arguments": "{}" }
+                }]
+            }),
+        );
+
+        assert!(!step.is_terminal());
+        assert_eq!(step.created_message_id(), None);
+        assert_eq!(step.tool_calls()[0].function.name, "get_weather");
+
+        let step = run_step("paused", serde_json::to_value(&step.step_details).unwrap());
+        assert_eq!(step.status, RunStepStatus::Unknown);
+        assert!(!step.is_terminal());
+    }
+}
@@ -2,38 +2,109 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use super::assistants::ToolResources;
+use super::messages::{AttachmentTool, MessageAttachment};
+
 /// Request to create a thread.
 ///
 /// # Example
 ///
-/// ```rust,ignore
-/// use portkey::model::CreateThreadRequest;
+/// ```
+/// use portkey_sdk::model::{AttachmentTool, CreateThreadRequest};
 ///
-/// let request = CreateThreadRequest::builder()
-///     .build()
-///     .unwrap();
+/// let request = CreateThreadRequest::new()
+///     .with_message("user", "Summarize the attached report.")
+///     .with_message_files(
+///         "user",
+///         "Compare it with last quarter.",
+///         ["file-q3", "file-q2"],
+///         vec![AttachmentTool::FileSearch],
+///     )
+///     .with_metadata("customer", "acme");
+/// assert_eq!(request.messages.unwrap().len(), 2);
 /// ```
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateThreadRequest {
     /// A list of messages to start the thread with.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<Vec<ThreadMessage>>,
 
+    /// Resources made available to the assistant's tools in this thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
     /// Set of key-value pairs that can be attached to an object.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, String>>,
 }
 
+impl CreateThreadRequest {
+    /// Creates a request for an empty thread.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a message to start the thread with.
+    pub fn with_message(self, role: impl Into<String>, content: impl Into<String>) -> Self {
+        self.with_thread_message(ThreadMessage::new(role, content))
+    }
+
+    /// Appends a message with attached files, each added to the given tools.
+    pub fn with_message_files<I, S>(
+        self,
+        role: impl Into<String>,
+        content: impl Into<String>,
+        file_ids: I,
+        tools: Vec<AttachmentTool>,
+    ) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let message =
+            file_ids
+                .into_iter()
+                .fold(ThreadMessage::new(role, content), |message, file_id| {
+                    message.with_attachment(MessageAttachment::new(file_id, tools.clone()))
+                });
+        self.with_thread_message(message)
+    }
+
+    /// Appends a message to start the thread with.
+    pub fn with_thread_message(mut self, message: ThreadMessage) -> Self {
+        self.messages.get_or_insert_with(Vec::new).push(message);
+        self
+    }
+
+    /// Sets the resources made available to the assistant's tools.
+    pub fn with_tool_resources(mut self, tool_resources: ToolResources) -> Self {
+        self.tool_resources = Some(tool_resources);
+        self
+    }
+
+    /// Adds a metadata key-value pair.
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+}
+
 /// Modifies a thread.
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ModifyThreadRequest {
+    /// Resources made available to the assistant's tools in this thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
     /// Set of key-value pairs that can be attached to an object.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, String>>,
 }
 
 /// A thread object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Thread {
     /// The identifier of the thread.
     pub id: String,
@@ -44,12 +115,16 @@ pub struct Thread {
     /// The Unix timestamp (in seconds) for when the thread was created.
     pub created_at: i64,
 
+    /// Resources made available to the assistant's tools in this thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_resources: Option<ToolResources>,
+
     /// Set of key-value pairs that can be attached to an object.
     pub metadata: HashMap<String, String>,
 }
 
 /// Response from deleting a thread.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeleteThreadResponse {
     pub id: String,
     pub object: String,
@@ -57,7 +132,7 @@ pub struct DeleteThreadResponse {
 }
 
 /// A message in a thread.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ThreadMessage {
     /// The role of the entity that is creating the message.
     pub role: String,
@@ -66,10 +141,100 @@ pub struct ThreadMessage {
     pub content: String,
 
     /// A list of File IDs that the message should use.
+    ///
+    /// Ignored by the Assistants v2 API, use `attachments` instead.
+    #[deprecated(note = "use `attachments` instead")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file_ids: Option<Vec<String>>,
 
+    /// Files attached to the message and the tools they are added to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<MessageAttachment>>,
+
     /// Set of key-value pairs that can be attached to an object.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, String>>,
 }
+
+impl ThreadMessage {
+    /// Creates a message with the given role and content.
+    #[allow(deprecated)]
+    pub fn new(role: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            role: role.into(),
+            content: content.into(),
+            file_ids: None,
+            attachments: None,
+            metadata: None,
+        }
+    }
+
+    /// Attaches a file to the message.
+    pub fn with_attachment(mut self, attachment: MessageAttachment) -> Self {
+        self.attachments
+            .get_or_insert_with(Vec::new)
+            .push(attachment);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_empty_thread() {
+        let json = serde_json::to_value(CreateThreadRequest::new()).unwrap();
+
+        assert_eq!(json, serde_json::json!({}));
+    }
+
+    #[test]
+    fn test_create_thread_with_messages() {
+        let request = CreateThreadRequest::new()
+            .with_message("user", "Hello")
+            .with_message("assistant", "Hi, how can I help?")
+            .with_metadata("session", "abc");
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "messages": [
+                    {"role": "user", "content": "Hello"},
+                    {"role": "assistant", "content": "Hi, how can I help?"},
+                ],
+                "metadata": {"session": "abc"},
+            })
+        );
+    }
+
+    #[test]
+    fn test_create_thread_with_attachments() {
+        let request = CreateThreadRequest::new()
+            .with_message_files(
+                "user",
+                "Analyze these",
+                ["file-a", "file-b"],
+                vec![AttachmentTool::CodeInterpreter, AttachmentTool::FileSearch],
+            )
+            .with_tool_resources(ToolResources::default().with_vector_stores(["vs_abc"]));
+        let json = serde_json::to_value(&request).unwrap();
+
+        let tools = serde_json::json!([{"type": "code_interpreter"}, {"type": "file_search"}]);
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "messages": [{
+                    "role": "user",
+                    "content": "Analyze these",
+                    "attachments": [
+                        {"file_id": "file-a", "tools": tools},
+                        {"file_id": "file-b", "tools": tools},
+                    ],
+                }],
+                "tool_resources": {"file_search": {"vector_store_ids": ["vs_abc"]}},
+            })
+        );
+    }
+}
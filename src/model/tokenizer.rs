@@ -0,0 +1,447 @@
+//! Prompt token estimation.
+//!
+//! Estimates how many tokens a chat request will use before it is sent, so
+//! that oversized prompts can be trimmed instead of failing with a
+//! context-length error.
+//!
+//! The estimates come from a heuristic calibrated against OpenAI's encodings,
+//! not from a byte-pair encoder, so they need no encoding data but are not
+//! exact counts.
+
+use super::{
+    ChatCompletionContentPart, ChatCompletionRequest, ChatCompletionRequestMessage,
+    ChatCompletionUserMessageContent,
+};
+use crate::error::{Error, Result};
+
+/// Tokens added to every message for the role and delimiters.
+const TOKENS_PER_MESSAGE: usize = 3;
+/// Extra token for a message that carries a `name`.
+const TOKENS_PER_NAME: usize = 1;
+/// Tokens that prime the assistant's reply.
+const TOKENS_PER_REPLY: usize = 3;
+/// Tokens counted for an image part, the cost of a low-detail image.
+const TOKENS_PER_IMAGE: usize = 85;
+
+/// A heuristic token estimate, calibrated for one of the encodings used by
+/// OpenAI models.
+///
+/// This does not run the byte-pair encoding itself: text is split roughly
+/// the way the encoding pre-tokenizes it, and each piece is charged by
+/// length. The result is close for English text and code, but can be off
+/// for other languages.
+///
+/// # Example
+///
+/// ```
+/// use portkey_sdk::model::TokenEstimate;
+///
+/// assert_eq!(TokenEstimate::for_model("gpt-4o-mini"), Some(TokenEstimate::O200kBase));
+/// assert_eq!(TokenEstimate::for_model("@openai/gpt-4-turbo"), Some(TokenEstimate::Cl100kBase));
+/// assert_eq!(TokenEstimate::for_model("claude-3-5-sonnet"), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenEstimate {
+    /// Calibrated for `o200k_base`, the encoding of GPT-4o, GPT-4.1, GPT-5
+    /// and the o-series models.
+    O200kBase,
+    /// Calibrated for `cl100k_base`, the encoding of GPT-4, GPT-3.5 and the
+    /// text-embedding models.
+    Cl100kBase,
+}
+
+impl TokenEstimate {
+    /// Returns the estimate for an OpenAI model, or `None` if its encoding is
+    /// unknown.
+    ///
+    /// A Portkey provider prefix such as `@openai/` is ignored.
+    pub fn for_model(model: &str) -> Option<Self> {
+        let model = model.rsplit('/').next().unwrap_or(model);
+        let is_o_series = model
+            .strip_prefix('o')
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()));
+
+        if is_o_series
+            || ["gpt-4o", "gpt-4.1", "gpt-4.5", "gpt-5", "chatgpt-4o"]
+                .iter()
+                .any(|prefix| model.starts_with(prefix))
+        {
+            Some(Self::O200kBase)
+        } else if [
+            "gpt-4",
+            "gpt-3.5",
+            "text-embedding-3",
+            "text-embedding-ada-002",
+        ]
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+        {
+            Some(Self::Cl100kBase)
+        } else {
+            None
+        }
+    }
+
+    /// Estimates the number of tokens `text` encodes to.
+    ///
+    /// The text is split the way the encodings pre-tokenize it: words with
+    /// their leading space, runs of up to three digits, punctuation and
+    /// whitespace. Each piece is then charged by length, which approximates
+    /// the merged token count for English text and code. The estimate is not
+    /// exact; leave headroom when comparing it to a context window.
+    pub fn estimate_tokens(self, text: &str) -> usize {
+        // Characters each additional token covers in a long word. Common
+        // words, with their leading space, are a single token.
+        let chars_per_token = match self {
+            Self::O200kBase => 7,
+            Self::Cl100kBase => 6,
+        };
+
+        let mut tokens = 0;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c.is_ascii_alphabetic()
+                || (c == ' ' && chars.peek().is_some_and(char::is_ascii_alphabetic))
+            {
+                let mut len: usize = 1;
+                while chars.next_if(char::is_ascii_alphabetic).is_some() {
+                    len += 1;
+                }
+                tokens += 1 + (len - 1) / chars_per_token;
+            } else if c.is_ascii_digit() {
+                let mut len: usize = 1;
+                while chars.next_if(char::is_ascii_digit).is_some() {
+                    len += 1;
+                }
+                tokens += len.div_ceil(3);
+            } else if c.is_whitespace() {
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                tokens += 1;
+            } else {
+                // Punctuation and non-ASCII characters, which rarely merge
+                // into longer tokens.
+                tokens += 1;
+            }
+        }
+        tokens
+    }
+}
+
+impl ChatCompletionRequest {
+    /// Estimates the number of prompt tokens this request will use.
+    ///
+    /// Counts the messages with [`TokenEstimate::estimate_tokens`] for the
+    /// encoding of [`model`](Self::model), plus the per-message overhead of the
+    /// chat format, the tool definitions and a fixed cost per image. Returns
+    /// [`Error::Validation`] if the model's encoding is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage};
+    ///
+    /// let request = ChatCompletionRequest::new(
+    ///     "gpt-4o",
+    ///     vec![ChatCompletionRequestMessage::user("Hello, world!")],
+    /// );
+    /// let tokens = request.estimated_prompt_tokens().unwrap();
+    /// assert!(tokens < 20);
+    /// ```
+    pub fn estimated_prompt_tokens(&self) -> Result<usize> {
+        let estimate = self.token_estimate()?;
+        let messages: usize = self
+            .messages
+            .iter()
+            .map(|message| estimate_message_tokens(estimate, message))
+            .sum();
+        Ok(self.fixed_prompt_tokens(estimate)? + messages)
+    }
+
+    /// Drops the oldest messages until the estimated prompt fits in
+    /// `max_tokens`, and returns the dropped messages in their original order.
+    ///
+    /// System and developer messages and the most recent user message are
+    /// always kept. An assistant message is dropped together with the tool
+    /// results that answer its tool calls, so the remaining history stays
+    /// valid. If the kept messages alone exceed the budget, the request is
+    /// left unchanged and [`Error::Validation`] is returned, as it is when the
+    /// model's encoding is unknown.
+    ///
+    /// The budget is compared against the heuristic
+    /// [estimate](Self::estimated_prompt_tokens), so keep it somewhat below
+    /// the model's context window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage};
+    ///
+    /// let mut request = ChatCompletionRequest::new(
+    ///     "gpt-4o",
+    ///     vec![
+    ///         ChatCompletionRequestMessage::system("You are a helpful assistant."),
+    ///         ChatCompletionRequestMessage::user("Tell me a long story."),
+    ///         ChatCompletionRequestMessage::assistant("Once upon a time ".repeat(100)),
+    ///         ChatCompletionRequestMessage::user("Now summarize it."),
+    ///     ],
+    /// );
+    /// let dropped = request.trim_to_budget(50).unwrap();
+    /// assert_eq!(dropped.len(), 2);
+    /// assert_eq!(request.messages.len(), 2);
+    /// ```
+    pub fn trim_to_budget(
+        &mut self,
+        max_tokens: usize,
+    ) -> Result<Vec<ChatCompletionRequestMessage>> {
+        let estimate = self.token_estimate()?;
+        let counts: Vec<usize> = self
+            .messages
+            .iter()
+            .map(|message| estimate_message_tokens(estimate, message))
+            .collect();
+        let mut tokens = self.fixed_prompt_tokens(estimate)? + counts.iter().sum::<usize>();
+
+        let last_user = self
+            .messages
+            .iter()
+            .rposition(|message| matches!(message, ChatCompletionRequestMessage::User { .. }));
+        let is_kept = |index: usize| {
+            Some(index) == last_user
+                || matches!(
+                    self.messages[index],
+                    ChatCompletionRequestMessage::System { .. }
+                        | ChatCompletionRequestMessage::Developer { .. }
+                )
+        };
+
+        let mut drop = vec![false; self.messages.len()];
+        let mut index = 0;
+        while tokens > max_tokens && index < self.messages.len() {
+            if is_kept(index) {
+                index += 1;
+                continue;
+            }
+
+            drop[index] = true;
+            tokens -= counts[index];
+            let has_tool_calls = matches!(
+                &self.messages[index],
+                ChatCompletionRequestMessage::Assistant { tool_calls: Some(calls), .. } if !calls.is_empty()
+            );
+            index += 1;
+            if has_tool_calls {
+                while index < self.messages.len()
+                    && !is_kept(index)
+                    && matches!(
+                        self.messages[index],
+                        ChatCompletionRequestMessage::Tool { .. }
+                    )
+                {
+                    drop[index] = true;
+                    tokens -= counts[index];
+                    index += 1;
+                }
+            }
+        }
+
+        if tokens > max_tokens {
+            return Err(Error::Validation(format!(
+                "prompt needs an estimated {tokens} tokens after trimming, over the budget of {max_tokens}"
+            )));
+        }
+
+        let mut dropped = Vec::new();
+        let mut kept = Vec::with_capacity(self.messages.len());
+        for (message, drop) in std::mem::take(&mut self.messages).into_iter().zip(drop) {
+            if drop {
+                dropped.push(message);
+            } else {
+                kept.push(message);
+            }
+        }
+        self.messages = kept;
+
+        Ok(dropped)
+    }
+
+    /// Returns the token estimate for the request's model.
+    fn token_estimate(&self) -> Result<TokenEstimate> {
+        TokenEstimate::for_model(&self.model).ok_or_else(|| {
+            Error::Validation(format!(
+                "cannot estimate tokens for model `{}`, its encoding is unknown",
+                self.model
+            ))
+        })
+    }
+
+    /// Estimates the prompt tokens that do not depend on the messages.
+    fn fixed_prompt_tokens(&self, estimate: TokenEstimate) -> Result<usize> {
+        let tools = match &self.tools {
+            Some(tools) => estimate.estimate_tokens(&serde_json::to_string(tools)?),
+            None => 0,
+        };
+        Ok(TOKENS_PER_REPLY + tools)
+    }
+}
+
+/// Estimates the tokens of a single message, including the chat format overhead.
+fn estimate_message_tokens(
+    estimate: TokenEstimate,
+    message: &ChatCompletionRequestMessage,
+) -> usize {
+    let count = |text: &str| estimate.estimate_tokens(text);
+
+    TOKENS_PER_MESSAGE
+        + match message {
+            ChatCompletionRequestMessage::System { content, name }
+            | ChatCompletionRequestMessage::Developer { content, name } => {
+                count(content) + name.as_ref().map_or(0, |_| TOKENS_PER_NAME)
+            }
+            ChatCompletionRequestMessage::User { content, name } => {
+                let content = match content {
+                    ChatCompletionUserMessageContent::Text(text) => count(text),
+                    ChatCompletionUserMessageContent::Parts(parts) => parts
+                        .iter()
+                        .map(|part| match part {
+                            ChatCompletionContentPart::Text { text } => count(text),
+                            ChatCompletionContentPart::ImageUrl { .. } => TOKENS_PER_IMAGE,
+                        })
+                        .sum(),
+                };
+                content + name.as_ref().map_or(0, |_| TOKENS_PER_NAME)
+            }
+            ChatCompletionRequestMessage::Assistant {
+                content,
+                name,
+                tool_calls,
+                function_call,
+            } => {
+                let tool_calls: usize = tool_calls
+                    .iter()
+                    .flatten()
+                    .map(|call| count(&call.function.name) + count(&call.function.arguments))
+                    .sum();
+                let function_call = function_call
+                    .as_ref()
+                    .map_or(0, |call| count(&call.name) + count(&call.arguments));
+                content.as_deref().map_or(0, count)
+                    + name.as_ref().map_or(0, |_| TOKENS_PER_NAME)
+                    + tool_calls
+                    + function_call
+            }
+            ChatCompletionRequestMessage::Tool { content, .. } => count(content),
+            ChatCompletionRequestMessage::Function { content, name } => {
+                count(content) + count(name)
+            }
+        }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_for_model() {
+        assert_eq!(
+            TokenEstimate::for_model("gpt-4o"),
+            Some(TokenEstimate::O200kBase)
+        );
+        assert_eq!(
+            TokenEstimate::for_model("o3-mini"),
+            Some(TokenEstimate::O200kBase)
+        );
+        assert_eq!(
+            TokenEstimate::for_model("gpt-3.5-turbo"),
+            Some(TokenEstimate::Cl100kBase)
+        );
+        assert_eq!(TokenEstimate::for_model("omni-moderation-latest"), None);
+    }
+
+    #[test]
+    fn test_estimate_tokens() {
+        let estimate = TokenEstimate::Cl100kBase;
+        assert_eq!(estimate.estimate_tokens(""), 0);
+        // "Hello", ",", " world", "!"
+        assert_eq!(estimate.estimate_tokens("Hello, world!"), 4);
+        // "123", "456", "7"
+        assert_eq!(estimate.estimate_tokens("1234567"), 3);
+        assert!(TokenEstimate::O200kBase.estimate_tokens("internationalization") < 5);
+    }
+
+    #[test]
+    fn test_estimated_prompt_tokens() {
+        let request = ChatCompletionRequest::new(
+            "gpt-4o",
+            vec![
+                ChatCompletionRequestMessage::system("Be brief."),
+                ChatCompletionRequestMessage::user("Hi"),
+            ],
+        );
+        // Reply priming, two message overheads, "Be", " brief", "." and "Hi".
+        assert_eq!(
+            request.estimated_prompt_tokens().unwrap(),
+            3 + 2 * 3 + 3 + 1
+        );
+
+        let request = ChatCompletionRequest::new("claude-3-5-sonnet", Vec::new());
+        assert!(matches!(
+            request.estimated_prompt_tokens(),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_trim_to_budget() {
+        use crate::model::{ChatCompletionMessageToolCall, FunctionCall};
+
+        let long = "lorem ipsum ".repeat(50);
+        let tool_call = ChatCompletionMessageToolCall {
+            id: "call_1".to_string(),
+            tool_type: "function".to_string(),
+            function: FunctionCall {
+                name: "search".to_string(),
+                arguments: "{}".to_string(),
+            },
+        };
+        let mut request = ChatCompletionRequest::new(
+            "gpt-4o",
+            vec![
+                ChatCompletionRequestMessage::system("Be brief."),
+                ChatCompletionRequestMessage::user(long.clone()),
+                ChatCompletionRequestMessage::assistant_tool_calls(vec![tool_call]),
+                ChatCompletionRequestMessage::tool("call_1", long.clone()),
+                ChatCompletionRequestMessage::assistant("Done."),
+                ChatCompletionRequestMessage::user("Thanks"),
+            ],
+        );
+
+        let budget = request.estimated_prompt_tokens().unwrap() - 1;
+        let dropped = request.trim_to_budget(budget).unwrap();
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(request.messages.len(), 5);
+
+        // Dropping the assistant tool call also drops its tool result.
+        let dropped = request.trim_to_budget(40).unwrap();
+        assert_eq!(dropped.len(), 2);
+        assert!(matches!(
+            dropped[1],
+            ChatCompletionRequestMessage::Tool { .. }
+        ));
+        assert!(request.estimated_prompt_tokens().unwrap() <= 40);
+        assert!(matches!(
+            request.messages[0],
+            ChatCompletionRequestMessage::System { .. }
+        ));
+        assert!(matches!(
+            request.messages.last(),
+            Some(ChatCompletionRequestMessage::User { .. })
+        ));
+
+        let before = request.messages.clone();
+        assert!(matches!(
+            request.trim_to_budget(5),
+            Err(Error::Validation(_))
+        ));
+        assert_eq!(request.messages, before);
+    }
+}
@@ -1,14 +1,29 @@
 //! Prelude module for convenient imports.
 //!
-//! The prelude re-exports the most commonly used types and traits from the Portkey SDK,
-//! allowing you to import everything you need with a single glob import.
+//! The prelude re-exports the most commonly used types from the Portkey SDK
+//! together with every service trait. Service methods such as
+//! `create_chat_completion` are defined on traits, so the trait must be in
+//! scope to call them; importing the prelude is the recommended way to make
+//! all of them available at once:
+//!
+//! ```no_run
+//! use portkey_sdk::prelude::*;
+//!
+//! # async fn example() -> Result<()> {
+//! let client = PortkeyClient::from_env()?;
+//! let request = ChatCompletionRequest::new(
+//!     "gpt-4o",
+//!     vec![ChatCompletionRequestMessage::user("Hello!")],
+//! );
+//! let response = client.create_chat_completion(request).await?;
+//! # Ok(())
+//! # }
+//! ```
 
-pub use crate::builder::{PortkeyBuilder, PortkeyBuilderError};
+pub use crate::builder::{AuthMethod, PortkeyBuilder, PortkeyBuilderError};
 pub use crate::model::*;
-pub use crate::service::{
-    AssistantsService, AudioService, BatchesService, ChatService, CompletionsService,
-    EmbeddingsService, FeedbackService, FilesService, FineTuningService, ImagesService,
-    LogsService, MessagesService, ModelsService, ModerationsService, PromptsService,
-    ResponsesService, RunsService, ThreadsService,
+pub use crate::service::*;
+pub use crate::{
+    ByteStream, Error, EventStream, GatewayConfig, Paginated, PortkeyClient, PortkeyConfig,
+    RequestOptions, Result,
 };
-pub use crate::{Error, PortkeyClient, PortkeyConfig, Result};
@@ -1,10 +1,12 @@
 use std::future::Future;
 
+use crate::client::ResponseExt;
 use crate::model::{
-    Assistant, AssistantFile, CreateAssistantFileRequest, CreateAssistantRequest,
-    DeleteAssistantFileResponse, DeleteAssistantResponse, ListAssistantFilesResponse,
-    ListAssistantsResponse, ModifyAssistantRequest, PaginationParams,
+    Assistant, AssistantFile, AssistantId, CreateAssistantFileRequest, CreateAssistantRequest,
+    DeleteAssistantFileResponse, DeleteAssistantResponse, FileId, ListAssistantFilesResponse,
+    ListAssistantsResponse, ModifyAssistantRequest, PaginationParams, UploadFileRequest,
 };
+use crate::service::FilesService;
 use crate::{PortkeyClient, Result};
 
 /// Service for managing assistants.
@@ -64,6 +66,27 @@ pub trait AssistantsService {
         params: PaginationParams,
     ) -> impl Future<Output = Result<ListAssistantsResponse>>;
 
+    /// Returns every assistant, following pagination, up to `max_items`.
+    ///
+    /// Stops fetching pages once `max_items` assistants are collected, so
+    /// the cap also bounds the number of requests.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::AssistantsService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let assistants = client.list_all_assistants(Some(500)).await?;
+    /// println!("Found {} assistants", assistants.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_all_assistants(
+        &self,
+        max_items: Option<usize>,
+    ) -> impl Future<Output = Result<Vec<Assistant>>>;
+
     /// Create an assistant file by attaching a File to an assistant.
     fn create_assistant_file(
         &self,
@@ -71,18 +94,46 @@ pub trait AssistantsService {
         request: CreateAssistantFileRequest,
     ) -> impl Future<Output = Result<AssistantFile>>;
 
+    /// Uploads a file and attaches it to an assistant in one call.
+    ///
+    /// The file is uploaded with the given `purpose` (usually `"assistants"`)
+    /// and then attached. If attaching fails, the uploaded file is deleted so
+    /// it is not left orphaned, and the attach error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::AssistantsService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let bytes = std::fs::read("handbook.pdf")?;
+    /// let file = client
+    ///     .create_assistant_file_from_bytes("asst_abc123", bytes, "handbook.pdf", "assistants")
+    ///     .await?;
+    /// println!("Attached {}", file.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_assistant_file_from_bytes(
+        &self,
+        assistant_id: &str,
+        bytes: Vec<u8>,
+        filename: &str,
+        purpose: &str,
+    ) -> impl Future<Output = Result<AssistantFile>>;
+
     /// Retrieves an AssistantFile.
     fn retrieve_assistant_file(
         &self,
-        assistant_id: &str,
-        file_id: &str,
+        assistant_id: impl Into<AssistantId>,
+        file_id: impl Into<FileId>,
     ) -> impl Future<Output = Result<AssistantFile>>;
 
     /// Delete an assistant file.
     fn delete_assistant_file(
         &self,
-        assistant_id: &str,
-        file_id: &str,
+        assistant_id: impl Into<AssistantId>,
+        file_id: impl Into<FileId>,
     ) -> impl Future<Output = Result<DeleteAssistantFileResponse>>;
 
     /// Returns a list of assistant files.
@@ -104,8 +155,8 @@ impl AssistantsService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::POST, "/assistants", &request)
             .await?;
-        let response = response.error_for_status()?;
-        let assistant: Assistant = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let assistant: Assistant = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -130,8 +181,8 @@ impl AssistantsService for PortkeyClient {
                 &format!("/assistants/{}", assistant_id),
             )
             .await?;
-        let response = response.error_for_status()?;
-        let assistant: Assistant = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let assistant: Assistant = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -161,8 +212,8 @@ impl AssistantsService for PortkeyClient {
                 &request,
             )
             .await?;
-        let response = response.error_for_status()?;
-        let assistant: Assistant = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let assistant: Assistant = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -187,7 +238,7 @@ impl AssistantsService for PortkeyClient {
                 &format!("/assistants/{}", assistant_id),
             )
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let delete_response: DeleteAssistantResponse = response.json().await?;
 
         #[cfg(feature = "tracing")]
@@ -209,15 +260,7 @@ impl AssistantsService for PortkeyClient {
             "Listing assistants"
         );
 
-        let query_params = params.to_query_params();
-        let query_params_refs: Vec<(&str, &str)> =
-            query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-
-        let response = self
-            .send_with_params(reqwest::Method::GET, "/assistants", &query_params_refs)
-            .await?;
-        let response = response.error_for_status()?;
-        let assistants: ListAssistantsResponse = response.json().await?;
+        let assistants: ListAssistantsResponse = self.get_paginated("/assistants", &params).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -228,6 +271,18 @@ impl AssistantsService for PortkeyClient {
         Ok(assistants)
     }
 
+    async fn list_all_assistants(&self, max_items: Option<usize>) -> Result<Vec<Assistant>> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            max_items = ?max_items,
+            "Listing all assistants"
+        );
+
+        self.get_all_pages::<ListAssistantsResponse>("/assistants", max_items)
+            .await
+    }
+
     async fn create_assistant_file(
         &self,
         assistant_id: &str,
@@ -247,7 +302,7 @@ impl AssistantsService for PortkeyClient {
                 &request,
             )
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let file: AssistantFile = response.json().await?;
 
         #[cfg(feature = "tracing")]
@@ -259,11 +314,50 @@ impl AssistantsService for PortkeyClient {
         Ok(file)
     }
 
-    async fn retrieve_assistant_file(
+    async fn create_assistant_file_from_bytes(
         &self,
         assistant_id: &str,
-        file_id: &str,
+        bytes: Vec<u8>,
+        filename: &str,
+        purpose: &str,
+    ) -> Result<AssistantFile> {
+        let uploaded = self
+            .upload_file(UploadFileRequest {
+                file: bytes,
+                filename: filename.to_string(),
+                purpose: purpose.to_string(),
+                expires_after: None,
+            })
+            .await?;
+
+        let request = CreateAssistantFileRequest {
+            file_id: uploaded.id.clone(),
+        };
+        match self.create_assistant_file(assistant_id, request).await {
+            Ok(file) => Ok(file),
+            Err(error) => {
+                #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+                if let Err(delete_error) = self.delete_file(&uploaded.id).await {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        target: crate::TRACING_TARGET_SERVICE,
+                        file_id = %uploaded.id,
+                        error = %delete_error,
+                        "Failed to delete uploaded file after attach failed"
+                    );
+                }
+                Err(error)
+            }
+        }
+    }
+
+    async fn retrieve_assistant_file(
+        &self,
+        assistant_id: impl Into<AssistantId>,
+        file_id: impl Into<FileId>,
     ) -> Result<AssistantFile> {
+        let assistant_id = assistant_id.into();
+        let file_id = file_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -278,7 +372,7 @@ impl AssistantsService for PortkeyClient {
                 &format!("/assistants/{}/files/{}", assistant_id, file_id),
             )
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let file: AssistantFile = response.json().await?;
 
         #[cfg(feature = "tracing")]
@@ -292,9 +386,11 @@ impl AssistantsService for PortkeyClient {
 
     async fn delete_assistant_file(
         &self,
-        assistant_id: &str,
-        file_id: &str,
+        assistant_id: impl Into<AssistantId>,
+        file_id: impl Into<FileId>,
     ) -> Result<DeleteAssistantFileResponse> {
+        let assistant_id = assistant_id.into();
+        let file_id = file_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -309,7 +405,7 @@ impl AssistantsService for PortkeyClient {
                 &format!("/assistants/{}/files/{}", assistant_id, file_id),
             )
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let delete_response: DeleteAssistantFileResponse = response.json().await?;
 
         #[cfg(feature = "tracing")]
@@ -333,19 +429,9 @@ impl AssistantsService for PortkeyClient {
             "Listing assistant files"
         );
 
-        let query_params = params.to_query_params();
-        let query_params_refs: Vec<(&str, &str)> =
-            query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-
-        let response = self
-            .send_with_params(
-                reqwest::Method::GET,
-                &format!("/assistants/{}/files", assistant_id),
-                &query_params_refs,
-            )
+        let files: ListAssistantFilesResponse = self
+            .get_paginated(&format!("/assistants/{}/files", assistant_id), &params)
             .await?;
-        let response = response.error_for_status()?;
-        let files: ListAssistantFilesResponse = response.json().await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -356,3 +442,101 @@ impl AssistantsService for PortkeyClient {
         Ok(files)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::RouteTransport;
+
+    const FILE_BODY: &str = r#"{"id":"file-abc","object":"file","bytes":5,"created_at":1,"filename":"notes.txt","purpose":"assistants"}"#;
+
+    #[tokio::test]
+    async fn test_create_assistant_file_from_bytes() {
+        let transport = RouteTransport::default()
+            .route("POST /files", 200, FILE_BODY)
+            .route(
+                "POST /assistants/asst_1/files",
+                200,
+                r#"{"id":"file-abc","object":"assistant.file","created_at":2,"assistant_id":"asst_1"}"#,
+            );
+        let requests = transport.requests();
+        let client = transport.client();
+
+        let file = client
+            .create_assistant_file_from_bytes(
+                "asst_1",
+                b"hello".to_vec(),
+                "notes.txt",
+                "assistants",
+            )
+            .await
+            .unwrap();
+        assert_eq!(file.id, "file-abc");
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["POST /files", "POST /assistants/asst_1/files"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_assistant_file_from_bytes_rolls_back() {
+        let transport = RouteTransport::default()
+            .route("POST /files", 200, FILE_BODY)
+            .route(
+                "POST /assistants/asst_1/files",
+                500,
+                r#"{"error":{"message":"internal"}}"#,
+            )
+            .route(
+                "DELETE /files/file-abc",
+                200,
+                r#"{"id":"file-abc","object":"file","deleted":true}"#,
+            );
+        let requests = transport.requests();
+        let client = transport.client();
+
+        let error = client
+            .create_assistant_file_from_bytes(
+                "asst_1",
+                b"hello".to_vec(),
+                "notes.txt",
+                "assistants",
+            )
+            .await
+            .unwrap_err();
+        assert!(!error.is_not_found());
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [
+                "POST /files",
+                "POST /assistants/asst_1/files",
+                "DELETE /files/file-abc"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_all_assistants_stops_at_cap() -> Result<()> {
+        let assistant = |id: &str| {
+            format!(
+                r#"{{"id":"{id}","object":"assistant","created_at":1,"model":"gpt-4o","tools":[],"metadata":{{}}}}"#
+            )
+        };
+        let page = format!(
+            r#"{{"object":"list","data":[{},{}],"first_id":"asst_1","last_id":"asst_2","has_more":true}}"#,
+            assistant("asst_1"),
+            assistant("asst_2"),
+        );
+        let transport = RouteTransport::default().route("GET /assistants", 200, page);
+        let requests = transport.requests();
+        let client = transport.client();
+
+        let assistants = client.list_all_assistants(Some(3)).await?;
+        assert_eq!(assistants.len(), 3);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+
+        assert!(client.list_all_assistants(Some(0)).await?.is_empty());
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        Ok(())
+    }
+}
@@ -2,16 +2,26 @@
 //!
 //! This module provides methods for audio transcription using Whisper and GPT models.
 
+use std::collections::VecDeque;
 use std::future::Future;
 
+use bytes::Bytes;
+use reqwest::Response;
 use reqwest::multipart::{Form, Part};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
-use crate::client::PortkeyClient;
-use crate::error::Result;
+use crate::client::{
+    ACCEPT_EVENT_STREAM, ByteSource, Endpoint, PortkeyClient, ResponseExt, spawn_bytes, spawn_sse,
+};
+use crate::error::{Error, Result};
 use crate::model::{
     CreateSpeechRequest, CreateTranscriptionRequest, CreateTranslationRequest,
-    TranscriptionResponse, TranslationResponse,
+    MAX_SPEECH_INPUT_CHARS, TranscriptionResponse, TranscriptionStreamEvent, TranslationResponse,
 };
+use crate::{ByteStream, EventStream, RequestOptions};
+
+/// A stream of transcription events.
+pub type TranscriptionStream = EventStream<TranscriptionStreamEvent>;
 
 /// Trait for Audio API operations.
 pub trait AudioService {
@@ -67,6 +77,45 @@ pub trait AudioService {
         request: CreateTranscriptionRequest,
     ) -> impl Future<Output = Result<TranscriptionResponse>>;
 
+    /// Creates a transcription of an audio file as a stream of partial transcripts.
+    ///
+    /// Sends `stream=true` with the form. Each [`TranscriptionStreamEvent::Delta`]
+    /// carries newly recognized text, and the final
+    /// [`TranscriptionStreamEvent::Done`] carries the full transcription.
+    /// Streaming is supported by the `gpt-4o-transcribe` family of models;
+    /// `whisper-1` rejects it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::AudioService;
+    /// # use portkey_sdk::model::{CreateTranscriptionRequest, TranscriptionStreamEvent};
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let audio_data = std::fs::read("meeting.mp3")?;
+    /// let request = CreateTranscriptionRequest {
+    ///     model: "gpt-4o-mini-transcribe".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut stream = client
+    ///     .create_transcription_stream(audio_data, "meeting.mp3", request)
+    ///     .await?;
+    /// while let Some(event) = stream.next().await {
+    ///     if let TranscriptionStreamEvent::Delta { delta } = event? {
+    ///         print!("{delta}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_transcription_stream(
+        &self,
+        file_data: Vec<u8>,
+        file_name: &str,
+        request: CreateTranscriptionRequest,
+    ) -> impl Future<Output = Result<TranscriptionStream>>;
+
     /// Creates speech audio from text input.
     ///
     /// # Arguments
@@ -108,6 +157,117 @@ pub trait AudioService {
     /// ```
     fn create_speech(&self, request: CreateSpeechRequest) -> impl Future<Output = Result<Vec<u8>>>;
 
+    /// Creates speech audio from text as a stream of chunks.
+    ///
+    /// Unlike [`create_speech`](Self::create_speech), the audio is not
+    /// buffered in memory, so playback can start before generation finishes.
+    /// Errors before the audio starts, such as invalid credentials, are
+    /// returned here; later transport errors are yielded by the stream.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::AudioService;
+    /// # use portkey_sdk::model::{CreateSpeechRequest, Voice};
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = CreateSpeechRequest {
+    ///     model: "tts-1".to_string(),
+    ///     input: "The quick brown fox jumped over the lazy dog.".to_string(),
+    ///     voice: Voice::Alloy,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut audio = client.create_speech_stream(request).await?;
+    /// while let Some(chunk) = audio.next().await {
+    ///     println!("Received {} bytes", chunk?.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_speech_stream(
+        &self,
+        request: CreateSpeechRequest,
+    ) -> impl Future<Output = Result<ByteStream>>;
+
+    /// Creates speech audio from text and writes it to `writer` as it arrives.
+    ///
+    /// Chunks are written as soon as they are received, and the writer is
+    /// flushed at the end. Returns the number of bytes written. On error,
+    /// the audio received so far has already been written.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::AudioService;
+    /// # use portkey_sdk::model::{CreateSpeechRequest, Voice};
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = CreateSpeechRequest {
+    ///     model: "tts-1".to_string(),
+    ///     input: "The quick brown fox jumped over the lazy dog.".to_string(),
+    ///     voice: Voice::Alloy,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut file = tokio::fs::File::create("speech.mp3").await?;
+    /// let written = client.create_speech_to_writer(request, &mut file).await?;
+    /// println!("Wrote {written} bytes");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_speech_to_writer<W>(
+        &self,
+        request: CreateSpeechRequest,
+        writer: &mut W,
+    ) -> impl Future<Output = Result<u64>>
+    where
+        W: AsyncWrite + Unpin;
+
+    /// Creates speech audio from text longer than the 4096-character input limit.
+    ///
+    /// The input is split on sentence boundaries into chunks of at most
+    /// [`MAX_SPEECH_INPUT_CHARS`] characters. Each chunk is synthesized in
+    /// order with the model, voice and options of `request`, and the audio is
+    /// streamed back as one continuous body. A sentence is only split if it is
+    /// longer than the limit on its own.
+    ///
+    /// Joining clips is only valid for formats where
+    /// [`SpeechResponseFormat::is_concatenable`](crate::model::SpeechResponseFormat::is_concatenable)
+    /// is `true` (MP3, the default, and PCM). Other formats are rejected with
+    /// [`Error::Validation`] before any request is sent.
+    ///
+    /// The first chunk is requested before this method returns, so errors such
+    /// as invalid credentials surface here. Errors for later chunks are
+    /// yielded by the stream and end it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result, save_while_streaming};
+    /// # use portkey_sdk::service::AudioService;
+    /// # use portkey_sdk::model::{CreateSpeechRequest, Voice};
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = CreateSpeechRequest {
+    ///     model: "tts-1".to_string(),
+    ///     input: std::fs::read_to_string("chapter-1.txt")?,
+    ///     voice: Voice::Fable,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let audio = client.create_speech_long(request).await?;
+    /// let mut stream = save_while_streaming(audio, "chapter-1.mp3");
+    /// while let Some(chunk) = stream.next().await {
+    ///     chunk?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_speech_long(
+        &self,
+        request: CreateSpeechRequest,
+    ) -> impl Future<Output = Result<ByteStream>>;
+
     /// Translates audio to English.
     ///
     /// # Arguments
@@ -168,65 +328,134 @@ impl AudioService for PortkeyClient {
         file_name: &str,
         request: CreateTranscriptionRequest,
     ) -> Result<TranscriptionResponse> {
-        // Build multipart form
-        let file_part = Part::bytes(file_data).file_name(file_name.to_string());
+        let form = transcription_form(file_data, file_name, request);
 
-        let mut form = Form::new()
-            .part("file", file_part)
-            .text("model", request.model.clone());
+        let response = self
+            .send_multipart(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::AudioTranscriptions),
+                form,
+            )
+            .await?;
 
-        if let Some(language) = request.language {
-            form = form.text("language", language);
-        }
+        let response = response.error_for_api_status().await?;
+        let transcription_response: TranscriptionResponse = response.json().await?;
+        Ok(transcription_response)
+    }
 
-        if let Some(prompt) = request.prompt {
-            form = form.text("prompt", prompt);
-        }
+    async fn create_transcription_stream(
+        &self,
+        file_data: Vec<u8>,
+        file_name: &str,
+        request: CreateTranscriptionRequest,
+    ) -> Result<TranscriptionStream> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            model = %request.model,
+            "Creating streaming transcription"
+        );
 
-        if let Some(response_format) = request.response_format {
-            let format_str = match response_format {
-                crate::model::TranscriptionResponseFormat::Json => "json",
-                crate::model::TranscriptionResponseFormat::Text => "text",
-                crate::model::TranscriptionResponseFormat::Srt => "srt",
-                crate::model::TranscriptionResponseFormat::VerboseJson => "verbose_json",
-                crate::model::TranscriptionResponseFormat::Vtt => "vtt",
-            };
-            form = form.text("response_format", format_str);
-        }
+        let form = transcription_form(file_data, file_name, request).text("stream", "true");
+        let options = RequestOptions::default()
+            .with_accept(ACCEPT_EVENT_STREAM)
+            .with_streaming();
+        let response = self
+            .send_multipart_with_options(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::AudioTranscriptions),
+                form,
+                &options,
+            )
+            .await?;
+        let response = response.error_for_api_status().await?;
 
-        if let Some(temperature) = request.temperature {
-            form = form.text("temperature", temperature.to_string());
-        }
+        Ok(spawn_sse(response, options.stream_buffer()))
+    }
 
-        if let Some(granularities) = request.timestamp_granularities {
-            for granularity in granularities {
-                let granularity_str = match granularity {
-                    crate::model::TimestampGranularity::Word => "word",
-                    crate::model::TimestampGranularity::Segment => "segment",
-                };
-                form = form.text("timestamp_granularities[]", granularity_str);
-            }
+    async fn create_speech_long(&self, request: CreateSpeechRequest) -> Result<ByteStream> {
+        let format = request.response_format.unwrap_or_default();
+        if !format.is_concatenable() {
+            return Err(Error::Validation(format!(
+                "speech in {format:?} format cannot be concatenated, use MP3 or PCM"
+            )));
         }
 
-        let response = self
-            .send_multipart(reqwest::Method::POST, "/audio/transcriptions", form)
-            .await?;
+        let mut inputs: VecDeque<_> =
+            split_speech_input(&request.input, MAX_SPEECH_INPUT_CHARS).into();
 
-        let response = response.error_for_status()?;
-        let transcription_response: TranscriptionResponse = response.json().await?;
-        Ok(transcription_response)
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            model = %request.model,
+            chunks = inputs.len(),
+            "Creating long speech"
+        );
+
+        let mut source = SpeechChunks {
+            client: self.clone(),
+            template: request,
+            current: None,
+            inputs: VecDeque::new(),
+        };
+        if let Some(input) = inputs.pop_front() {
+            source.current = Some(source.synthesize(input).await?);
+        }
+        source.inputs = inputs;
+
+        Ok(spawn_bytes(
+            source,
+            RequestOptions::default().stream_buffer(),
+        ))
     }
 
     async fn create_speech(&self, request: CreateSpeechRequest) -> Result<Vec<u8>> {
-        let response = self
-            .send_json(reqwest::Method::POST, "/audio/speech", &request)
-            .await?;
-
-        let response = response.error_for_status()?;
+        let response = self.speech_response(&request, false).await?;
         let audio_bytes = response.bytes().await?;
         Ok(audio_bytes.to_vec())
     }
 
+    async fn create_speech_stream(&self, request: CreateSpeechRequest) -> Result<ByteStream> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            model = %request.model,
+            "Streaming speech"
+        );
+
+        let response = self.speech_response(&request, true).await?;
+        Ok(spawn_bytes(
+            response,
+            RequestOptions::default().stream_buffer(),
+        ))
+    }
+
+    async fn create_speech_to_writer<W>(
+        &self,
+        request: CreateSpeechRequest,
+        writer: &mut W,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut response = self.speech_response(&request, true).await?;
+        let mut written = 0;
+        while let Some(chunk) = response.chunk().await? {
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            written,
+            "Speech written successfully"
+        );
+
+        Ok(written)
+    }
+
     async fn create_translation(
         &self,
         file_data: Vec<u8>,
@@ -260,15 +489,237 @@ impl AudioService for PortkeyClient {
         }
 
         let response = self
-            .send_multipart(reqwest::Method::POST, "/audio/translations", form)
+            .send_multipart(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::AudioTranslations),
+                form,
+            )
             .await?;
 
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let translation_response: TranslationResponse = response.json().await?;
         Ok(translation_response)
     }
 }
 
+/// Builds the multipart form for a transcription request.
+fn transcription_form(
+    file_data: Vec<u8>,
+    file_name: &str,
+    request: CreateTranscriptionRequest,
+) -> Form {
+    let file_part = Part::bytes(file_data).file_name(file_name.to_string());
+
+    let mut form = Form::new()
+        .part("file", file_part)
+        .text("model", request.model.clone());
+
+    if let Some(language) = request.language {
+        form = form.text("language", language);
+    }
+
+    if let Some(prompt) = request.prompt {
+        form = form.text("prompt", prompt);
+    }
+
+    if let Some(response_format) = request.response_format {
+        let format_str = match response_format {
+            crate::model::TranscriptionResponseFormat::Json => "json",
+            crate::model::TranscriptionResponseFormat::Text => "text",
+            crate::model::TranscriptionResponseFormat::Srt => "srt",
+            crate::model::TranscriptionResponseFormat::VerboseJson => "verbose_json",
+            crate::model::TranscriptionResponseFormat::Vtt => "vtt",
+        };
+        form = form.text("response_format", format_str);
+    }
+
+    if let Some(temperature) = request.temperature {
+        form = form.text("temperature", temperature.to_string());
+    }
+
+    if let Some(granularities) = request.timestamp_granularities {
+        for granularity in granularities {
+            let granularity_str = match granularity {
+                crate::model::TimestampGranularity::Word => "word",
+                crate::model::TimestampGranularity::Segment => "segment",
+            };
+            form = form.text("timestamp_granularities[]", granularity_str);
+        }
+    }
+
+    form
+}
+
+impl PortkeyClient {
+    /// Requests speech audio, failing on a non-success status.
+    ///
+    /// With `streaming`, the timeout only bounds the wait for the response to
+    /// start, as the audio is read while it is still being generated.
+    async fn speech_response(
+        &self,
+        request: &CreateSpeechRequest,
+        streaming: bool,
+    ) -> Result<Response> {
+        let mut options = speech_options(request);
+        if streaming {
+            options = options.with_streaming();
+        }
+        self.send_json_with_options(
+            reqwest::Method::POST,
+            self.endpoint_path(Endpoint::AudioSpeech),
+            request,
+            &options,
+        )
+        .await?
+        .error_for_api_status()
+        .await
+    }
+}
+
+/// Returns options accepting audio in the requested speech format.
+fn speech_options(request: &CreateSpeechRequest) -> RequestOptions {
+    let format = request.response_format.unwrap_or_default();
+    RequestOptions::default().with_accept(format.mime_type())
+}
+
+/// Synthesizes speech chunk by chunk, yielding the audio of each in order.
+struct SpeechChunks {
+    client: PortkeyClient,
+    template: CreateSpeechRequest,
+    current: Option<Response>,
+    inputs: VecDeque<String>,
+}
+
+impl SpeechChunks {
+    /// Requests the audio for one chunk of input.
+    async fn synthesize(&self, input: String) -> Result<Response> {
+        let request = CreateSpeechRequest {
+            input,
+            ..self.template.clone()
+        };
+        self.client.speech_response(&request, true).await
+    }
+}
+
+impl ByteSource for SpeechChunks {
+    type Chunk = Bytes;
+
+    async fn next_chunk(&mut self) -> Result<Option<Bytes>> {
+        loop {
+            if let Some(response) = self.current.as_mut()
+                && let Some(chunk) = response.chunk().await?
+            {
+                return Ok(Some(chunk));
+            }
+
+            let Some(input) = self.inputs.pop_front() else {
+                return Ok(None);
+            };
+            self.current = Some(self.synthesize(input).await?);
+        }
+    }
+}
+
+/// Splits text into chunks of at most `max_chars` characters.
+///
+/// Chunks end on sentence boundaries where possible. A sentence longer than
+/// `max_chars` is split on whitespace, and a single word longer than
+/// `max_chars` is split between characters.
+fn split_speech_input(text: &str, max_chars: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_chars = 0;
+
+    for sentence in sentences(text) {
+        // Trailing whitespace is trimmed from chunks, so it does not count
+        // towards the limit.
+        let sentence_chars = sentence.trim_end().chars().count();
+        if current_chars > 0 && current_chars + sentence_chars > max_chars {
+            chunks.push(std::mem::take(&mut current));
+            current_chars = 0;
+        }
+
+        if sentence_chars <= max_chars {
+            current.push_str(sentence);
+            current_chars += sentence.chars().count();
+            continue;
+        }
+
+        for word in sentence.split_inclusive(char::is_whitespace) {
+            for piece in split_chars(word, max_chars) {
+                if current_chars > 0 && current_chars + piece.trim_end().chars().count() > max_chars
+                {
+                    chunks.push(std::mem::take(&mut current));
+                    current_chars = 0;
+                }
+                current.push_str(piece);
+                current_chars += piece.chars().count();
+            }
+        }
+    }
+    chunks.push(current);
+
+    chunks
+        .into_iter()
+        .map(|chunk| chunk.trim().to_string())
+        .filter(|chunk| !chunk.is_empty())
+        .collect()
+}
+
+/// Splits text after sentence-ending punctuation followed by whitespace, and
+/// after line breaks. Trailing whitespace stays with the sentence.
+fn sentences(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut chars = rest.char_indices().peekable();
+        let mut end = rest.len();
+        while let Some((index, c)) = chars.next() {
+            let boundary = match c {
+                '\n' => true,
+                '.' | '!' | '?' | '…' => {
+                    chars.peek().is_some_and(|(_, next)| next.is_whitespace())
+                }
+                _ => false,
+            };
+            if boundary {
+                end = index + c.len_utf8();
+                while let Some((index, next)) = chars.peek()
+                    && next.is_whitespace()
+                {
+                    end = index + next.len_utf8();
+                    chars.next();
+                }
+                break;
+            }
+        }
+
+        let (sentence, remaining) = rest.split_at(end);
+        rest = remaining;
+        Some(sentence)
+    })
+}
+
+/// Splits a string into pieces of at most `max_chars` characters.
+fn split_chars(text: &str, max_chars: usize) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let end = rest
+            .char_indices()
+            .nth(max_chars)
+            .map_or(rest.len(), |(index, _)| index);
+        let (piece, remaining) = rest.split_at(end);
+        rest = remaining;
+        Some(piece)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -284,4 +735,94 @@ mod tests {
         assert_eq!(request.model, "whisper-1");
         assert_eq!(request.language, Some("en".to_string()));
     }
+
+    #[test]
+    fn test_split_speech_input_on_sentences() {
+        let text = "First sentence. Second one! Third?\nFourth line";
+        assert_eq!(
+            split_speech_input(text, 30),
+            ["First sentence. Second one!", "Third?\nFourth line"]
+        );
+        assert_eq!(split_speech_input(text, 1000), [text]);
+        assert_eq!(
+            split_speech_input("Version 1.5 is out.", 1000),
+            ["Version 1.5 is out."]
+        );
+        assert!(split_speech_input("  \n ", 10).is_empty());
+    }
+
+    #[test]
+    fn test_split_speech_input_long_sentence() {
+        let chunks = split_speech_input("one two three four five", 10);
+        assert_eq!(chunks, ["one two", "three four", "five"]);
+
+        let chunks = split_speech_input("ééééé", 2);
+        assert_eq!(chunks, ["éé", "éé", "é"]);
+
+        let text = "Lorem ipsum dolor sit amet. ".repeat(500);
+        let chunks = split_speech_input(&text, MAX_SPEECH_INPUT_CHARS);
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert!(chunk.chars().count() <= MAX_SPEECH_INPUT_CHARS);
+            assert!(chunk.ends_with('.'));
+        }
+        assert_eq!(chunks.join(" "), text.trim());
+    }
+
+    #[tokio::test]
+    async fn test_create_speech_stream() -> Result<()> {
+        use crate::client::MockTransport;
+
+        let request = CreateSpeechRequest {
+            model: "tts-1".to_string(),
+            input: "Hello.".to_string(),
+            ..Default::default()
+        };
+
+        let client = MockTransport::client(200, "ID3audio");
+        let audio = client.create_speech_stream(request.clone()).await?;
+        assert_eq!(crate::collect_all(audio, None).await?.concat(), b"ID3audio");
+
+        let mut file = Vec::new();
+        let written = client
+            .create_speech_to_writer(request.clone(), &mut file)
+            .await?;
+        assert_eq!(written, 8);
+        assert_eq!(file, b"ID3audio");
+
+        let client = MockTransport::client(401, r#"{"error":{"message":"bad key"}}"#);
+        assert!(client.create_speech_stream(request.clone()).await.is_err());
+        let mut file = Vec::new();
+        assert!(
+            client
+                .create_speech_to_writer(request, &mut file)
+                .await
+                .is_err()
+        );
+        assert!(file.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_speech_long_rejects_container_formats() -> Result<()> {
+        use crate::builder::AuthMethod;
+        use crate::model::SpeechResponseFormat;
+
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk"))
+            .build_client()?;
+        let request = CreateSpeechRequest {
+            model: "tts-1".to_string(),
+            input: "Hello.".to_string(),
+            response_format: Some(SpeechResponseFormat::Wav),
+            ..Default::default()
+        };
+
+        let result = client.create_speech_long(request).await;
+        assert!(matches!(result, Err(Error::Validation(_))));
+
+        Ok(())
+    }
 }
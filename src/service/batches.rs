@@ -1,7 +1,12 @@
 use std::future::Future;
 
-use crate::model::{Batch, CreateBatchRequest, ListBatchesResponse, PaginationParams};
-use crate::{PortkeyClient, Result};
+use crate::client::{NdjsonDecoder, ResponseExt};
+use crate::model::{
+    Batch, BatchEndpoint, BatchResultLine, CreateBatchRequest, ListBatchesResponse,
+    PaginationParams,
+};
+use crate::service::FilesService;
+use crate::{Error, EventStream, PortkeyClient, Result};
 
 /// Service for managing batch processing jobs.
 ///
@@ -10,7 +15,7 @@ use crate::{PortkeyClient, Result};
 /// ```no_run
 /// use portkey_sdk::{PortkeyConfig, PortkeyClient, Result};
 /// use portkey_sdk::service::BatchesService;
-/// use portkey_sdk::model::CreateBatchRequest;
+/// use portkey_sdk::model::{BatchEndpoint, CompletionWindow, CreateBatchRequest};
 ///
 /// # async fn example() -> Result<()> {
 /// let config = PortkeyConfig::builder()
@@ -21,8 +26,8 @@ use crate::{PortkeyClient, Result};
 /// let batch = client.create_batch(
 ///     CreateBatchRequest {
 ///         input_file_id: "file-abc123".to_string(),
-///         endpoint: "/v1/chat/completions".to_string(),
-///         completion_window: "24h".to_string(),
+///         endpoint: BatchEndpoint::ChatCompletions,
+///         completion_window: CompletionWindow::TwentyFourHours,
 ///         metadata: None,
 ///     }
 /// ).await?;
@@ -39,13 +44,13 @@ pub trait BatchesService {
     /// ```no_run
     /// # use portkey_sdk::{PortkeyClient, Result};
     /// # use portkey_sdk::service::BatchesService;
-    /// # use portkey_sdk::model::CreateBatchRequest;
+    /// # use portkey_sdk::model::{BatchEndpoint, CompletionWindow, CreateBatchRequest};
     /// # async fn example(client: PortkeyClient) -> Result<()> {
     /// let batch = client.create_batch(
     ///     CreateBatchRequest {
     ///         input_file_id: "file-abc123".to_string(),
-    ///         endpoint: "/v1/chat/completions".to_string(),
-    ///         completion_window: "24h".to_string(),
+    ///         endpoint: BatchEndpoint::ChatCompletions,
+    ///         completion_window: CompletionWindow::TwentyFourHours,
     ///         metadata: None,
     ///     }
     /// ).await?;
@@ -54,6 +59,31 @@ pub trait BatchesService {
     /// ```
     fn create_batch(&self, request: CreateBatchRequest) -> impl Future<Output = Result<Batch>>;
 
+    /// Creates a batch after checking the input file against the request.
+    ///
+    /// Downloads the input file and verifies that it was uploaded with the
+    /// `batch` purpose and that every request line targets the request's
+    /// endpoint. Returns [`Error::Validation`] without submitting the batch
+    /// if either check fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::BatchesService;
+    /// # use portkey_sdk::model::{BatchEndpoint, CreateBatchRequest};
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let batch = client.create_batch_checked(
+    ///     CreateBatchRequest::new("file-abc123", BatchEndpoint::Embeddings)
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_batch_checked(
+        &self,
+        request: CreateBatchRequest,
+    ) -> impl Future<Output = Result<Batch>>;
+
     /// Retrieves a batch.
     ///
     /// # Arguments
@@ -73,6 +103,73 @@ pub trait BatchesService {
     /// ```
     fn retrieve_batch(&self, batch_id: &str) -> impl Future<Output = Result<Batch>>;
 
+    /// Retrieves the results of a completed batch.
+    ///
+    /// Retrieves the batch, downloads its output file and parses every line.
+    /// Returns [`Error::Validation`] if the batch has not completed yet, and an
+    /// empty list if the batch produced no output file.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch_id` - The ID of the batch.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::BatchesService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// for line in client.retrieve_batch_results("batch_abc123").await? {
+    ///     if let Some(response) = line.response {
+    ///         println!("{}: {}", line.custom_id, response.body);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_batch_results(
+        &self,
+        batch_id: &str,
+    ) -> impl Future<Output = Result<Vec<BatchResultLine>>>;
+
+    /// Streams the results of a completed batch line by line.
+    ///
+    /// Like [`retrieve_batch_results`](Self::retrieve_batch_results), but
+    /// parses the output file while it downloads instead of buffering it, which
+    /// keeps memory use flat for large batches. The stream is empty if the
+    /// batch produced no output file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::BatchesService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let mut results = client.stream_batch_results("batch_abc123").await?;
+    /// while let Some(line) = results.next().await {
+    ///     println!("{}", line?.custom_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn stream_batch_results(
+        &self,
+        batch_id: &str,
+    ) -> impl Future<Output = Result<EventStream<BatchResultLine>>>;
+
+    /// Retrieves the failed requests of a completed batch.
+    ///
+    /// Like [`retrieve_batch_results`](Self::retrieve_batch_results), but reads
+    /// the batch's error file. Returns an empty list if no request failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch_id` - The ID of the batch.
+    fn retrieve_batch_errors(
+        &self,
+        batch_id: &str,
+    ) -> impl Future<Output = Result<Vec<BatchResultLine>>>;
+
     /// Cancels an in-progress batch.
     ///
     /// # Arguments
@@ -105,7 +202,7 @@ pub trait BatchesService {
     /// # use portkey_sdk::service::BatchesService;
     /// # use portkey_sdk::model::PaginationParams;
     /// # async fn example(client: &impl BatchesService) -> portkey_sdk::Result<()> {
-    /// let params = PaginationParams { limit: Some(10), order: None, after: None, before: None };
+    /// let params = PaginationParams::new().with_limit(10);
     /// let batches = client.list_batches(params).await?;
     /// for batch in batches.data {
     ///     println!("Batch {}: {}", batch.id, batch.status);
@@ -130,8 +227,8 @@ impl BatchesService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::POST, "/batches", &request)
             .await?;
-        let response = response.error_for_status()?;
-        let batch: Batch = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let batch: Batch = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -142,6 +239,29 @@ impl BatchesService for PortkeyClient {
         Ok(batch)
     }
 
+    async fn create_batch_checked(&self, request: CreateBatchRequest) -> Result<Batch> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            input_file_id = %request.input_file_id,
+            endpoint = %request.endpoint,
+            "Checking batch input file"
+        );
+
+        let file = self.retrieve_file(&request.input_file_id).await?;
+        if file.purpose != "batch" {
+            return Err(Error::Validation(format!(
+                "input file {} has purpose `{}`, expected `batch`",
+                file.id, file.purpose
+            )));
+        }
+
+        let content = self.retrieve_file_content(&request.input_file_id).await?;
+        check_batch_input(request.endpoint, &content)?;
+
+        self.create_batch(request).await
+    }
+
     async fn retrieve_batch(&self, batch_id: &str) -> Result<Batch> {
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -153,8 +273,8 @@ impl BatchesService for PortkeyClient {
         let response = self
             .send(reqwest::Method::GET, &format!("/batches/{}", batch_id))
             .await?;
-        let response = response.error_for_status()?;
-        let batch: Batch = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let batch: Batch = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -165,6 +285,33 @@ impl BatchesService for PortkeyClient {
         Ok(batch)
     }
 
+    async fn retrieve_batch_results(&self, batch_id: &str) -> Result<Vec<BatchResultLine>> {
+        let batch = self.retrieve_batch(batch_id).await?;
+        ensure_batch_completed(&batch)?;
+        match batch.output_file_id {
+            Some(file_id) => parse_batch_results(&self.retrieve_file_content(&file_id).await?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn stream_batch_results(&self, batch_id: &str) -> Result<EventStream<BatchResultLine>> {
+        let batch = self.retrieve_batch(batch_id).await?;
+        ensure_batch_completed(&batch)?;
+        match batch.output_file_id {
+            Some(file_id) => self.retrieve_file_lines(&file_id).await,
+            None => Ok(EventStream::empty()),
+        }
+    }
+
+    async fn retrieve_batch_errors(&self, batch_id: &str) -> Result<Vec<BatchResultLine>> {
+        let batch = self.retrieve_batch(batch_id).await?;
+        ensure_batch_completed(&batch)?;
+        match batch.error_file_id {
+            Some(file_id) => parse_batch_results(&self.retrieve_file_content(&file_id).await?),
+            None => Ok(Vec::new()),
+        }
+    }
+
     async fn cancel_batch(&self, batch_id: &str) -> Result<Batch> {
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -180,8 +327,8 @@ impl BatchesService for PortkeyClient {
                 &serde_json::json!({}),
             )
             .await?;
-        let response = response.error_for_status()?;
-        let batch: Batch = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let batch: Batch = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -199,15 +346,7 @@ impl BatchesService for PortkeyClient {
             "Listing batches"
         );
 
-        let query_params = params.to_query_params();
-        let query_params_refs: Vec<(&str, &str)> =
-            query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-
-        let response = self
-            .send_with_params(reqwest::Method::GET, "/batches", &query_params_refs)
-            .await?;
-        let response = response.error_for_status()?;
-        let batches: ListBatchesResponse = response.json().await?;
+        let batches: ListBatchesResponse = self.get_paginated("/batches", &params).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -218,3 +357,125 @@ impl BatchesService for PortkeyClient {
         Ok(batches)
     }
 }
+
+/// Checks that every JSONL line of a batch input file targets `endpoint`.
+fn check_batch_input(endpoint: BatchEndpoint, content: &[u8]) -> Result<()> {
+    let content = std::str::from_utf8(content)
+        .map_err(|e| Error::Validation(format!("batch input file is not valid UTF-8: {e}")))?;
+
+    for (index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let value: serde_json::Value = serde_json::from_str(line).map_err(|e| {
+            Error::Validation(format!(
+                "line {} of batch input is not valid JSON: {e}",
+                index + 1
+            ))
+        })?;
+        let url = value.get("url").and_then(|url| url.as_str());
+        if url != Some(endpoint.as_str()) {
+            return Err(Error::Validation(format!(
+                "line {} of batch input targets {}, expected {endpoint}",
+                index + 1,
+                url.unwrap_or("no url"),
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns [`Error::Validation`] unless the batch has completed.
+fn ensure_batch_completed(batch: &Batch) -> Result<()> {
+    if batch.status == "completed" {
+        Ok(())
+    } else {
+        Err(Error::Validation(format!(
+            "batch {} is {}, results are only available once it has completed",
+            batch.id, batch.status
+        )))
+    }
+}
+
+/// Parses the JSONL content of a batch output or error file.
+fn parse_batch_results(content: &[u8]) -> Result<Vec<BatchResultLine>> {
+    let mut decoder = NdjsonDecoder::default();
+    let lines = decoder.feed(content);
+    lines
+        .into_iter()
+        .chain(decoder.finish())
+        .map(|line| serde_json::from_slice(&line).map_err(Into::into))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_batch_input_matching() {
+        let content = concat!(
+            r#"{"custom_id":"1","method":"POST","url":"/v1/embeddings","body":{}}"#,
+            "\n\n",
+            r#"{"custom_id":"2","method":"POST","url":"/v1/embeddings","body":{}}"#,
+            "\n",
+        );
+        assert!(check_batch_input(BatchEndpoint::Embeddings, content.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn test_check_batch_input_mismatch() {
+        let content = concat!(
+            r#"{"custom_id":"1","method":"POST","url":"/v1/chat/completions","body":{}}"#,
+            "\n",
+            r#"{"custom_id":"2","method":"POST","url":"/v1/embeddings","body":{}}"#,
+        );
+        let err =
+            check_batch_input(BatchEndpoint::ChatCompletions, content.as_bytes()).unwrap_err();
+        assert!(matches!(err, Error::Validation(ref msg) if msg.starts_with("line 2")));
+    }
+    #[test]
+    fn test_parse_batch_results() -> Result<()> {
+        let content = concat!(
+            r#"{"id":"batch_req_1","custom_id":"a","response":{"status_code":200,"request_id":"req_1","body":{"ok":true}},"error":null}"#,
+            "\n\n",
+            r#"{"id":"batch_req_2","custom_id":"b","response":null,"error":{"code":"timeout","message":"Request timed out"}}"#,
+            "\n",
+        );
+        let lines = parse_batch_results(content.as_bytes())?;
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].is_success());
+        assert_eq!(lines[0].response.as_ref().unwrap().body["ok"], true);
+        assert!(!lines[1].is_success());
+        assert_eq!(
+            lines[1].error.as_ref().unwrap().message,
+            "Request timed out"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ensure_batch_completed() {
+        let mut batch: Batch = serde_json::from_value(serde_json::json!({
+            "id": "batch_abc123",
+            "object": "batch",
+            "endpoint": "/v1/chat/completions",
+            "input_file_id": "file-abc123",
+            "completion_window": "24h",
+            "status": "in_progress",
+            "created_at": 1711471533
+        }))
+        .unwrap();
+        assert!(matches!(
+            ensure_batch_completed(&batch),
+            Err(Error::Validation(_))
+        ));
+
+        batch.status = "completed".to_string();
+        assert!(ensure_batch_completed(&batch).is_ok());
+    }
+}
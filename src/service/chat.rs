@@ -1,9 +1,249 @@
+use std::collections::BTreeMap;
 use std::future::Future;
 
 #[cfg(feature = "tracing")]
 use crate::TRACING_TARGET_SERVICE;
-use crate::model::{ChatCompletionRequest, ChatCompletionResponse};
-use crate::{PortkeyClient, Result};
+use crate::client::{ACCEPT_EVENT_STREAM, Endpoint, ResponseExt, spawn_sse};
+use crate::model::{
+    ChatCompletionChoice, ChatCompletionChunk, ChatCompletionMessageToolCall,
+    ChatCompletionRequest, ChatCompletionRequestMessage, ChatCompletionResponse,
+    ChatCompletionResponseMessage, ChatCompletionStreamMetadata, FunctionCall, LenientResponse,
+    ListChatCompletionsResponse, Logprobs, PaginationParams, PortkeyResponse,
+};
+use crate::{Error, EventStream, PortkeyClient, RequestOptions, Result};
+
+/// A stream of chat completion chunks.
+pub type ChatCompletionStream = EventStream<ChatCompletionChunk>;
+
+impl ChatCompletionStream {
+    /// Waits for the next chunk and returns the response metadata it carries.
+    ///
+    /// Every chunk carries the same id, model and creation time, so this can
+    /// be called before reading any content, for example to log the response
+    /// id. The chunk is not consumed and is still returned by
+    /// [`next`](EventStream::next). Returns `None` if the stream has ended or
+    /// the next item is an error, which `next` then yields.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage};
+    /// # use portkey_sdk::service::ChatService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = ChatCompletionRequest::new(
+    ///     "gpt-4o",
+    ///     vec![ChatCompletionRequestMessage::user("Hello!")],
+    /// );
+    /// let mut stream = client.create_chat_completion_stream(request).await?;
+    /// if let Some(metadata) = stream.metadata().await {
+    ///     println!("Streaming response {}", metadata.id);
+    /// }
+    /// let text = stream.collect_text().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn metadata(&mut self) -> Option<ChatCompletionStreamMetadata> {
+        match self.peek().await? {
+            Ok(chunk) => Some(chunk.metadata()),
+            Err(_) => None,
+        }
+    }
+
+    /// Drives the stream to completion and returns the text of the first choice.
+    ///
+    /// Returns the first error yielded by the stream, if any.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage};
+    /// # use portkey_sdk::service::ChatService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = ChatCompletionRequest::new(
+    ///     "gpt-4o",
+    ///     vec![ChatCompletionRequestMessage::user("Hello!")],
+    /// );
+    /// let text = client.create_chat_completion_stream(request).await?.collect_text().await?;
+    /// println!("{text}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn collect_text(mut self) -> Result<String> {
+        let mut text = String::new();
+        while let Some(chunk) = self.next().await {
+            for choice in chunk?.choices {
+                if choice.index == 0
+                    && let Some(content) = choice.delta.content
+                {
+                    text.push_str(&content);
+                }
+            }
+        }
+        Ok(text)
+    }
+
+    /// Drives the stream to completion and assembles a [`ChatCompletionResponse`].
+    ///
+    /// Deltas are merged per choice index. Returns the first error yielded by
+    /// the stream, or [`Error::Stream`] if the stream ends without any chunks.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage};
+    /// # use portkey_sdk::service::ChatService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = ChatCompletionRequest::new(
+    ///     "gpt-4o",
+    ///     vec![ChatCompletionRequestMessage::user("Hello!")],
+    /// );
+    /// let stream = client.create_chat_completion_stream(request).await?;
+    /// let response = stream.collect_response().await?;
+    /// println!("{:?}", response.choices[0].message.content);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn collect_response(mut self) -> Result<ChatCompletionResponse> {
+        let mut accumulator = ChunkAccumulator::default();
+        while let Some(chunk) = self.next().await {
+            accumulator.push(chunk?);
+        }
+        accumulator
+            .finish()
+            .ok_or_else(|| Error::Stream("stream ended before any chunk was received".into()))
+    }
+}
+
+/// Merges streamed chunks into a complete response.
+#[derive(Debug, Default)]
+struct ChunkAccumulator {
+    response: Option<ChatCompletionResponse>,
+    /// Tool calls keyed by choice index and tool call index.
+    tool_calls: BTreeMap<(i32, i32), ChatCompletionMessageToolCall>,
+}
+
+impl ChunkAccumulator {
+    fn push(&mut self, chunk: ChatCompletionChunk) {
+        let response = self.response.get_or_insert_with(|| ChatCompletionResponse {
+            id: chunk.id.clone(),
+            object: "chat.completion".to_string(),
+            created: chunk.created,
+            model: chunk.model.clone(),
+            provider: chunk.provider.clone(),
+            choices: Vec::new(),
+            usage: None,
+            system_fingerprint: None,
+        });
+
+        if chunk.usage.is_some() {
+            response.usage = chunk.usage;
+        }
+        if chunk.system_fingerprint.is_some() {
+            response.system_fingerprint = chunk.system_fingerprint;
+        }
+
+        for delta in chunk.choices {
+            let position = match response.choices.iter().position(|c| c.index == delta.index) {
+                Some(position) => position,
+                None => {
+                    response.choices.push(ChatCompletionChoice {
+                        finish_reason: String::new(),
+                        index: delta.index,
+                        message: ChatCompletionResponseMessage {
+                            role: "assistant".to_string(),
+                            content: None,
+                            tool_calls: None,
+                            function_call: None,
+                            content_blocks: None,
+                            refusal: None,
+                        },
+                        logprobs: None,
+                    });
+                    response.choices.len() - 1
+                }
+            };
+            let choice = &mut response.choices[position];
+
+            if let Some(role) = delta.delta.role {
+                choice.message.role = role;
+            }
+            if let Some(content) = delta.delta.content {
+                choice
+                    .message
+                    .content
+                    .get_or_insert_with(String::new)
+                    .push_str(&content);
+            }
+            if let Some(refusal) = delta.delta.refusal {
+                choice
+                    .message
+                    .refusal
+                    .get_or_insert_with(String::new)
+                    .push_str(&refusal);
+            }
+            for fragment in delta.delta.tool_calls.into_iter().flatten() {
+                let tool_call = self
+                    .tool_calls
+                    .entry((delta.index, fragment.index))
+                    .or_insert_with(|| ChatCompletionMessageToolCall {
+                        id: String::new(),
+                        tool_type: "function".to_string(),
+                        function: FunctionCall {
+                            name: String::new(),
+                            arguments: String::new(),
+                        },
+                    });
+                if let Some(id) = fragment.id {
+                    tool_call.id = id;
+                }
+                if let Some(tool_type) = fragment.tool_type {
+                    tool_call.tool_type = tool_type;
+                }
+                if let Some(name) = fragment.function.name
+                    && !name.is_empty()
+                {
+                    tool_call.function.name = name;
+                }
+                if let Some(arguments) = fragment.function.arguments {
+                    tool_call.function.arguments.push_str(&arguments);
+                }
+            }
+            if let Some(finish_reason) = delta.finish_reason {
+                choice.finish_reason = finish_reason;
+            }
+            if let Some(tokens) = delta.logprobs.and_then(|logprobs| logprobs.content) {
+                choice
+                    .logprobs
+                    .get_or_insert(Logprobs { content: None })
+                    .content
+                    .get_or_insert_with(Vec::new)
+                    .extend(tokens);
+            }
+        }
+    }
+
+    fn finish(self) -> Option<ChatCompletionResponse> {
+        let mut response = self.response?;
+        for ((choice_index, _), tool_call) in self.tool_calls {
+            if let Some(choice) = response
+                .choices
+                .iter_mut()
+                .find(|choice| choice.index == choice_index)
+            {
+                choice
+                    .message
+                    .tool_calls
+                    .get_or_insert_with(Vec::new)
+                    .push(tool_call);
+            }
+        }
+        response.choices.sort_by_key(|choice| choice.index);
+        Some(response)
+    }
+}
 
 /// Trait for chat completion operations.
 ///
@@ -23,6 +263,9 @@ pub trait ChatService {
     /// # Returns
     ///
     /// Returns the chat completion response with the model's generated message(s).
+    /// Requests with `stream` set fail with [`Error::Validation`]; use
+    /// [`create_chat_completion_stream`](Self::create_chat_completion_stream)
+    /// to stream.
     ///
     /// # Authentication Options
     ///
@@ -77,6 +320,8 @@ pub trait ChatService {
     ///     tools: None,
     ///     tool_choice: None,
     ///     parallel_tool_calls: None,
+    ///     prediction: None,
+    ///     store: None,
     ///     user: None,
     /// };
     ///
@@ -89,6 +334,263 @@ pub trait ChatService {
         &self,
         request: ChatCompletionRequest,
     ) -> impl Future<Output = Result<ChatCompletionResponse>>;
+
+    /// Creates a chat completion and returns it with the metadata from the
+    /// response headers, such as the trace ID and the remaining rate limit.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage};
+    /// # use portkey_sdk::service::ChatService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request =
+    ///     ChatCompletionRequest::new("gpt-4o", vec![ChatCompletionRequestMessage::user("Hello!")]);
+    /// let response = client.create_chat_completion_with_meta(request).await?;
+    ///
+    /// println!("trace: {:?}", response.meta.trace_id);
+    /// if response.meta.ratelimit_remaining == Some(0) {
+    ///     println!("rate limited for {:?}", response.meta.ratelimit_reset);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_chat_completion_with_meta(
+        &self,
+        request: ChatCompletionRequest,
+    ) -> impl Future<Output = Result<PortkeyResponse<ChatCompletionResponse>>>;
+
+    /// Creates a chat completion, falling back to the raw JSON body if it
+    /// does not match [`ChatCompletionResponse`].
+    ///
+    /// This is a stopgap for when the API adds or changes response fields
+    /// before the SDK catches up: instead of failing with a deserialization
+    /// error, the response is returned as [`LenientResponse::Raw`] together
+    /// with the parse error. Prefer [`create_chat_completion`](Self::create_chat_completion)
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the body is not JSON.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::{
+    /// #     ChatCompletionRequest, ChatCompletionRequestMessage, LenientResponse,
+    /// # };
+    /// # use portkey_sdk::service::ChatService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = ChatCompletionRequest::new(
+    ///     "gpt-4o",
+    ///     vec![ChatCompletionRequestMessage::user("Hello!")],
+    /// );
+    ///
+    /// match client.create_chat_completion_lenient(request).await? {
+    ///     LenientResponse::Typed(response) => println!("{:?}", response.first_message_text()),
+    ///     LenientResponse::Raw { value, error } => {
+    ///         eprintln!("unexpected response shape ({error}): {value}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_chat_completion_lenient(
+        &self,
+        request: ChatCompletionRequest,
+    ) -> impl Future<Output = Result<LenientResponse<ChatCompletionResponse>>>;
+
+    /// Creates a chat completion with per-request options.
+    ///
+    /// The options take precedence over the client configuration for this
+    /// request only, so one client can send requests with different trace
+    /// IDs, cache namespaces, metadata and timeouts.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use portkey_sdk::{PortkeyClient, RequestOptions, Result};
+    /// # use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage};
+    /// # use portkey_sdk::service::ChatService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = ChatCompletionRequest::new(
+    ///     "gpt-4o",
+    ///     vec![ChatCompletionRequestMessage::user("Summarize this ticket.")],
+    /// );
+    /// let options = RequestOptions::new()
+    ///     .with_trace_id("ticket-4821")
+    ///     .with_cache_namespace("tenant-acme")
+    ///     .with_metadata("_user", "user-123")
+    ///     .with_timeout(Duration::from_secs(90));
+    ///
+    /// let response = client
+    ///     .create_chat_completion_with_options(request, options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_chat_completion_with_options(
+        &self,
+        request: ChatCompletionRequest,
+        options: RequestOptions,
+    ) -> impl Future<Output = Result<ChatCompletionResponse>>;
+
+    /// Creates a streaming chat completion.
+    ///
+    /// Sets `stream` on the request and returns the response as a stream of
+    /// [`ChatCompletionChunk`]s. The stream uses the default [`RequestOptions`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage};
+    /// # use portkey_sdk::service::ChatService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = ChatCompletionRequest::new(
+    ///     "gpt-4o",
+    ///     vec![ChatCompletionRequestMessage::user("Tell me a story.")],
+    /// );
+    ///
+    /// let mut stream = client.create_chat_completion_stream(request).await?;
+    /// while let Some(chunk) = stream.next().await {
+    ///     if let Some(content) = &chunk?.choices[0].delta.content {
+    ///         print!("{content}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_chat_completion_stream(
+        &self,
+        request: ChatCompletionRequest,
+    ) -> impl Future<Output = Result<ChatCompletionStream>>;
+
+    /// Creates a streaming chat completion with per-request options.
+    ///
+    /// [`RequestOptions::with_stream_buffer`] bounds how many chunks are read
+    /// ahead of the consumer. A consumer that stalls stops the SDK from
+    /// reading further from the connection.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, RequestOptions, Result};
+    /// # use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage};
+    /// # use portkey_sdk::service::ChatService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = ChatCompletionRequest::new(
+    ///     "gpt-4o",
+    ///     vec![ChatCompletionRequestMessage::user("Tell me a story.")],
+    /// );
+    /// let options = RequestOptions::default().with_stream_buffer(4);
+    ///
+    /// let mut stream = client
+    ///     .create_chat_completion_stream_with_options(request, options)
+    ///     .await?;
+    /// while let Some(chunk) = stream.next().await {
+    ///     println!("{:?}", chunk?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_chat_completion_stream_with_options(
+        &self,
+        request: ChatCompletionRequest,
+        options: RequestOptions,
+    ) -> impl Future<Output = Result<ChatCompletionStream>>;
+
+    /// Creates a chat completion from a model and messages, with every other
+    /// request field left at its default.
+    ///
+    /// Shorthand for [`create_chat_completion`](Self::create_chat_completion)
+    /// with [`ChatCompletionRequest::new`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::ChatCompletionRequestMessage;
+    /// # use portkey_sdk::service::ChatService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let response = client
+    ///     .chat("gpt-4o", vec![ChatCompletionRequestMessage::user("Hello!")])
+    ///     .await?;
+    /// println!("{:?}", response.first_message_text());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn chat(
+        &self,
+        model: impl Into<String>,
+        messages: Vec<ChatCompletionRequestMessage>,
+    ) -> impl Future<Output = Result<ChatCompletionResponse>>;
+
+    /// Sends a single user prompt and returns the text of the answer.
+    ///
+    /// Returns [`Error::UnexpectedResponse`] if the model refused or the
+    /// response has no text content.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::ChatService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let answer = client.chat_text("gpt-4o", "What is the capital of France?").await?;
+    /// println!("{answer}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn chat_text(
+        &self,
+        model: impl Into<String>,
+        user_prompt: impl Into<String>,
+    ) -> impl Future<Output = Result<String>>;
+
+    /// Retrieves a chat completion that was created with
+    /// [`store`](ChatCompletionRequest::store) enabled.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::{ChatCompletionRequest, ChatCompletionRequestMessage};
+    /// # use portkey_sdk::service::ChatService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request =
+    ///     ChatCompletionRequest::new("gpt-4o", vec![ChatCompletionRequestMessage::user("Hello!")])
+    ///         .with_store(true);
+    /// let created = client.create_chat_completion(request).await?;
+    ///
+    /// let stored = client.retrieve_chat_completion(&created.id).await?;
+    /// println!("{:?}", stored.first_message_text());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_chat_completion(
+        &self,
+        completion_id: &str,
+    ) -> impl Future<Output = Result<ChatCompletionResponse>>;
+
+    /// Returns a page of stored chat completions.
+    fn list_chat_completions(
+        &self,
+        params: PaginationParams,
+    ) -> impl Future<Output = Result<ListChatCompletionsResponse>>;
+}
+
+/// Rejects a request that asks for a stream from a non-streaming method.
+fn ensure_not_streaming(request: &ChatCompletionRequest) -> Result<()> {
+    if request.stream == Some(true) {
+        return Err(Error::Validation(
+            "request has `stream` set, use `create_chat_completion_stream` instead".to_string(),
+        ));
+    }
+    Ok(())
 }
 
 impl ChatService for PortkeyClient {
@@ -96,6 +598,196 @@ impl ChatService for PortkeyClient {
         &self,
         request: ChatCompletionRequest,
     ) -> Result<ChatCompletionResponse> {
+        self.create_chat_completion_with_options(request, RequestOptions::default())
+            .await
+    }
+
+    async fn create_chat_completion_with_options(
+        &self,
+        request: ChatCompletionRequest,
+        options: RequestOptions,
+    ) -> Result<ChatCompletionResponse> {
+        self.complete_chat(request, &options)
+            .await
+            .map(PortkeyResponse::into_body)
+    }
+
+    async fn create_chat_completion_with_meta(
+        &self,
+        request: ChatCompletionRequest,
+    ) -> Result<PortkeyResponse<ChatCompletionResponse>> {
+        self.complete_chat(request, &RequestOptions::default())
+            .await
+    }
+
+    async fn create_chat_completion_lenient(
+        &self,
+        mut request: ChatCompletionRequest,
+    ) -> Result<LenientResponse<ChatCompletionResponse>> {
+        ensure_not_streaming(&request)?;
+        self.resolve_model(&mut request.model)?;
+        self.apply_generation_defaults(&mut request.max_tokens, &mut request.temperature);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_SERVICE,
+            model = %request.model,
+            messages_count = request.messages.len(),
+            "Creating lenient chat completion"
+        );
+
+        let response = self
+            .send_json(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::ChatCompletions),
+                &request,
+            )
+            .await?;
+        let response = response.error_for_api_status().await?;
+        let value: serde_json::Value = response.json().await?;
+        let response = LenientResponse::from_value(value);
+
+        #[cfg(feature = "tracing")]
+        if let LenientResponse::Raw { error, .. } = &response {
+            tracing::warn!(
+                target: TRACING_TARGET_SERVICE,
+                error = %error,
+                "Chat completion did not match the expected type"
+            );
+        }
+
+        Ok(response)
+    }
+
+    async fn create_chat_completion_stream(
+        &self,
+        request: ChatCompletionRequest,
+    ) -> Result<ChatCompletionStream> {
+        self.create_chat_completion_stream_with_options(request, RequestOptions::default())
+            .await
+    }
+
+    async fn create_chat_completion_stream_with_options(
+        &self,
+        mut request: ChatCompletionRequest,
+        options: RequestOptions,
+    ) -> Result<ChatCompletionStream> {
+        self.resolve_model(&mut request.model)?;
+        self.apply_generation_defaults(&mut request.max_tokens, &mut request.temperature);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_SERVICE,
+            model = %request.model,
+            messages_count = request.messages.len(),
+            stream_buffer = options.stream_buffer(),
+            "Creating streaming chat completion"
+        );
+
+        request.stream = Some(true);
+        let options = options.with_accept(ACCEPT_EVENT_STREAM).with_streaming();
+        let response = self
+            .send_json_with_options(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::ChatCompletions),
+                &request,
+                &options,
+            )
+            .await?;
+        let response = response.error_for_api_status().await?;
+
+        Ok(spawn_sse(response, options.stream_buffer()))
+    }
+
+    async fn chat(
+        &self,
+        model: impl Into<String>,
+        messages: Vec<ChatCompletionRequestMessage>,
+    ) -> Result<ChatCompletionResponse> {
+        self.create_chat_completion(ChatCompletionRequest::new(model, messages))
+            .await
+    }
+
+    async fn chat_text(
+        &self,
+        model: impl Into<String>,
+        user_prompt: impl Into<String>,
+    ) -> Result<String> {
+        let response = self
+            .chat(model, vec![ChatCompletionRequestMessage::user(user_prompt)])
+            .await?;
+
+        if let Some(text) = response.first_message_text() {
+            return Ok(text.to_string());
+        }
+        Err(Error::UnexpectedResponse(match response.refusal() {
+            Some(refusal) => format!("model refused to answer: {refusal}"),
+            None => format!("chat completion {} has no text content", response.id),
+        }))
+    }
+
+    async fn retrieve_chat_completion(
+        &self,
+        completion_id: &str,
+    ) -> Result<ChatCompletionResponse> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_SERVICE,
+            completion_id = %completion_id,
+            "Retrieving chat completion"
+        );
+
+        let path = format!(
+            "{}/{}",
+            self.endpoint_path(Endpoint::ChatCompletions),
+            completion_id
+        );
+        let response = self.send(reqwest::Method::GET, &path).await?;
+        let response = response.error_for_api_status().await?;
+        let chat_response: ChatCompletionResponse = self.json_object(response).await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_SERVICE,
+            "Chat completion retrieved successfully"
+        );
+
+        Ok(chat_response)
+    }
+
+    async fn list_chat_completions(
+        &self,
+        params: PaginationParams<'_>,
+    ) -> Result<ListChatCompletionsResponse> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(target: TRACING_TARGET_SERVICE, "Listing chat completions");
+
+        let completions: ListChatCompletionsResponse = self
+            .get_paginated(self.endpoint_path(Endpoint::ChatCompletions), &params)
+            .await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_SERVICE,
+            count = completions.data.len(),
+            "Chat completions retrieved successfully"
+        );
+
+        Ok(completions)
+    }
+}
+
+impl PortkeyClient {
+    /// Creates a chat completion, returning it with the response metadata.
+    async fn complete_chat(
+        &self,
+        mut request: ChatCompletionRequest,
+        options: &RequestOptions,
+    ) -> Result<PortkeyResponse<ChatCompletionResponse>> {
+        ensure_not_streaming(&request)?;
+        self.resolve_model(&mut request.model)?;
+        self.apply_generation_defaults(&mut request.max_tokens, &mut request.temperature);
+
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: TRACING_TARGET_SERVICE,
@@ -105,10 +797,16 @@ impl ChatService for PortkeyClient {
         );
 
         let response = self
-            .send_json(reqwest::Method::POST, "/chat/completions", &request)
+            .send_json_with_options(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::ChatCompletions),
+                &request,
+                options,
+            )
             .await?;
-        let response = response.error_for_status()?;
-        let chat_response: ChatCompletionResponse = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let meta = response.meta();
+        let chat_response: ChatCompletionResponse = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -118,6 +816,472 @@ impl ChatService for PortkeyClient {
             "Chat completion created successfully"
         );
 
-        Ok(chat_response)
+        Ok(PortkeyResponse {
+            body: chat_response,
+            meta,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(json: serde_json::Value) -> Result<ChatCompletionChunk> {
+        Ok(serde_json::from_value(json).unwrap())
+    }
+
+    fn frames() -> Vec<Result<ChatCompletionChunk>> {
+        vec![
+            chunk(serde_json::json!({
+                "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+                "choices": [{"index": 0, "delta": {"role": "assistant", "content": "Hel"}, "finish_reason": null}]
+            })),
+            chunk(serde_json::json!({
+                "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+                "choices": [{"index": 0, "delta": {"content": "lo!"}, "finish_reason": "stop"}]
+            })),
+            chunk(serde_json::json!({
+                "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+                "choices": [],
+                "usage": {"prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7}
+            })),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_metadata_does_not_consume_chunk() {
+        let mut stream = ChatCompletionStream::from_items(frames());
+
+        let metadata = stream.metadata().await.unwrap();
+        assert_eq!(metadata.id, "chatcmpl-1");
+        assert_eq!(metadata.model, "gpt-4o");
+        assert_eq!(metadata.created, 1);
+
+        assert_eq!(stream.collect_text().await.unwrap(), "Hello!");
+    }
+
+    #[tokio::test]
+    async fn test_metadata_on_error() {
+        let mut stream =
+            ChatCompletionStream::from_items(vec![Err(Error::Stream("broken".into()))]);
+
+        assert!(stream.metadata().await.is_none());
+        assert!(matches!(stream.next().await, Some(Err(Error::Stream(_)))));
+        assert!(stream.metadata().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_collect_text() {
+        let stream = ChatCompletionStream::from_items(frames());
+        assert_eq!(stream.collect_text().await.unwrap(), "Hello!");
+    }
+
+    #[tokio::test]
+    async fn test_collect_response() {
+        let stream = ChatCompletionStream::from_items(frames());
+        let response = stream.collect_response().await.unwrap();
+
+        assert_eq!(response.id, "chatcmpl-1");
+        assert_eq!(response.object, "chat.completion");
+        assert_eq!(response.choices.len(), 1);
+        assert_eq!(response.choices[0].message.role, "assistant");
+        assert_eq!(
+            response.choices[0].message.content.as_deref(),
+            Some("Hello!")
+        );
+        assert_eq!(response.choices[0].finish_reason, "stop");
+        assert_eq!(response.usage.unwrap().total_tokens, 7);
+    }
+
+    #[tokio::test]
+    async fn test_collect_response_refusal() {
+        let stream = ChatCompletionStream::from_items(vec![
+            chunk(serde_json::json!({
+                "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+                "choices": [{"index": 0, "delta": {"role": "assistant", "refusal": "I can't "}, "finish_reason": null}]
+            })),
+            chunk(serde_json::json!({
+                "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+                "choices": [{"index": 0, "delta": {"refusal": "help with that."}, "finish_reason": "stop"}]
+            })),
+        ]);
+        let response = stream.collect_response().await.unwrap();
+
+        assert_eq!(response.refusal(), Some("I can't help with that."));
+        assert_eq!(response.first_message_text(), None);
+    }
+
+    #[tokio::test]
+    async fn test_chat_text() {
+        use crate::client::MockTransport;
+
+        let client = MockTransport::client(
+            200,
+            r#"{"id":"chatcmpl-1","object":"chat.completion","created":1,"model":"gpt-4o","choices":[{"index":0,"finish_reason":"stop","message":{"role":"assistant","content":"Paris"}}]}"#,
+        );
+        assert_eq!(
+            client
+                .chat_text("gpt-4o", "Capital of France?")
+                .await
+                .unwrap(),
+            "Paris"
+        );
+
+        let client = MockTransport::client(
+            200,
+            r#"{"id":"chatcmpl-1","object":"chat.completion","created":1,"model":"gpt-4o","choices":[{"index":0,"finish_reason":"stop","message":{"role":"assistant","content":null,"refusal":"I can't help with that."}}]}"#,
+        );
+        assert!(matches!(
+            client.chat_text("gpt-4o", "Something harmful").await,
+            Err(Error::UnexpectedResponse(message)) if message.contains("can't help")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_effective_provider_and_model() {
+        use crate::client::MockTransport;
+
+        // A fallback config answered a gpt-4o request with another target.
+        let client = MockTransport::client(
+            200,
+            r#"{"id":"chatcmpl-1","object":"chat.completion","created":1,"model":"claude-3-5-sonnet-20241022","provider":"anthropic","choices":[]}"#,
+        );
+        let request =
+            ChatCompletionRequest::new("gpt-4o", vec![ChatCompletionRequestMessage::user("Hi")]);
+        let response = client
+            .create_chat_completion_with_meta(request)
+            .await
+            .unwrap();
+
+        assert_eq!(response.body.model, "claude-3-5-sonnet-20241022");
+        assert_eq!(response.effective_provider(), Some("anthropic"));
+    }
+
+    #[tokio::test]
+    async fn test_create_chat_completion_lenient() {
+        use crate::client::MockTransport;
+
+        let request =
+            || ChatCompletionRequest::new("gpt-4o", vec![ChatCompletionRequestMessage::user("Hi")]);
+
+        let client = MockTransport::client(
+            200,
+            r#"{"id":"chatcmpl-1","object":"chat.completion","created":1,"model":"gpt-4o","choices":[]}"#,
+        );
+        let response = client
+            .create_chat_completion_lenient(request())
+            .await
+            .unwrap();
+        assert_eq!(response.typed().unwrap().id, "chatcmpl-1");
+
+        let client = MockTransport::client(200, r#"{"id":"chatcmpl-1","choices":"new-shape"}"#);
+        let response = client
+            .create_chat_completion_lenient(request())
+            .await
+            .unwrap();
+        let LenientResponse::Raw { value, .. } = response else {
+            panic!("expected a raw response");
+        };
+        assert_eq!(value["choices"], "new-shape");
+
+        let client = MockTransport::client(200, "not json");
+        assert!(matches!(
+            client.create_chat_completion_lenient(request()).await,
+            Err(Error::Http(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_collect_response_tool_calls() {
+        let tool_chunk = |tool_calls: serde_json::Value, finish_reason: serde_json::Value| {
+            chunk(serde_json::json!({
+                "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+                "choices": [{"index": 0, "delta": {"tool_calls": tool_calls}, "finish_reason": finish_reason}]
+            }))
+        };
+        let stream = ChatCompletionStream::from_items(vec![
+            chunk(serde_json::json!({
+                "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+                "choices": [{"index": 0, "delta": {"role": "assistant", "content": null}, "finish_reason": null}]
+            })),
+            tool_chunk(
+                serde_json::json!([{"index": 0, "id": "call_a", "type": "function", "function": {"name": "get_weather", "arguments": ""}}]),
+                serde_json::Value::Null,
+            ),
+            tool_chunk(
+                serde_json::json!([{"index": 0, "function": {"arguments": "{\"city\":"}}]),
+                serde_json::Value::Null,
+            ),
+            tool_chunk(
+                serde_json::json!([{"index": 1, "id": "call_b", "type": "function", "function": {"name": "get_time", "arguments": "{}"}}]),
+                serde_json::Value::Null,
+            ),
+            tool_chunk(
+                serde_json::json!([{"index": 0, "function": {"arguments": "\"Paris\"}"}}]),
+                serde_json::json!("tool_calls"),
+            ),
+        ]);
+        let response = stream.collect_response().await.unwrap();
+
+        let choice = &response.choices[0];
+        assert_eq!(choice.finish_reason, "tool_calls");
+        assert_eq!(choice.message.content, None);
+        let tool_calls = choice.message.tool_calls.as_ref().unwrap();
+        assert_eq!(tool_calls.len(), 2);
+        assert_eq!(tool_calls[0].id, "call_a");
+        assert_eq!(tool_calls[0].tool_type, "function");
+        assert_eq!(tool_calls[0].function.name, "get_weather");
+        assert_eq!(tool_calls[0].function.arguments, r#"{"city":"Paris"}"#);
+        assert_eq!(tool_calls[1].id, "call_b");
+        assert_eq!(tool_calls[1].function.name, "get_time");
+        assert_eq!(tool_calls[1].function.arguments, "{}");
+    }
+
+    #[tokio::test]
+    async fn test_collect_propagates_first_error() {
+        let mut items = frames();
+        items.insert(1, Err(Error::Stream("connection reset".into())));
+        let stream = ChatCompletionStream::from_items(items);
+
+        let err = stream.collect_text().await.unwrap_err();
+        assert!(matches!(err, Error::Stream(ref msg) if msg == "connection reset"));
+    }
+
+    #[tokio::test]
+    async fn test_collect_response_empty_stream() {
+        let stream = ChatCompletionStream::from_items(Vec::new());
+        assert!(matches!(
+            stream.collect_response().await,
+            Err(Error::Stream(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stored_chat_completions() -> Result<()> {
+        use crate::client::RouteTransport;
+
+        let completion = r#"{"id":"chatcmpl-1","object":"chat.completion","created":1,"model":"gpt-4o","choices":[{"index":0,"finish_reason":"stop","message":{"role":"assistant","content":"Hello!"}}]}"#;
+        let transport = RouteTransport::default()
+            .route("GET /chat/completions/chatcmpl-1", 200, completion)
+            .route(
+                "GET /chat/completions",
+                200,
+                format!(
+                    r#"{{"object":"list","data":[{completion}],"first_id":"chatcmpl-1","last_id":"chatcmpl-1","has_more":false}}"#
+                ),
+            );
+        let requests = transport.requests();
+        let client = transport.client();
+
+        let stored = client.retrieve_chat_completion("chatcmpl-1").await?;
+        assert_eq!(stored.first_message_text(), Some("Hello!"));
+
+        let page = client
+            .list_chat_completions(PaginationParams::new().with_limit(10))
+            .await?;
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.data[0].id, "chatcmpl-1");
+        assert!(!page.has_more);
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /chat/completions/chatcmpl-1", "GET /chat/completions"]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_chat_completion_rejects_stream() {
+        use crate::client::RouteTransport;
+
+        let transport = RouteTransport::default();
+        let requests = transport.requests();
+        let client = transport.client();
+        let mut request =
+            ChatCompletionRequest::new("gpt-4o", vec![ChatCompletionRequestMessage::user("Hi")]);
+        request.stream = Some(true);
+
+        assert!(matches!(
+            client.create_chat_completion(request.clone()).await,
+            Err(Error::Validation(message)) if message.contains("create_chat_completion_stream")
+        ));
+        assert!(matches!(
+            client.create_chat_completion_lenient(request).await,
+            Err(Error::Validation(_))
+        ));
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stream_multibyte_split_across_chunks() {
+        use bytes::Bytes;
+
+        use crate::client::{ByteStream, spawn_sse};
+
+        let frame = "data: {\"id\":\"c\",\"object\":\"chat.completion.chunk\",\"created\":1,\"model\":\"gpt-4o\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"héllo 👋\"},\"finish_reason\":null}]}\n\ndata: [DONE]\n\n";
+        let split = frame.find('👋').unwrap() + 2;
+        let (head, tail) = frame.as_bytes().split_at(split);
+        let source = ByteStream::from_items(vec![
+            Ok(Bytes::copy_from_slice(head)),
+            Ok(Bytes::copy_from_slice(tail)),
+        ]);
+
+        let stream: ChatCompletionStream = spawn_sse(source, 4);
+        assert_eq!(stream.collect_text().await.unwrap(), "héllo 👋");
+    }
+
+    #[tokio::test]
+    async fn test_create_chat_completion_with_meta() -> Result<()> {
+        use crate::builder::{AuthMethod, HttpTransport, TransportFuture};
+
+        #[derive(Debug)]
+        struct HeaderTransport;
+
+        impl HttpTransport for HeaderTransport {
+            fn execute(&self, _: reqwest::Request) -> TransportFuture<'_> {
+                let response = http::Response::builder()
+                    .header("x-portkey-trace-id", "trace-abc")
+                    .header("x-ratelimit-remaining-requests", "0")
+                    .header("x-ratelimit-reset-requests", "1.5")
+                    .body(r#"{"id":"chatcmpl-1","object":"chat.completion","created":1,"model":"gpt-4o","choices":[]}"#)
+                    .unwrap();
+                Box::pin(async { Ok(response.into()) })
+            }
+        }
+
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_transport(HeaderTransport)
+            .build_client()?;
+        let request =
+            ChatCompletionRequest::new("gpt-4o", vec![ChatCompletionRequestMessage::user("Hi")]);
+        let response = client.create_chat_completion_with_meta(request).await?;
+
+        assert_eq!(response.body.id, "chatcmpl-1");
+        assert_eq!(response.meta.trace_id.as_deref(), Some("trace-abc"));
+        assert_eq!(response.meta.ratelimit_remaining, Some(0));
+        assert_eq!(
+            response.meta.ratelimit_reset,
+            Some(std::time::Duration::from_millis(1500))
+        );
+
+        Ok(())
+    }
+
+    /// Answers with an event stream that sends a frame every 10ms, and fails
+    /// the body once it outlasts the request timeout, as reqwest does.
+    #[derive(Debug)]
+    struct SlowStreamTransport;
+
+    impl crate::builder::HttpTransport for SlowStreamTransport {
+        fn execute(&self, request: reqwest::Request) -> crate::builder::TransportFuture<'_> {
+            let frame = r#"data: {"id":"c","object":"chat.completion.chunk","created":1,"model":"gpt-4o","choices":[{"index":0,"delta":{"content":"a"},"finish_reason":null}]}"#;
+            let body = SlowBody {
+                frames: vec![format!("{frame}\n\n"); 5].into(),
+                delay: Box::pin(tokio::time::sleep(std::time::Duration::from_millis(10))),
+                started: std::time::Instant::now(),
+                timeout: request.timeout().copied(),
+            };
+            let response = http::Response::builder()
+                .body(reqwest::Body::wrap(body))
+                .unwrap();
+            Box::pin(async { Ok(response.into()) })
+        }
+    }
+
+    struct SlowBody {
+        frames: std::collections::VecDeque<String>,
+        delay: std::pin::Pin<Box<tokio::time::Sleep>>,
+        started: std::time::Instant,
+        timeout: Option<std::time::Duration>,
+    }
+
+    impl http_body::Body for SlowBody {
+        type Data = bytes::Bytes;
+        type Error = std::io::Error;
+
+        fn poll_frame(
+            mut self: std::pin::Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Option<std::result::Result<http_body::Frame<Self::Data>, Self::Error>>>
+        {
+            use std::task::Poll;
+
+            if self.delay.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            if self
+                .timeout
+                .is_some_and(|timeout| self.started.elapsed() > timeout)
+            {
+                let error = std::io::Error::new(std::io::ErrorKind::TimedOut, "body timed out");
+                return Poll::Ready(Some(Err(error)));
+            }
+            let Some(frame) = self.frames.pop_front() else {
+                return Poll::Ready(None);
+            };
+            self.delay = Box::pin(tokio::time::sleep(std::time::Duration::from_millis(10)));
+            Poll::Ready(Some(Ok(http_body::Frame::data(frame.into()))))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_outlives_timeout() -> Result<()> {
+        use crate::builder::AuthMethod;
+
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_transport(SlowStreamTransport)
+            .build_client()?;
+        let request =
+            ChatCompletionRequest::new("gpt-4o", vec![ChatCompletionRequestMessage::user("Hi")]);
+        // The stream runs for about 50ms, well past the timeout
+        let options = RequestOptions::new().with_timeout(std::time::Duration::from_millis(15));
+        let stream = client
+            .create_chat_completion_stream_with_options(request, options)
+            .await?;
+        assert_eq!(stream.collect_text().await?, "aaaaa");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stream_times_out_before_response() -> Result<()> {
+        use std::sync::Arc;
+
+        use crate::builder::{AuthMethod, HttpTransport, TransportFuture};
+        use crate::client::TestClock;
+
+        #[derive(Debug)]
+        struct PendingTransport;
+
+        impl HttpTransport for PendingTransport {
+            fn execute(&self, _: reqwest::Request) -> TransportFuture<'_> {
+                Box::pin(std::future::pending())
+            }
+        }
+
+        let config = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_transport(PendingTransport)
+            .build()?;
+        let clock = Arc::new(TestClock::new());
+        let client = PortkeyClient::with_clock(config, clock.clone())?;
+        let request =
+            ChatCompletionRequest::new("gpt-4o", vec![ChatCompletionRequestMessage::user("Hi")]);
+        let options = RequestOptions::new().with_timeout(std::time::Duration::from_secs(5));
+        let error = client
+            .create_chat_completion_stream_with_options(request, options)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::DeadlineExceeded));
+        assert_eq!(clock.elapsed(), std::time::Duration::from_secs(5));
+
+        Ok(())
     }
 }
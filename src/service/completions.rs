@@ -4,9 +4,9 @@
 
 use std::future::Future;
 
-use crate::client::PortkeyClient;
+use crate::client::{Endpoint, PortkeyClient, ResponseExt};
 use crate::error::Result;
-use crate::model::{CompletionResponse, CreateCompletionRequest};
+use crate::model::{CompletionResponse, CreateCompletionRequest, PortkeyResponse};
 
 /// Service trait for completions operations.
 pub trait CompletionsService {
@@ -43,17 +43,36 @@ pub trait CompletionsService {
         &self,
         request: CreateCompletionRequest,
     ) -> impl Future<Output = Result<CompletionResponse>>;
+
+    /// Creates a completion and returns it with the metadata from the
+    /// response headers, such as the trace ID and the prompt slug.
+    fn create_completion_with_meta(
+        &self,
+        request: CreateCompletionRequest,
+    ) -> impl Future<Output = Result<PortkeyResponse<CompletionResponse>>>;
 }
 
 impl CompletionsService for PortkeyClient {
+    async fn create_completion(
+        &self,
+        request: CreateCompletionRequest,
+    ) -> Result<CompletionResponse> {
+        self.create_completion_with_meta(request)
+            .await
+            .map(PortkeyResponse::into_body)
+    }
+
     #[cfg_attr(
         feature = "tracing",
         tracing::instrument(skip(self, request), fields(model = %request.model))
     )]
-    async fn create_completion(
+    async fn create_completion_with_meta(
         &self,
-        request: CreateCompletionRequest,
-    ) -> Result<CompletionResponse> {
+        mut request: CreateCompletionRequest,
+    ) -> Result<PortkeyResponse<CompletionResponse>> {
+        self.resolve_model(&mut request.model)?;
+        self.apply_generation_defaults(&mut request.max_tokens, &mut request.temperature);
+
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -61,11 +80,16 @@ impl CompletionsService for PortkeyClient {
         );
 
         let response = self
-            .send_json(reqwest::Method::POST, "/completions", &request)
+            .send_json(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::Completions),
+                &request,
+            )
             .await?
-            .error_for_status()?
-            .json::<CompletionResponse>()
+            .error_for_api_status()
             .await?;
+        let meta = response.meta();
+        let response: CompletionResponse = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::info!(
@@ -75,31 +99,9 @@ impl CompletionsService for PortkeyClient {
             "Completion created successfully"
         );
 
-        Ok(response)
-    }
-}
-
-// Add Default impl for CreateCompletionRequest
-impl Default for CreateCompletionRequest {
-    fn default() -> Self {
-        Self {
-            model: String::new(),
-            prompt: None,
-            max_tokens: Some(16),
-            temperature: Some(1.0),
-            top_p: Some(1.0),
-            n: Some(1),
-            stream: Some(false),
-            logprobs: None,
-            echo: Some(false),
-            stop: None,
-            frequency_penalty: Some(0.0),
-            presence_penalty: Some(0.0),
-            best_of: Some(1),
-            logit_bias: None,
-            user: None,
-            suffix: None,
-            seed: None,
-        }
+        Ok(PortkeyResponse {
+            body: response,
+            meta,
+        })
     }
 }
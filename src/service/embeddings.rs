@@ -1,10 +1,11 @@
 use std::future::Future;
 
+use crate::RequestOptions;
 #[cfg(feature = "tracing")]
 use crate::TRACING_TARGET_SERVICE;
-use crate::client::PortkeyClient;
+use crate::client::{Endpoint, PortkeyClient, ResponseExt};
 use crate::error::Result;
-use crate::model::{CreateEmbeddingRequest, CreateEmbeddingResponse};
+use crate::model::{CreateEmbeddingRequest, CreateEmbeddingResponse, PortkeyResponse};
 
 /// Service trait for creating embeddings.
 ///
@@ -58,7 +59,9 @@ pub trait EmbeddingsService {
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails or the response cannot be parsed.
+    /// Returns an error if the API request fails or the response cannot be parsed,
+    /// and [`Error::Validation`](crate::Error::Validation) without sending the
+    /// request if it fails [`CreateEmbeddingRequest::validate`].
     ///
     /// # Example
     ///
@@ -85,6 +88,7 @@ pub trait EmbeddingsService {
     /// };
     ///
     /// let response = client.create_embedding(request).await?;
+    /// response.ensure_dimensions(512)?;
     /// for (i, embedding) in response.data.iter().enumerate() {
     ///     println!("Embedding {}: {} dimensions", i, embedding.embedding.len());
     /// }
@@ -95,6 +99,44 @@ pub trait EmbeddingsService {
         &self,
         request: CreateEmbeddingRequest,
     ) -> impl Future<Output = Result<CreateEmbeddingResponse>>;
+
+    /// Creates embeddings and returns them with the metadata from the
+    /// response headers, such as the trace ID and the remaining rate limit.
+    fn create_embedding_with_meta(
+        &self,
+        request: CreateEmbeddingRequest,
+    ) -> impl Future<Output = Result<PortkeyResponse<CreateEmbeddingResponse>>>;
+
+    /// Creates embeddings with per-request options.
+    ///
+    /// The options take precedence over the client configuration for this
+    /// request only.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, RequestOptions, Result};
+    /// # use portkey_sdk::model::{CreateEmbeddingRequest, EmbeddingInput};
+    /// # use portkey_sdk::service::EmbeddingsService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = CreateEmbeddingRequest {
+    ///     model: "text-embedding-3-small".to_string(),
+    ///     input: EmbeddingInput::String("Hello, world!".to_string()),
+    ///     encoding_format: None,
+    ///     dimensions: None,
+    ///     user: None,
+    /// };
+    /// let options = RequestOptions::new().with_cache_namespace("tenant-acme");
+    ///
+    /// let response = client.create_embedding_with_options(request, options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_embedding_with_options(
+        &self,
+        request: CreateEmbeddingRequest,
+        options: RequestOptions,
+    ) -> impl Future<Output = Result<CreateEmbeddingResponse>>;
 }
 
 impl EmbeddingsService for PortkeyClient {
@@ -102,6 +144,38 @@ impl EmbeddingsService for PortkeyClient {
         &self,
         request: CreateEmbeddingRequest,
     ) -> Result<CreateEmbeddingResponse> {
+        self.create_embedding_with_meta(request)
+            .await
+            .map(PortkeyResponse::into_body)
+    }
+
+    async fn create_embedding_with_meta(
+        &self,
+        request: CreateEmbeddingRequest,
+    ) -> Result<PortkeyResponse<CreateEmbeddingResponse>> {
+        self.embed(request, &RequestOptions::default()).await
+    }
+
+    async fn create_embedding_with_options(
+        &self,
+        request: CreateEmbeddingRequest,
+        options: RequestOptions,
+    ) -> Result<CreateEmbeddingResponse> {
+        self.embed(request, &options)
+            .await
+            .map(PortkeyResponse::into_body)
+    }
+}
+
+impl PortkeyClient {
+    /// Creates embeddings, returning them with the response metadata.
+    async fn embed(
+        &self,
+        mut request: CreateEmbeddingRequest,
+        options: &RequestOptions,
+    ) -> Result<PortkeyResponse<CreateEmbeddingResponse>> {
+        self.resolve_model(&mut request.model)?;
+
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: TRACING_TARGET_SERVICE,
@@ -111,12 +185,23 @@ impl EmbeddingsService for PortkeyClient {
             "Creating embedding"
         );
 
+        request.validate()?;
+
         let response = self
-            .send_json(reqwest::Method::POST, "/embeddings", &request)
+            .send_json_with_options(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::Embeddings),
+                &request,
+                options,
+            )
             .await?;
-        let response = response.error_for_status()?;
-        let embedding_response: CreateEmbeddingResponse = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let meta = response.meta();
+        let embedding_response: CreateEmbeddingResponse = self.json_object(response).await?;
 
-        Ok(embedding_response)
+        Ok(PortkeyResponse {
+            body: embedding_response,
+            meta,
+        })
     }
 }
@@ -2,7 +2,7 @@ use std::future::Future;
 
 #[cfg(feature = "tracing")]
 use crate::TRACING_TARGET_SERVICE;
-use crate::client::PortkeyClient;
+use crate::client::{PortkeyClient, ResponseExt};
 use crate::error::Result;
 use crate::model::{CreateFeedbackRequest, FeedbackResponse, UpdateFeedbackRequest};
 
@@ -108,7 +108,7 @@ impl FeedbackService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::POST, "/feedback", &request)
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let feedback_response: FeedbackResponse = response.json().await?;
 
         Ok(feedback_response)
@@ -131,7 +131,7 @@ impl FeedbackService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::PATCH, &path, &request)
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let feedback_response: FeedbackResponse = response.json().await?;
 
         Ok(feedback_response)
@@ -4,9 +4,13 @@
 
 use std::future::Future;
 
-use crate::client::PortkeyClient;
+use serde::de::DeserializeOwned;
+use tokio::task::JoinSet;
+
+use crate::client::{ACCEPT_ANY, PortkeyClient, ResponseExt, spawn_bytes, spawn_ndjson};
 use crate::error::Result;
 use crate::model::{DeleteFileResponse, FileObject, ListFilesResponse, UploadFileRequest};
+use crate::{ByteStream, EventStream, RequestOptions};
 
 /// Service trait for file operations.
 pub trait FilesService {
@@ -29,6 +33,7 @@ pub trait FilesService {
     ///     file: vec![/* file bytes */],
     ///     filename: "training_data.jsonl".to_string(),
     ///     purpose: "fine-tune".to_string(),
+    ///     expires_after: None,
     /// };
     ///
     /// let file = client.upload_file(request).await?;
@@ -97,6 +102,69 @@ pub trait FilesService {
     /// ```
     fn retrieve_file_content(&self, file_id: &str) -> impl Future<Output = Result<Vec<u8>>>;
 
+    /// Returns the contents of the specified file as a stream of chunks.
+    ///
+    /// Unlike [`retrieve_file_content`](Self::retrieve_file_content), the file
+    /// is not buffered in memory, which suits large batch outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The ID of the file to retrieve content from
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::FilesService;
+    /// # async fn example() -> Result<()> {
+    /// let client = PortkeyClient::from_env()?;
+    ///
+    /// let mut stream = client.retrieve_file_content_stream("file-abc123").await?;
+    /// while let Some(chunk) = stream.next().await {
+    ///     println!("Received {} bytes", chunk?.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_file_content_stream(
+        &self,
+        file_id: &str,
+    ) -> impl Future<Output = Result<ByteStream>>;
+
+    /// Streams a JSONL file, yielding one deserialized value per line.
+    ///
+    /// Combines [`retrieve_file_content_stream`](Self::retrieve_file_content_stream)
+    /// with line-by-line parsing, so large batch outputs and fine-tuning
+    /// results are processed without buffering the whole file. Blank lines
+    /// are skipped; a line that fails to parse yields an error and ends the
+    /// stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The ID of the JSONL file to read
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::FilesService;
+    /// # use portkey_sdk::model::BatchResultLine;
+    /// # async fn example() -> Result<()> {
+    /// let client = PortkeyClient::from_env()?;
+    ///
+    /// let mut lines = client
+    ///     .retrieve_file_lines::<BatchResultLine>("file-abc123")
+    ///     .await?;
+    /// while let Some(line) = lines.next().await {
+    ///     println!("{}", line?.custom_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_file_lines<T>(&self, file_id: &str) -> impl Future<Output = Result<EventStream<T>>>
+    where
+        T: DeserializeOwned + Send + 'static;
+
     /// Delete a file.
     ///
     /// # Arguments
@@ -117,6 +185,44 @@ pub trait FilesService {
     /// # }
     /// ```
     fn delete_file(&self, file_id: &str) -> impl Future<Output = Result<DeleteFileResponse>>;
+
+    /// Delete many files, running up to `concurrency` deletions at a time.
+    ///
+    /// Each deletion is retried under the client's
+    /// [`RetryPolicy`](crate::builder::RetryPolicy), so rate-limited deletions
+    /// back off as configured. Every ID is attempted, and the results are
+    /// returned in the order of `ids`, so partial failures are visible. A
+    /// `concurrency` of zero is treated as one.
+    ///
+    /// The deletions run as tasks on the current Tokio runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the files to delete
+    /// * `concurrency` - The maximum number of deletions in flight
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::FilesService;
+    /// # async fn example() -> Result<()> {
+    /// let client = PortkeyClient::from_env()?;
+    ///
+    /// let ids = vec!["file-abc123".to_string(), "file-def456".to_string()];
+    /// for (id, result) in client.delete_files(ids, 4).await {
+    ///     if let Err(error) = result {
+    ///         eprintln!("Failed to delete {id}: {error}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn delete_files(
+        &self,
+        ids: Vec<String>,
+        concurrency: usize,
+    ) -> impl Future<Output = Vec<(String, Result<DeleteFileResponse>)>>;
 }
 
 impl FilesService for PortkeyClient {
@@ -131,19 +237,23 @@ impl FilesService for PortkeyClient {
             "Uploading file"
         );
 
+        let fields = upload_form_fields(&request);
         let part =
             reqwest::multipart::Part::bytes(request.file).file_name(request.filename.clone());
 
-        let form = reqwest::multipart::Form::new()
-            .part("file", part)
-            .text("purpose", request.purpose);
+        let form = fields
+            .into_iter()
+            .fold(reqwest::multipart::Form::new(), |form, (name, value)| {
+                form.text(name, value)
+            })
+            .part("file", part);
 
         let response = self
             .send_multipart(reqwest::Method::POST, "/files", form)
             .await?
-            .error_for_status()?
-            .json::<FileObject>()
+            .error_for_api_status()
             .await?;
+        let response: FileObject = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::info!(
@@ -167,9 +277,9 @@ impl FilesService for PortkeyClient {
         let response = self
             .send(reqwest::Method::GET, "/files")
             .await?
-            .error_for_status()?
-            .json::<ListFilesResponse>()
+            .error_for_api_status()
             .await?;
+        let response: ListFilesResponse = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::info!(
@@ -192,9 +302,9 @@ impl FilesService for PortkeyClient {
         let response = self
             .send(reqwest::Method::GET, &format!("/files/{}", file_id))
             .await?
-            .error_for_status()?
-            .json::<FileObject>()
+            .error_for_api_status()
             .await?;
+        let response: FileObject = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::info!(
@@ -216,9 +326,14 @@ impl FilesService for PortkeyClient {
         );
 
         let response = self
-            .send(reqwest::Method::GET, &format!("/files/{}/content", file_id))
+            .send_with_options(
+                reqwest::Method::GET,
+                &format!("/files/{}/content", file_id),
+                &RequestOptions::default().with_accept(ACCEPT_ANY),
+            )
+            .await?
+            .error_for_api_status()
             .await?
-            .error_for_status()?
             .bytes()
             .await?;
 
@@ -232,6 +347,42 @@ impl FilesService for PortkeyClient {
         Ok(response.to_vec())
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(file_id)))]
+    async fn retrieve_file_content_stream(&self, file_id: &str) -> Result<ByteStream> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            "Streaming file content"
+        );
+
+        let response = self
+            .send_with_options(
+                reqwest::Method::GET,
+                &format!("/files/{}/content", file_id),
+                &RequestOptions::default()
+                    .with_accept(ACCEPT_ANY)
+                    .with_streaming(),
+            )
+            .await?
+            .error_for_api_status()
+            .await?;
+
+        Ok(spawn_bytes(
+            response,
+            RequestOptions::default().stream_buffer(),
+        ))
+    }
+
+    async fn retrieve_file_lines<T>(&self, file_id: &str) -> Result<EventStream<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        Ok(spawn_ndjson(
+            self.retrieve_file_content_stream(file_id).await?,
+            RequestOptions::default().stream_buffer(),
+        ))
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(file_id)))]
     async fn delete_file(&self, file_id: &str) -> Result<DeleteFileResponse> {
         #[cfg(feature = "tracing")]
@@ -243,9 +394,9 @@ impl FilesService for PortkeyClient {
         let response = self
             .send(reqwest::Method::DELETE, &format!("/files/{}", file_id))
             .await?
-            .error_for_status()?
-            .json::<DeleteFileResponse>()
+            .error_for_api_status()
             .await?;
+        let response: DeleteFileResponse = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::info!(
@@ -257,4 +408,180 @@ impl FilesService for PortkeyClient {
 
         Ok(response)
     }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self, ids), fields(count = ids.len(), concurrency))
+    )]
+    async fn delete_files(
+        &self,
+        ids: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<DeleteFileResponse>)> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            "Deleting files"
+        );
+
+        let concurrency = concurrency.max(1);
+        let mut results: Vec<Option<Result<DeleteFileResponse>>> =
+            ids.iter().map(|_| None).collect();
+        let mut pending = ids.iter().cloned().enumerate();
+        let mut tasks = JoinSet::new();
+
+        loop {
+            while tasks.len() < concurrency
+                && let Some((index, id)) = pending.next()
+            {
+                let client = self.clone();
+                tasks.spawn(async move { (index, client.delete_file(&id).await) });
+            }
+
+            match tasks.join_next().await {
+                Some(Ok((index, result))) => results[index] = Some(result),
+                Some(Err(error)) => std::panic::resume_unwind(error.into_panic()),
+                None => break,
+            }
+        }
+
+        ids.into_iter()
+            .zip(results)
+            .map(|(id, result)| (id, result.expect("every deletion completes")))
+            .collect()
+    }
+}
+
+/// Returns the text fields of the multipart form for a file upload.
+fn upload_form_fields(request: &UploadFileRequest) -> Vec<(&'static str, String)> {
+    let mut fields = vec![("purpose", request.purpose.clone())];
+
+    if let Some(expires_after) = request.expires_after {
+        fields.push((
+            "expires_after[anchor]",
+            expires_after.anchor.as_str().to_string(),
+        ));
+        fields.push(("expires_after[seconds]", expires_after.seconds.to_string()));
+    }
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::client::{RouteTransport, TestClock};
+    use crate::model::ExpiresAfter;
+
+    fn request(expires_after: Option<ExpiresAfter>) -> UploadFileRequest {
+        UploadFileRequest {
+            file: b"{}".to_vec(),
+            filename: "batch.jsonl".to_string(),
+            purpose: "batch".to_string(),
+            expires_after,
+        }
+    }
+
+    #[test]
+    fn test_upload_form_fields() {
+        assert_eq!(
+            upload_form_fields(&request(None)),
+            vec![("purpose", "batch".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_upload_form_fields_with_expiration() {
+        assert_eq!(
+            upload_form_fields(&request(Some(ExpiresAfter::from_creation(3600)))),
+            vec![
+                ("purpose", "batch".to_string()),
+                ("expires_after[anchor]", "created_at".to_string()),
+                ("expires_after[seconds]", "3600".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_files_uses_retry_policy() -> Result<()> {
+        use std::time::Duration;
+
+        use crate::builder::RetryPolicy;
+
+        let deleted = |id: &str| format!(r#"{{"id":"{id}","object":"file","deleted":true}}"#);
+        let transport = RouteTransport::default()
+            .route("DELETE /files/file-a", 200, deleted("file-a"))
+            .route(
+                "DELETE /files/file-b",
+                429,
+                r#"{"error":{"message":"Rate limited"}}"#,
+            )
+            .route("DELETE /files/file-c", 200, deleted("file-c"));
+        let requests = transport.requests();
+        let config = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(crate::builder::AuthMethod::virtual_key("vk-123"))
+            .with_retry_policy(
+                RetryPolicy::new(2)
+                    .with_initial_backoff(Duration::from_millis(100))
+                    .with_jitter(false),
+            )
+            .with_transport(transport)
+            .build()?;
+        let clock = Arc::new(TestClock::new());
+        let client = PortkeyClient::with_clock(config, clock.clone())?;
+
+        let ids = ["file-a", "file-b", "file-c"].map(String::from).to_vec();
+        let results = client.delete_files(ids, 2).await;
+
+        let ids: Vec<&str> = results.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, ["file-a", "file-b", "file-c"]);
+        assert!(results[0].1.as_ref().unwrap().deleted);
+        assert!(results[1].1.as_ref().unwrap_err().is_status(429));
+        assert_eq!(results[2].1.as_ref().unwrap().id, "file-c");
+
+        let requests = requests.lock().unwrap();
+        let attempts = |id: &str| {
+            let route = format!("DELETE /files/{id}");
+            requests.iter().filter(|request| **request == route).count()
+        };
+        assert_eq!(attempts("file-a"), 1);
+        assert_eq!(attempts("file-b"), 3);
+        assert_eq!(attempts("file-c"), 1);
+        assert_eq!(clock.elapsed(), Duration::from_millis(300));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_file_lines() -> Result<()> {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Line {
+            id: u32,
+        }
+
+        let client = RouteTransport::default()
+            .route(
+                "GET /files/file-abc123/content",
+                200,
+                "{\"id\":1}\n\n{\"id\":2}\n{\"id\":3}\nnot json\n{\"id\":4}",
+            )
+            .client();
+
+        let mut lines = client.retrieve_file_lines::<Line>("file-abc123").await?;
+        let mut ids = Vec::new();
+        let mut errors = 0;
+        while let Some(line) = lines.next().await {
+            match line {
+                Ok(line) => ids.push(line.id),
+                Err(_) => errors += 1,
+            }
+        }
+
+        assert_eq!(ids, [1, 2, 3]);
+        assert_eq!(errors, 1);
+        Ok(())
+    }
 }
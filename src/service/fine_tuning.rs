@@ -1,5 +1,6 @@
 use std::future::Future;
 
+use crate::client::ResponseExt;
 use crate::model::{
     CreateFineTuningJobRequest, FineTuningJob, ListFineTuningJobCheckpointsResponse,
     ListFineTuningJobEventsResponse, ListFineTuningJobsResponse, PaginationParams,
@@ -72,7 +73,7 @@ pub trait FineTuningService {
     /// # use portkey_sdk::service::FineTuningService;
     /// # use portkey_sdk::model::PaginationParams;
     /// # async fn example(client: &impl FineTuningService) -> portkey_sdk::Result<()> {
-    /// let params = PaginationParams { limit: Some(10), order: None, after: None, before: None };
+    /// let params = PaginationParams::new().with_limit(10);
     /// let jobs = client.list_fine_tuning_jobs(params).await?;
     /// for job in jobs.data {
     ///     println!("Job {}: {}", job.id, job.status);
@@ -143,7 +144,7 @@ pub trait FineTuningService {
     /// # use portkey_sdk::service::FineTuningService;
     /// # use portkey_sdk::model::PaginationParams;
     /// # async fn example(client: &impl FineTuningService) -> portkey_sdk::Result<()> {
-    /// let params = PaginationParams { limit: Some(10), order: None, after: None, before: None };
+    /// let params = PaginationParams::new().with_limit(10);
     /// let events = client.list_fine_tuning_job_events("ftjob-abc123", params).await?;
     /// for event in events.data {
     ///     println!("[{}] {}", event.level, event.message);
@@ -171,7 +172,7 @@ pub trait FineTuningService {
     /// # use portkey_sdk::service::FineTuningService;
     /// # use portkey_sdk::model::PaginationParams;
     /// # async fn example(client: &impl FineTuningService) -> portkey_sdk::Result<()> {
-    /// let params = PaginationParams { limit: Some(5), order: None, after: None, before: None };
+    /// let params = PaginationParams::new().with_limit(5);
     /// let checkpoints = client.list_fine_tuning_job_checkpoints("ftjob-abc123", params).await?;
     /// for checkpoint in checkpoints.data {
     ///     println!("Checkpoint at step {}", checkpoint.step_number);
@@ -200,8 +201,8 @@ impl FineTuningService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::POST, "/fine_tuning/jobs", &request)
             .await?;
-        let response = response.error_for_status()?;
-        let job: FineTuningJob = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let job: FineTuningJob = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -222,19 +223,8 @@ impl FineTuningService for PortkeyClient {
             "Listing fine-tuning jobs"
         );
 
-        let query_params = params.to_query_params();
-        let query_params_refs: Vec<(&str, &str)> =
-            query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-
-        let response = self
-            .send_with_params(
-                reqwest::Method::GET,
-                "/fine_tuning/jobs",
-                &query_params_refs,
-            )
-            .await?;
-        let response = response.error_for_status()?;
-        let jobs: ListFineTuningJobsResponse = response.json().await?;
+        let jobs: ListFineTuningJobsResponse =
+            self.get_paginated("/fine_tuning/jobs", &params).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -259,8 +249,8 @@ impl FineTuningService for PortkeyClient {
                 &format!("/fine_tuning/jobs/{}", fine_tuning_job_id),
             )
             .await?;
-        let response = response.error_for_status()?;
-        let job: FineTuningJob = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let job: FineTuningJob = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -286,8 +276,8 @@ impl FineTuningService for PortkeyClient {
                 &serde_json::json!({}),
             )
             .await?;
-        let response = response.error_for_status()?;
-        let job: FineTuningJob = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let job: FineTuningJob = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -310,19 +300,12 @@ impl FineTuningService for PortkeyClient {
             "Listing fine-tuning job events"
         );
 
-        let query_params = params.to_query_params();
-        let query_params_refs: Vec<(&str, &str)> =
-            query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-
-        let response = self
-            .send_with_params(
-                reqwest::Method::GET,
+        let events: ListFineTuningJobEventsResponse = self
+            .get_paginated(
                 &format!("/fine_tuning/jobs/{}/events", fine_tuning_job_id),
-                &query_params_refs,
+                &params,
             )
             .await?;
-        let response = response.error_for_status()?;
-        let events: ListFineTuningJobEventsResponse = response.json().await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -345,19 +328,12 @@ impl FineTuningService for PortkeyClient {
             "Listing fine-tuning job checkpoints"
         );
 
-        let query_params = params.to_query_params();
-        let query_params_refs: Vec<(&str, &str)> =
-            query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-
-        let response = self
-            .send_with_params(
-                reqwest::Method::GET,
+        let checkpoints: ListFineTuningJobCheckpointsResponse = self
+            .get_paginated(
                 &format!("/fine_tuning/jobs/{}/checkpoints", fine_tuning_job_id),
-                &query_params_refs,
+                &params,
             )
             .await?;
-        let response = response.error_for_status()?;
-        let checkpoints: ListFineTuningJobCheckpointsResponse = response.json().await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -6,7 +6,7 @@ use std::future::Future;
 
 use reqwest::multipart::{Form, Part};
 
-use crate::client::PortkeyClient;
+use crate::client::{Endpoint, PortkeyClient, ResponseExt};
 use crate::error::Result;
 use crate::model::{
     CreateImageEditRequest, CreateImageRequest, CreateImageVariationRequest, ImageSize,
@@ -25,6 +25,10 @@ pub trait ImagesService {
     ///
     /// Returns an `ImagesResponse` containing the generated images.
     ///
+    /// With [strict validation](crate::builder::PortkeyBuilder::with_strict_validation),
+    /// requests that fail [`CreateImageRequest::validate`] are rejected with
+    /// [`Error::Validation`](crate::Error::Validation) before they are sent.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -177,10 +181,18 @@ pub trait ImagesService {
 
 impl ImagesService for PortkeyClient {
     async fn generate_image(&self, request: CreateImageRequest) -> Result<ImagesResponse> {
+        if self.inner.config.strict_validation() {
+            request.validate()?;
+        }
+
         let response = self
-            .send_json(reqwest::Method::POST, "/images/generations", &request)
+            .send_json(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::ImageGenerations),
+                &request,
+            )
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let images_response: ImagesResponse = response.json().await?;
         Ok(images_response)
     }
@@ -239,10 +251,14 @@ impl ImagesService for PortkeyClient {
         }
 
         let response = self
-            .send_multipart(reqwest::Method::POST, "/images/edits", form)
+            .send_multipart(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::ImageEdits),
+                form,
+            )
             .await?;
 
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let images_response: ImagesResponse = response.json().await?;
         Ok(images_response)
     }
@@ -291,10 +307,14 @@ impl ImagesService for PortkeyClient {
         }
 
         let response = self
-            .send_multipart(reqwest::Method::POST, "/images/variations", form)
+            .send_multipart(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::ImageVariations),
+                form,
+            )
             .await?;
 
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let images_response: ImagesResponse = response.json().await?;
         Ok(images_response)
     }
@@ -303,6 +323,8 @@ impl ImagesService for PortkeyClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::builder::AuthMethod;
+    use crate::client::RouteTransport;
     use crate::model::{ImageQuality, ImageSize, ImageStyle};
 
     #[test]
@@ -322,4 +344,28 @@ mod tests {
         assert_eq!(request.model, Some("dall-e-3".to_string()));
         assert_eq!(request.n, Some(1));
     }
+
+    #[tokio::test]
+    async fn test_generate_image_strict_validation() {
+        let transport = RouteTransport::default();
+        let requests = transport.requests();
+        let client = PortkeyClient::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk-123"))
+            .with_strict_validation(true)
+            .with_transport(transport)
+            .build_client()
+            .unwrap();
+
+        let request = CreateImageRequest {
+            prompt: "A cute baby sea otter".to_string(),
+            model: Some("dall-e-3".to_string()),
+            n: Some(2),
+            ..Default::default()
+        };
+        let result = client.generate_image(request).await;
+
+        assert!(matches!(result, Err(crate::Error::Validation(_))));
+        assert!(requests.lock().unwrap().is_empty());
+    }
 }
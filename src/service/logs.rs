@@ -2,7 +2,7 @@ use std::future::Future;
 
 #[cfg(feature = "tracing")]
 use crate::TRACING_TARGET_SERVICE;
-use crate::client::PortkeyClient;
+use crate::client::{PortkeyClient, ResponseExt};
 use crate::error::Result;
 use crate::model::{
     CreateLogExportRequest, CreateLogExportResponse, DownloadLogExportResponse, ExportTaskResponse,
@@ -99,6 +99,24 @@ pub trait LogsService {
     /// Returns an error if the export is not found or the API request fails.
     fn get_log_export(&self, export_id: &str) -> impl Future<Output = Result<LogExport>>;
 
+    /// Retrieves a log export by ID, if it exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `export_id` - The unique identifier of the export
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(None)` if the API responds with `404 Not Found`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails for any other reason.
+    fn try_get_log_export(
+        &self,
+        export_id: &str,
+    ) -> impl Future<Output = Result<Option<LogExport>>>;
+
     /// Starts processing a log export.
     ///
     /// # Arguments
@@ -159,7 +177,9 @@ pub trait LogsService {
     ///
     /// # Returns
     ///
-    /// Returns an `InsertLogResponse` with status and log IDs.
+    /// Returns an `InsertLogResponse` with status and log IDs. When the API
+    /// reports per-log results for a batch,
+    /// [`InsertLogResponse::failures`] lists the logs that were rejected.
     ///
     /// # Errors
     ///
@@ -224,8 +244,8 @@ impl LogsService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::POST, "/logs/exports", &request)
             .await?;
-        let response = response.error_for_status()?;
-        let export_response: CreateLogExportResponse = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let export_response: CreateLogExportResponse = self.json_object(response).await?;
 
         Ok(export_response)
     }
@@ -240,12 +260,20 @@ impl LogsService for PortkeyClient {
 
         let path = format!("/logs/exports/{}", export_id);
         let response = self.send(reqwest::Method::GET, &path).await?;
-        let response = response.error_for_status()?;
-        let export: LogExport = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let export: LogExport = self.json_object(response).await?;
 
         Ok(export)
     }
 
+    async fn try_get_log_export(&self, export_id: &str) -> Result<Option<LogExport>> {
+        match self.get_log_export(export_id).await {
+            Ok(export) => Ok(Some(export)),
+            Err(error) if error.is_not_found() => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
     async fn start_log_export(&self, export_id: &str) -> Result<ExportTaskResponse> {
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -256,8 +284,8 @@ impl LogsService for PortkeyClient {
 
         let path = format!("/logs/exports/{}/start", export_id);
         let response = self.send(reqwest::Method::POST, &path).await?;
-        let response = response.error_for_status()?;
-        let task_response: ExportTaskResponse = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let task_response: ExportTaskResponse = self.json_object(response).await?;
 
         Ok(task_response)
     }
@@ -272,8 +300,8 @@ impl LogsService for PortkeyClient {
 
         let path = format!("/logs/exports/{}/cancel", export_id);
         let response = self.send(reqwest::Method::POST, &path).await?;
-        let response = response.error_for_status()?;
-        let task_response: ExportTaskResponse = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let task_response: ExportTaskResponse = self.json_object(response).await?;
 
         Ok(task_response)
     }
@@ -288,7 +316,7 @@ impl LogsService for PortkeyClient {
 
         let path = format!("/logs/exports/{}/download", export_id);
         let response = self.send(reqwest::Method::GET, &path).await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let download_response: DownloadLogExportResponse = response.json().await?;
 
         Ok(download_response)
@@ -304,7 +332,7 @@ impl LogsService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::POST, "/logs", &request)
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let insert_response: InsertLogResponse = response.json().await?;
 
         Ok(insert_response)
@@ -327,8 +355,8 @@ impl LogsService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::PUT, &path, &request)
             .await?;
-        let response = response.error_for_status()?;
-        let update_response: UpdateLogExportResponse = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let update_response: UpdateLogExportResponse = self.json_object(response).await?;
 
         Ok(update_response)
     }
@@ -357,9 +385,31 @@ impl LogsService for PortkeyClient {
             self.send(reqwest::Method::GET, "/logs/exports").await?
         };
 
-        let response = response.error_for_status()?;
-        let list_response: ListLogExportsResponse = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let list_response: ListLogExportsResponse = self.json_object(response).await?;
 
         Ok(list_response)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::MockTransport;
+
+    #[tokio::test]
+    async fn test_try_get_log_export() {
+        let client = MockTransport::client(404, r#"{"error":{"message":"not found"}}"#);
+        assert!(
+            client
+                .try_get_log_export("exp_123")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let client = MockTransport::client(500, r#"{"error":{"message":"internal"}}"#);
+        let error = client.try_get_log_export("exp_123").await.unwrap_err();
+        assert!(error.is_status(500));
+    }
+}
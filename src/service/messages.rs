@@ -1,8 +1,9 @@
 use std::future::Future;
 
+use crate::client::ResponseExt;
 use crate::model::{
-    CreateMessageRequest, ListMessageFilesResponse, ListMessagesResponse, Message, MessageFile,
-    ModifyMessageRequest, PaginationParams,
+    CreateMessageRequest, FileId, ListMessageFilesResponse, ListMessagesResponse, Message,
+    MessageFile, MessageId, ModifyMessageRequest, PaginationParams, ThreadId,
 };
 use crate::{PortkeyClient, Result};
 
@@ -43,17 +44,33 @@ pub trait MessagesService {
     ) -> impl Future<Output = Result<Message>>;
 
     /// Retrieve a message.
+    ///
+    /// The IDs can be passed as strings or as typed IDs, which the compiler
+    /// keeps from being swapped:
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::MessagesService;
+    /// use portkey_sdk::model::{MessageId, ThreadId};
+    ///
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let thread_id = ThreadId::from("thread_abc123");
+    /// let message_id = MessageId::from("msg_abc123");
+    /// let message = client.retrieve_message(&thread_id, &message_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     fn retrieve_message(
         &self,
-        thread_id: &str,
-        message_id: &str,
+        thread_id: impl Into<ThreadId>,
+        message_id: impl Into<MessageId>,
     ) -> impl Future<Output = Result<Message>>;
 
     /// Modifies a message.
     fn modify_message(
         &self,
-        thread_id: &str,
-        message_id: &str,
+        thread_id: impl Into<ThreadId>,
+        message_id: impl Into<MessageId>,
         request: ModifyMessageRequest,
     ) -> impl Future<Output = Result<Message>>;
 
@@ -64,19 +81,29 @@ pub trait MessagesService {
         params: PaginationParams,
     ) -> impl Future<Output = Result<ListMessagesResponse>>;
 
+    /// Returns every message of a thread, following pagination, up to
+    /// `max_items`.
+    ///
+    /// Stops fetching pages once `max_items` messages are collected.
+    fn list_all_messages(
+        &self,
+        thread_id: &str,
+        max_items: Option<usize>,
+    ) -> impl Future<Output = Result<Vec<Message>>>;
+
     /// Retrieves a message file.
     fn retrieve_message_file(
         &self,
-        thread_id: &str,
-        message_id: &str,
-        file_id: &str,
+        thread_id: impl Into<ThreadId>,
+        message_id: impl Into<MessageId>,
+        file_id: impl Into<FileId>,
     ) -> impl Future<Output = Result<MessageFile>>;
 
     /// Returns a list of message files.
     fn list_message_files(
         &self,
-        thread_id: &str,
-        message_id: &str,
+        thread_id: impl Into<ThreadId>,
+        message_id: impl Into<MessageId>,
         params: PaginationParams,
     ) -> impl Future<Output = Result<ListMessageFilesResponse>>;
 }
@@ -101,8 +128,8 @@ impl MessagesService for PortkeyClient {
                 &request,
             )
             .await?;
-        let response = response.error_for_status()?;
-        let message: Message = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let message: Message = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -113,7 +140,13 @@ impl MessagesService for PortkeyClient {
         Ok(message)
     }
 
-    async fn retrieve_message(&self, thread_id: &str, message_id: &str) -> Result<Message> {
+    async fn retrieve_message(
+        &self,
+        thread_id: impl Into<ThreadId>,
+        message_id: impl Into<MessageId>,
+    ) -> Result<Message> {
+        let thread_id = thread_id.into();
+        let message_id = message_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -128,8 +161,8 @@ impl MessagesService for PortkeyClient {
                 &format!("/threads/{}/messages/{}", thread_id, message_id),
             )
             .await?;
-        let response = response.error_for_status()?;
-        let message: Message = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let message: Message = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -142,10 +175,12 @@ impl MessagesService for PortkeyClient {
 
     async fn modify_message(
         &self,
-        thread_id: &str,
-        message_id: &str,
+        thread_id: impl Into<ThreadId>,
+        message_id: impl Into<MessageId>,
         request: ModifyMessageRequest,
     ) -> Result<Message> {
+        let thread_id = thread_id.into();
+        let message_id = message_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -161,8 +196,8 @@ impl MessagesService for PortkeyClient {
                 &request,
             )
             .await?;
-        let response = response.error_for_status()?;
-        let message: Message = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let message: Message = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -185,19 +220,9 @@ impl MessagesService for PortkeyClient {
             "Listing messages"
         );
 
-        let query_params = params.to_query_params();
-        let query_params_refs: Vec<(&str, &str)> =
-            query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-
-        let response = self
-            .send_with_params(
-                reqwest::Method::GET,
-                &format!("/threads/{}/messages", thread_id),
-                &query_params_refs,
-            )
+        let messages: ListMessagesResponse = self
+            .get_paginated(&format!("/threads/{}/messages", thread_id), &params)
             .await?;
-        let response = response.error_for_status()?;
-        let messages: ListMessagesResponse = response.json().await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -208,12 +233,35 @@ impl MessagesService for PortkeyClient {
         Ok(messages)
     }
 
-    async fn retrieve_message_file(
+    async fn list_all_messages(
         &self,
         thread_id: &str,
-        message_id: &str,
-        file_id: &str,
+        max_items: Option<usize>,
+    ) -> Result<Vec<Message>> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            thread_id = %thread_id,
+            max_items = ?max_items,
+            "Listing all messages"
+        );
+
+        self.get_all_pages::<ListMessagesResponse>(
+            &format!("/threads/{}/messages", thread_id),
+            max_items,
+        )
+        .await
+    }
+
+    async fn retrieve_message_file(
+        &self,
+        thread_id: impl Into<ThreadId>,
+        message_id: impl Into<MessageId>,
+        file_id: impl Into<FileId>,
     ) -> Result<MessageFile> {
+        let thread_id = thread_id.into();
+        let message_id = message_id.into();
+        let file_id = file_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -232,7 +280,7 @@ impl MessagesService for PortkeyClient {
                 ),
             )
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let file: MessageFile = response.json().await?;
 
         #[cfg(feature = "tracing")]
@@ -246,10 +294,12 @@ impl MessagesService for PortkeyClient {
 
     async fn list_message_files(
         &self,
-        thread_id: &str,
-        message_id: &str,
+        thread_id: impl Into<ThreadId>,
+        message_id: impl Into<MessageId>,
         params: PaginationParams<'_>,
     ) -> Result<ListMessageFilesResponse> {
+        let thread_id = thread_id.into();
+        let message_id = message_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -258,19 +308,12 @@ impl MessagesService for PortkeyClient {
             "Listing message files"
         );
 
-        let query_params = params.to_query_params();
-        let query_params_refs: Vec<(&str, &str)> =
-            query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-
-        let response = self
-            .send_with_params(
-                reqwest::Method::GET,
+        let files: ListMessageFilesResponse = self
+            .get_paginated(
                 &format!("/threads/{}/messages/{}/files", thread_id, message_id),
-                &query_params_refs,
+                &params,
             )
             .await?;
-        let response = response.error_for_status()?;
-        let files: ListMessageFilesResponse = response.json().await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -18,6 +18,7 @@ mod messages;
 mod models;
 mod moderations;
 mod prompts;
+mod response_generations;
 mod responses;
 mod runs;
 mod threads;
@@ -37,6 +38,7 @@ pub use messages::*;
 pub use models::*;
 pub use moderations::*;
 pub use prompts::*;
+pub use response_generations::*;
 pub use responses::*;
 pub use runs::*;
 pub use threads::*;
@@ -3,10 +3,15 @@
 //! This module provides methods for listing available models through Portkey.
 
 use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::client::PortkeyClient;
+use crate::client::{Endpoint, PortkeyClient, ResponseExt};
 use crate::error::Result;
-use crate::model::{ListModelsParams, ListModelsResponse, ModelSortField, SortOrder};
+use crate::model::{
+    DeleteModelResponse, ListModelsParams, ListModelsResponse, ModelSortField, ModelsCatalog,
+    SortOrder,
+};
 
 /// Trait for Models API operations.
 pub trait ModelsService {
@@ -54,11 +59,47 @@ pub trait ModelsService {
         &self,
         params: Option<ListModelsParams>,
     ) -> impl Future<Output = Result<ListModelsResponse>>;
+
+    /// Returns the catalog of available models, cached on the client.
+    ///
+    /// The catalog is fetched with [`list_models`](Self::list_models) on the
+    /// first call and reused until it is older than `ttl`. The cache is shared
+    /// between clones of the client, and concurrent callers wait for a single
+    /// refresh instead of each fetching the list.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::ModelsService;
+    /// # use std::time::Duration;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let catalog = client.models_catalog(Duration::from_secs(300)).await?;
+    /// if !catalog.contains("gpt-4o") {
+    ///     println!("gpt-4o is not available");
+    /// }
+    /// for model in catalog.by_provider("openai") {
+    ///     println!("{}", model.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn models_catalog(&self, ttl: Duration) -> impl Future<Output = Result<Arc<ModelsCatalog>>>;
+
+    /// Drops the cached models catalog, so the next
+    /// [`models_catalog`](Self::models_catalog) call fetches a fresh list.
+    fn invalidate_models_catalog(&self) -> impl Future<Output = ()>;
+
+    /// Deletes a fine-tuned model.
+    ///
+    /// Also invalidates the cached models catalog.
+    fn delete_model(&self, model: &str) -> impl Future<Output = Result<DeleteModelResponse>>;
 }
 
 impl ModelsService for PortkeyClient {
     async fn list_models(&self, params: Option<ListModelsParams>) -> Result<ListModelsResponse> {
-        let mut request = self.request_builder(reqwest::Method::GET, "/models")?;
+        let mut request =
+            self.request_builder(reqwest::Method::GET, self.endpoint_path(Endpoint::Models))?;
 
         // Add query parameters if provided
         if let Some(p) = params {
@@ -91,16 +132,124 @@ impl ModelsService for PortkeyClient {
             }
         }
 
-        let response = request.send().await?;
-        let response = response.error_for_status()?;
-        let models_response: ListModelsResponse = response.json().await?;
+        let response = self.execute(request).await?;
+        let response = response.error_for_api_status().await?;
+        let models_response: ListModelsResponse = self.json_object(response).await?;
         Ok(models_response)
     }
+
+    async fn models_catalog(&self, ttl: Duration) -> Result<Arc<ModelsCatalog>> {
+        let mut cached = self.inner.models_catalog.lock().await;
+        if let Some((fetched_at, catalog)) = cached.as_ref()
+            && self.inner.clock.now().duration_since(*fetched_at) < ttl
+        {
+            return Ok(catalog.clone());
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            "Refreshing models catalog"
+        );
+
+        let catalog = Arc::new(ModelsCatalog::from(self.list_models(None).await?));
+        *cached = Some((self.inner.clock.now(), catalog.clone()));
+        Ok(catalog)
+    }
+
+    async fn invalidate_models_catalog(&self) {
+        *self.inner.models_catalog.lock().await = None;
+    }
+
+    async fn delete_model(&self, model: &str) -> Result<DeleteModelResponse> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            model = %model,
+            "Deleting model"
+        );
+
+        let path = format!("{}/{}", self.endpoint_path(Endpoint::Models), model);
+        let response = self
+            .send(reqwest::Method::DELETE, &path)
+            .await?
+            .error_for_api_status()
+            .await?;
+        let response: DeleteModelResponse = self.json_object(response).await?;
+
+        self.invalidate_models_catalog().await;
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Instant;
+
     use super::*;
+    use crate::PortkeyConfig;
+    use crate::builder::AuthMethod;
+    use crate::client::{Clock, TestClock};
+    use crate::model::Model;
+
+    fn catalog_client(clock: Arc<TestClock>) -> Result<PortkeyClient> {
+        // Nothing listens on this address, so any refresh fails.
+        let config = PortkeyConfig::builder()
+            .with_api_key("test_key")
+            .with_auth_method(AuthMethod::virtual_key("vk"))
+            .with_base_url("http://127.0.0.1:9")
+            .build()?;
+        PortkeyClient::with_clock(config, clock)
+    }
+
+    async fn seed_catalog(client: &PortkeyClient, at: Instant) {
+        let catalog = ModelsCatalog::from(ListModelsResponse {
+            object: "list".to_string(),
+            data: vec![Model {
+                id: "gpt-4o".to_string(),
+                created: 0,
+                object: "model".to_string(),
+                owned_by: "openai".to_string(),
+            }],
+        });
+        *client.inner.models_catalog.lock().await = Some((at, Arc::new(catalog)));
+    }
+
+    #[tokio::test]
+    async fn test_models_catalog_served_from_cache_within_ttl() -> Result<()> {
+        let clock = Arc::new(TestClock::new());
+        let client = catalog_client(clock.clone())?;
+        seed_catalog(&client, clock.now()).await;
+
+        clock.advance(Duration::from_secs(59));
+        let catalog = client
+            .clone()
+            .models_catalog(Duration::from_secs(60))
+            .await?;
+        assert!(catalog.contains("gpt-4o"));
+
+        clock.advance(Duration::from_secs(1));
+        assert!(
+            client
+                .models_catalog(Duration::from_secs(60))
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_models_catalog() -> Result<()> {
+        let clock = Arc::new(TestClock::new());
+        let client = catalog_client(clock.clone())?;
+        seed_catalog(&client, clock.now()).await;
+
+        client.invalidate_models_catalog().await;
+        assert!(client.inner.models_catalog.lock().await.is_none());
+
+        Ok(())
+    }
 
     #[test]
     fn test_list_models_params() {
@@ -1,5 +1,6 @@
 use std::future::Future;
 
+use crate::client::{Endpoint, ResponseExt};
 use crate::model::{CreateModerationRequest, ModerationResponse};
 use crate::{PortkeyClient, Result};
 
@@ -81,9 +82,13 @@ impl ModerationsService for PortkeyClient {
         );
 
         let response = self
-            .send_json(reqwest::Method::POST, "/moderations", &request)
+            .send_json(
+                reqwest::Method::POST,
+                self.endpoint_path(Endpoint::Moderations),
+                &request,
+            )
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let moderation: ModerationResponse = response.json().await?;
 
         #[cfg(feature = "tracing")]
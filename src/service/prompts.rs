@@ -2,11 +2,13 @@ use std::future::Future;
 
 #[cfg(feature = "tracing")]
 use crate::TRACING_TARGET_SERVICE;
-use crate::client::PortkeyClient;
+use crate::client::{PortkeyClient, ResponseExt};
 use crate::error::Result;
 use crate::model::{
-    PromptCompletionRequest, PromptCompletionResponse, PromptRenderRequest, PromptRenderResponse,
+    ChatCompletionRequest, ChatCompletionResponse, PromptCompletionRequest,
+    PromptCompletionResponse, PromptRenderRequest, PromptRenderResponse, PromptVariables,
 };
+use crate::service::ChatService;
 
 /// Service trait for executing prompt templates.
 ///
@@ -98,6 +100,53 @@ pub trait PromptsService {
         prompt_id: &str,
         request: PromptRenderRequest,
     ) -> impl Future<Output = Result<PromptRenderResponse>>;
+
+    /// Renders a prompt template and sends it as a chat completion from the client.
+    ///
+    /// Unlike [`execute_prompt`](Self::execute_prompt), where Portkey renders
+    /// and runs the template in a single opaque call, this renders the
+    /// template with [`render_prompt`](Self::render_prompt), converts the
+    /// result to a [`ChatCompletionRequest`], passes it to `modify`, and then
+    /// sends it with [`ChatService::create_chat_completion`]. The completion
+    /// is therefore logged as a regular chat completion rather than a prompt
+    /// execution, and uses the client's auth method rather than the one saved
+    /// with the prompt.
+    ///
+    /// Returns [`Error::Serialization`](crate::Error::Serialization) if the
+    /// prompt does not render to a chat request.
+    ///
+    /// # Arguments
+    ///
+    /// * `prompt_id` - The ID of the prompt template
+    /// * `variables` - The variables to substitute in the template
+    /// * `modify` - Called with the rendered request before it is sent
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::{ChatCompletionRequestMessage, PromptVariables};
+    /// # use portkey_sdk::service::PromptsService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let variables = PromptVariables::new().with_var("user_input", "Hello world");
+    /// let response = client
+    ///     .render_and_execute_locally("pp-example-123", variables, |request| {
+    ///         request
+    ///             .messages
+    ///             .push(ChatCompletionRequestMessage::user("Answer in one sentence."));
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn render_and_execute_locally<F>(
+        &self,
+        prompt_id: &str,
+        variables: impl Into<PromptVariables>,
+        modify: F,
+    ) -> impl Future<Output = Result<ChatCompletionResponse>>
+    where
+        F: FnOnce(&mut ChatCompletionRequest);
 }
 
 impl PromptsService for PortkeyClient {
@@ -119,7 +168,7 @@ impl PromptsService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::POST, &path, &request)
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let completion_response: PromptCompletionResponse = response.json().await?;
 
         Ok(completion_response)
@@ -142,9 +191,35 @@ impl PromptsService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::POST, &path, &request)
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let render_response: PromptRenderResponse = response.json().await?;
 
         Ok(render_response)
     }
+
+    async fn render_and_execute_locally<F>(
+        &self,
+        prompt_id: &str,
+        variables: impl Into<PromptVariables>,
+        modify: F,
+    ) -> Result<ChatCompletionResponse>
+    where
+        F: FnOnce(&mut ChatCompletionRequest),
+    {
+        let render_request = PromptRenderRequest::default().with_variables(variables);
+        let rendered = self.render_prompt(prompt_id, render_request).await?;
+
+        let mut request = rendered.as_chat_request()?;
+        modify(&mut request);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_SERVICE,
+            prompt_id = %prompt_id,
+            model = %request.model,
+            "Executing rendered prompt template"
+        );
+
+        self.create_chat_completion(request).await
+    }
 }
@@ -0,0 +1,142 @@
+use std::future::Future;
+
+#[cfg(feature = "tracing")]
+use crate::TRACING_TARGET_SERVICE;
+use crate::client::{ACCEPT_EVENT_STREAM, ResponseExt, spawn_sse};
+use crate::model::{CreateResponseGenerationRequest, ResponseGeneration, ResponseStreamEvent};
+use crate::{EventStream, PortkeyClient, RequestOptions, Result};
+
+/// A stream of Responses API generation events.
+pub type ResponseGenerationStream = EventStream<ResponseStreamEvent>;
+
+/// Service trait for generating model output with the Responses API.
+///
+/// This is OpenAI's generation endpoint, which takes `input` and returns
+/// model output. For recording and managing responses in Portkey's logs, see
+/// [`ResponsesService`](super::ResponsesService).
+///
+/// This trait is implemented on the [`PortkeyClient`](crate::client::PortkeyClient).
+///
+/// # Example
+///
+/// ```no_run
+/// use portkey_sdk::{PortkeyConfig, Result};
+/// use portkey_sdk::builder::AuthMethod;
+/// use portkey_sdk::model::CreateResponseGenerationRequest;
+/// use portkey_sdk::service::ResponseGenerationsService;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let client = PortkeyConfig::builder()
+///         .with_api_key("your-portkey-api-key")
+///         .with_auth_method(AuthMethod::virtual_key("your-virtual-key"))
+///         .build_client()?;
+///
+///     let request = CreateResponseGenerationRequest::new("gpt-4o", "Write a haiku about Rust.");
+///     let response = client.create_response_generation(request).await?;
+///     println!("{}", response.output_text());
+///
+///     Ok(())
+/// }
+/// ```
+pub trait ResponseGenerationsService {
+    /// Generates a response.
+    fn create_response_generation(
+        &self,
+        request: CreateResponseGenerationRequest,
+    ) -> impl Future<Output = Result<ResponseGeneration>>;
+
+    /// Generates a response as a stream of [`ResponseStreamEvent`]s.
+    ///
+    /// Sets `stream` on the request. The stream uses the default [`RequestOptions`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::model::{CreateResponseGenerationRequest, ResponseStreamEvent};
+    /// # use portkey_sdk::service::ResponseGenerationsService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// let request = CreateResponseGenerationRequest::new("gpt-4o", "Tell me a story.");
+    /// let mut stream = client.create_response_generation_stream(request).await?;
+    /// while let Some(event) = stream.next().await {
+    ///     if let ResponseStreamEvent::OutputTextDelta { delta, .. } = event? {
+    ///         print!("{delta}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_response_generation_stream(
+        &self,
+        request: CreateResponseGenerationRequest,
+    ) -> impl Future<Output = Result<ResponseGenerationStream>>;
+
+    /// Generates a response as a stream with per-request options.
+    fn create_response_generation_stream_with_options(
+        &self,
+        request: CreateResponseGenerationRequest,
+        options: RequestOptions,
+    ) -> impl Future<Output = Result<ResponseGenerationStream>>;
+}
+
+impl ResponseGenerationsService for PortkeyClient {
+    async fn create_response_generation(
+        &self,
+        request: CreateResponseGenerationRequest,
+    ) -> Result<ResponseGeneration> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_SERVICE,
+            model = %request.model,
+            "Creating response generation"
+        );
+
+        let response = self
+            .send_json(reqwest::Method::POST, "/responses", &request)
+            .await?;
+        let response = response.error_for_api_status().await?;
+        let generation: ResponseGeneration = self.json_object(response).await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_SERVICE,
+            id = %generation.id,
+            status = %generation.status,
+            "Response generation created successfully"
+        );
+
+        Ok(generation)
+    }
+
+    async fn create_response_generation_stream(
+        &self,
+        request: CreateResponseGenerationRequest,
+    ) -> Result<ResponseGenerationStream> {
+        self.create_response_generation_stream_with_options(request, RequestOptions::default())
+            .await
+    }
+
+    async fn create_response_generation_stream_with_options(
+        &self,
+        mut request: CreateResponseGenerationRequest,
+        options: RequestOptions,
+    ) -> Result<ResponseGenerationStream> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_SERVICE,
+            model = %request.model,
+            stream_buffer = options.stream_buffer(),
+            "Creating streaming response generation"
+        );
+
+        request.stream = Some(true);
+        let options = options.with_accept(ACCEPT_EVENT_STREAM).with_streaming();
+        let response = self
+            .send_json_with_options(reqwest::Method::POST, "/responses", &request, &options)
+            .await?;
+        let response = response.error_for_api_status().await?;
+
+        Ok(spawn_sse(response, options.stream_buffer()))
+    }
+}
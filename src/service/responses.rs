@@ -2,7 +2,7 @@ use std::future::Future;
 
 #[cfg(feature = "tracing")]
 use crate::TRACING_TARGET_SERVICE;
-use crate::client::PortkeyClient;
+use crate::client::{PortkeyClient, ResponseExt};
 use crate::error::Result;
 use crate::model::{CreateResponseRequest, ListInputItemsParams, ListInputItemsResponse, Response};
 
@@ -11,6 +11,9 @@ use crate::model::{CreateResponseRequest, ListInputItemsParams, ListInputItemsRe
 /// This trait provides methods for creating, retrieving, listing, and deleting
 /// responses, as well as managing input items associated with responses.
 ///
+/// These are logging-oriented records of responses. To generate model output
+/// with the Responses API, see [`ResponseGenerationsService`](super::ResponseGenerationsService).
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -98,6 +101,22 @@ pub trait ResponsesService {
     /// Returns an error if the response is not found or the API request fails.
     fn get_response(&self, response_id: &str) -> impl Future<Output = Result<Response>>;
 
+    /// Retrieves a specific response by ID, if it exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `response_id` - The unique identifier of the response to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(None)` if the API responds with `404 Not Found`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails for any other reason.
+    fn try_get_response(&self, response_id: &str)
+    -> impl Future<Output = Result<Option<Response>>>;
+
     /// Deletes a response.
     ///
     /// # Arguments
@@ -147,7 +166,7 @@ impl ResponsesService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::POST, "/responses", &request)
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let response_data: Response = response.json().await?;
 
         Ok(response_data)
@@ -163,12 +182,20 @@ impl ResponsesService for PortkeyClient {
 
         let path = format!("/responses/{}", response_id);
         let response = self.send(reqwest::Method::GET, &path).await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let response_data: Response = response.json().await?;
 
         Ok(response_data)
     }
 
+    async fn try_get_response(&self, response_id: &str) -> Result<Option<Response>> {
+        match self.get_response(response_id).await {
+            Ok(response) => Ok(Some(response)),
+            Err(error) if error.is_not_found() => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
     async fn delete_response(&self, response_id: &str) -> Result<()> {
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -179,7 +206,7 @@ impl ResponsesService for PortkeyClient {
 
         let path = format!("/responses/{}", response_id);
         let response = self.send(reqwest::Method::DELETE, &path).await?;
-        response.error_for_status()?;
+        response.error_for_api_status().await?;
 
         Ok(())
     }
@@ -209,10 +236,30 @@ impl ResponsesService for PortkeyClient {
             request = request.query(&[("offset", offset.to_string())]);
         }
 
-        let response = request.send().await?;
-        let response = response.error_for_status()?;
+        let response = self.execute(request).await?;
+        let response = response.error_for_api_status().await?;
         let input_items: ListInputItemsResponse = response.json().await?;
 
         Ok(input_items)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::MockTransport;
+
+    #[tokio::test]
+    async fn test_try_get_response() {
+        let client = MockTransport::client(200, r#"{"id":"resp_123"}"#);
+        let response = client.try_get_response("resp_123").await.unwrap();
+        assert_eq!(response.unwrap().id, "resp_123");
+
+        let client = MockTransport::client(404, r#"{"error":{"message":"not found"}}"#);
+        assert!(client.try_get_response("resp_123").await.unwrap().is_none());
+
+        let client = MockTransport::client(500, r#"{"error":{"message":"internal"}}"#);
+        let error = client.try_get_response("resp_123").await.unwrap_err();
+        assert!(error.is_status(500));
+    }
+}
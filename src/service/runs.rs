@@ -1,8 +1,9 @@
 use std::future::Future;
 
+use crate::client::ResponseExt;
 use crate::model::{
     CreateRunRequest, ListRunStepsResponse, ListRunsResponse, ModifyRunRequest, PaginationParams,
-    Run, RunStep, SubmitToolOutputsRequest,
+    Run, RunId, RunStep, RunStepId, SubmitToolOutputsRequest, ThreadId,
 };
 use crate::{PortkeyClient, Result};
 
@@ -42,13 +43,17 @@ pub trait RunsService {
     ) -> impl Future<Output = Result<Run>>;
 
     /// Retrieves a run.
-    fn retrieve_run(&self, thread_id: &str, run_id: &str) -> impl Future<Output = Result<Run>>;
+    fn retrieve_run(
+        &self,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
+    ) -> impl Future<Output = Result<Run>>;
 
     /// Modifies a run.
     fn modify_run(
         &self,
-        thread_id: &str,
-        run_id: &str,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
         request: ModifyRunRequest,
     ) -> impl Future<Output = Result<Run>>;
 
@@ -59,17 +64,58 @@ pub trait RunsService {
         params: PaginationParams,
     ) -> impl Future<Output = Result<ListRunsResponse>>;
 
+    /// Returns every run of a thread, following pagination, up to
+    /// `max_items`.
+    ///
+    /// Stops fetching pages once `max_items` runs are collected.
+    fn list_all_runs(
+        &self,
+        thread_id: &str,
+        max_items: Option<usize>,
+    ) -> impl Future<Output = Result<Vec<Run>>>;
+
     /// When a run has the status: "requires_action" and required_action.type is submit_tool_outputs,
     /// this endpoint can be used to submit the outputs from the tool calls once they're all completed.
     fn submit_tool_outputs(
         &self,
-        thread_id: &str,
-        run_id: &str,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
         request: SubmitToolOutputsRequest,
     ) -> impl Future<Output = Result<Run>>;
 
     /// Cancels a run that is in_progress.
-    fn cancel_run(&self, thread_id: &str, run_id: &str) -> impl Future<Output = Result<Run>>;
+    fn cancel_run(
+        &self,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
+    ) -> impl Future<Output = Result<Run>>;
+
+    /// Cancels every queued, in-progress or `requires_action` run on a thread.
+    ///
+    /// Every active run is attempted, and the result of each cancellation is
+    /// returned with the run ID, so a failure does not hide the runs that
+    /// were already cancelled. A run that finishes between being listed and
+    /// being cancelled makes the API reject the cancellation; when the run is
+    /// then found in a terminal status, it is skipped rather than reported.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use portkey_sdk::{PortkeyClient, Result};
+    /// # use portkey_sdk::service::RunsService;
+    /// # async fn example(client: PortkeyClient) -> Result<()> {
+    /// for (id, result) in client.cancel_active_runs("thread_abc123").await? {
+    ///     if let Err(error) = result {
+    ///         eprintln!("Failed to cancel {id}: {error}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn cancel_active_runs(
+        &self,
+        thread_id: &str,
+    ) -> impl Future<Output = Result<Vec<(String, Result<Run>)>>>;
 
     /// Create a thread and run it in one request.
     fn create_thread_and_run(&self, request: CreateRunRequest)
@@ -78,16 +124,16 @@ pub trait RunsService {
     /// Retrieves a run step.
     fn retrieve_run_step(
         &self,
-        thread_id: &str,
-        run_id: &str,
-        step_id: &str,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
+        step_id: impl Into<RunStepId>,
     ) -> impl Future<Output = Result<RunStep>>;
 
     /// Returns a list of run steps belonging to a run.
     fn list_run_steps(
         &self,
-        thread_id: &str,
-        run_id: &str,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
         params: PaginationParams,
     ) -> impl Future<Output = Result<ListRunStepsResponse>>;
 }
@@ -108,8 +154,8 @@ impl RunsService for PortkeyClient {
                 &request,
             )
             .await?;
-        let response = response.error_for_status()?;
-        let run: Run = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let run: Run = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -120,7 +166,13 @@ impl RunsService for PortkeyClient {
         Ok(run)
     }
 
-    async fn retrieve_run(&self, thread_id: &str, run_id: &str) -> Result<Run> {
+    async fn retrieve_run(
+        &self,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
+    ) -> Result<Run> {
+        let thread_id = thread_id.into();
+        let run_id = run_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -135,8 +187,8 @@ impl RunsService for PortkeyClient {
                 &format!("/threads/{}/runs/{}", thread_id, run_id),
             )
             .await?;
-        let response = response.error_for_status()?;
-        let run: Run = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let run: Run = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -149,10 +201,12 @@ impl RunsService for PortkeyClient {
 
     async fn modify_run(
         &self,
-        thread_id: &str,
-        run_id: &str,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
         request: ModifyRunRequest,
     ) -> Result<Run> {
+        let thread_id = thread_id.into();
+        let run_id = run_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -168,8 +222,8 @@ impl RunsService for PortkeyClient {
                 &request,
             )
             .await?;
-        let response = response.error_for_status()?;
-        let run: Run = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let run: Run = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -192,19 +246,9 @@ impl RunsService for PortkeyClient {
             "Listing runs"
         );
 
-        let query_params = params.to_query_params();
-        let query_params_refs: Vec<(&str, &str)> =
-            query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-
-        let response = self
-            .send_with_params(
-                reqwest::Method::GET,
-                &format!("/threads/{}/runs", thread_id),
-                &query_params_refs,
-            )
+        let runs: ListRunsResponse = self
+            .get_paginated(&format!("/threads/{}/runs", thread_id), &params)
             .await?;
-        let response = response.error_for_status()?;
-        let runs: ListRunsResponse = response.json().await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -215,12 +259,27 @@ impl RunsService for PortkeyClient {
         Ok(runs)
     }
 
+    async fn list_all_runs(&self, thread_id: &str, max_items: Option<usize>) -> Result<Vec<Run>> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: crate::TRACING_TARGET_SERVICE,
+            thread_id = %thread_id,
+            max_items = ?max_items,
+            "Listing all runs"
+        );
+
+        self.get_all_pages::<ListRunsResponse>(&format!("/threads/{}/runs", thread_id), max_items)
+            .await
+    }
+
     async fn submit_tool_outputs(
         &self,
-        thread_id: &str,
-        run_id: &str,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
         request: SubmitToolOutputsRequest,
     ) -> Result<Run> {
+        let thread_id = thread_id.into();
+        let run_id = run_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -236,8 +295,8 @@ impl RunsService for PortkeyClient {
                 &request,
             )
             .await?;
-        let response = response.error_for_status()?;
-        let run: Run = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let run: Run = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -248,7 +307,13 @@ impl RunsService for PortkeyClient {
         Ok(run)
     }
 
-    async fn cancel_run(&self, thread_id: &str, run_id: &str) -> Result<Run> {
+    async fn cancel_run(
+        &self,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
+    ) -> Result<Run> {
+        let thread_id = thread_id.into();
+        let run_id = run_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -264,8 +329,8 @@ impl RunsService for PortkeyClient {
                 &serde_json::json!({}),
             )
             .await?;
-        let response = response.error_for_status()?;
-        let run: Run = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let run: Run = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -276,6 +341,38 @@ impl RunsService for PortkeyClient {
         Ok(run)
     }
 
+    async fn cancel_active_runs(&self, thread_id: &str) -> Result<Vec<(String, Result<Run>)>> {
+        let mut active = self.list_all_runs(thread_id, None).await?;
+        active.retain(Run::is_cancellable);
+
+        let mut results = Vec::with_capacity(active.len());
+        for run in active {
+            let result = match self.cancel_run(thread_id, &run.id).await {
+                Err(error) if error.is_status(400) || error.is_status(409) => {
+                    // Skip the run only if it reached a terminal status after
+                    // it was listed, not on any rejected cancellation.
+                    match self.retrieve_run(thread_id, &run.id).await {
+                        Ok(current) if current.is_terminal() => {
+                            #[cfg(feature = "tracing")]
+                            tracing::debug!(
+                                target: crate::TRACING_TARGET_SERVICE,
+                                run_id = %run.id,
+                                status = %current.status,
+                                "Run is no longer cancellable"
+                            );
+                            continue;
+                        }
+                        _ => Err(error),
+                    }
+                }
+                result => result,
+            };
+            results.push((run.id, result));
+        }
+
+        Ok(results)
+    }
+
     async fn create_thread_and_run(&self, request: CreateRunRequest) -> Result<Run> {
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -286,8 +383,8 @@ impl RunsService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::POST, "/threads/runs", &request)
             .await?;
-        let response = response.error_for_status()?;
-        let run: Run = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let run: Run = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -300,10 +397,13 @@ impl RunsService for PortkeyClient {
 
     async fn retrieve_run_step(
         &self,
-        thread_id: &str,
-        run_id: &str,
-        step_id: &str,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
+        step_id: impl Into<RunStepId>,
     ) -> Result<RunStep> {
+        let thread_id = thread_id.into();
+        let run_id = run_id.into();
+        let step_id = step_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -319,8 +419,8 @@ impl RunsService for PortkeyClient {
                 &format!("/threads/{}/runs/{}/steps/{}", thread_id, run_id, step_id),
             )
             .await?;
-        let response = response.error_for_status()?;
-        let step: RunStep = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let step: RunStep = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -333,10 +433,12 @@ impl RunsService for PortkeyClient {
 
     async fn list_run_steps(
         &self,
-        thread_id: &str,
-        run_id: &str,
+        thread_id: impl Into<ThreadId>,
+        run_id: impl Into<RunId>,
         params: PaginationParams<'_>,
     ) -> Result<ListRunStepsResponse> {
+        let thread_id = thread_id.into();
+        let run_id = run_id.into();
         #[cfg(feature = "tracing")]
         tracing::debug!(
             target: crate::TRACING_TARGET_SERVICE,
@@ -345,19 +447,12 @@ impl RunsService for PortkeyClient {
             "Listing run steps"
         );
 
-        let query_params = params.to_query_params();
-        let query_params_refs: Vec<(&str, &str)> =
-            query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-
-        let response = self
-            .send_with_params(
-                reqwest::Method::GET,
+        let steps: ListRunStepsResponse = self
+            .get_paginated(
                 &format!("/threads/{}/runs/{}/steps", thread_id, run_id),
-                &query_params_refs,
+                &params,
             )
             .await?;
-        let response = response.error_for_status()?;
-        let steps: ListRunStepsResponse = response.json().await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -368,3 +463,96 @@ impl RunsService for PortkeyClient {
         Ok(steps)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::RouteTransport;
+
+    fn run_json(id: &str, status: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "object": "thread.run",
+            "created_at": 1699063290,
+            "thread_id": "thread_1",
+            "assistant_id": "asst_1",
+            "status": status,
+            "model": "gpt-4o",
+            "instructions": "",
+            "tools": [],
+            "metadata": {}
+        })
+    }
+
+    #[tokio::test]
+    async fn test_cancel_active_runs() {
+        let list = serde_json::json!({
+            "object": "list",
+            "data": [
+                run_json("run_1", "queued"),
+                run_json("run_2", "completed"),
+                run_json("run_3", "in_progress"),
+                run_json("run_4", "requires_action"),
+                run_json("run_5", "queued"),
+            ],
+            "first_id": "run_1",
+            "last_id": "run_5",
+            "has_more": false
+        });
+        let transport = RouteTransport::default()
+            .route("GET /threads/thread_1/runs", 200, list.to_string())
+            .route(
+                "POST /threads/thread_1/runs/run_1/cancel",
+                200,
+                run_json("run_1", "cancelling").to_string(),
+            )
+            // run_3 completed after it was listed
+            .route(
+                "POST /threads/thread_1/runs/run_3/cancel",
+                400,
+                r#"{"error":{"message":"Cannot cancel run with status 'completed'."}}"#,
+            )
+            .route(
+                "GET /threads/thread_1/runs/run_3",
+                200,
+                run_json("run_3", "completed").to_string(),
+            )
+            // run_4 is still active, so its rejection is reported
+            .route(
+                "POST /threads/thread_1/runs/run_4/cancel",
+                409,
+                r#"{"error":{"message":"Run is locked."}}"#,
+            )
+            .route(
+                "GET /threads/thread_1/runs/run_4",
+                200,
+                run_json("run_4", "requires_action").to_string(),
+            )
+            .route(
+                "POST /threads/thread_1/runs/run_5/cancel",
+                200,
+                run_json("run_5", "cancelling").to_string(),
+            );
+        let requests = transport.requests();
+        let client = transport.client();
+
+        let results = client.cancel_active_runs("thread_1").await.unwrap();
+        let ids: Vec<&str> = results.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, ["run_1", "run_4", "run_5"]);
+        assert_eq!(results[0].1.as_ref().unwrap().id, "run_1");
+        assert!(results[1].1.as_ref().unwrap_err().is_status(409));
+        assert_eq!(results[2].1.as_ref().unwrap().id, "run_5");
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [
+                "GET /threads/thread_1/runs",
+                "POST /threads/thread_1/runs/run_1/cancel",
+                "POST /threads/thread_1/runs/run_3/cancel",
+                "GET /threads/thread_1/runs/run_3",
+                "POST /threads/thread_1/runs/run_4/cancel",
+                "GET /threads/thread_1/runs/run_4",
+                "POST /threads/thread_1/runs/run_5/cancel"
+            ]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use std::future::Future;
 
+use crate::client::ResponseExt;
 use crate::model::{CreateThreadRequest, DeleteThreadResponse, ModifyThreadRequest, Thread};
 use crate::{PortkeyClient, Result};
 
@@ -55,8 +56,8 @@ impl ThreadsService for PortkeyClient {
         let response = self
             .send_json(reqwest::Method::POST, "/threads", &request)
             .await?;
-        let response = response.error_for_status()?;
-        let thread: Thread = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let thread: Thread = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -78,8 +79,8 @@ impl ThreadsService for PortkeyClient {
         let response = self
             .send(reqwest::Method::GET, &format!("/threads/{}", thread_id))
             .await?;
-        let response = response.error_for_status()?;
-        let thread: Thread = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let thread: Thread = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -105,8 +106,8 @@ impl ThreadsService for PortkeyClient {
                 &request,
             )
             .await?;
-        let response = response.error_for_status()?;
-        let thread: Thread = response.json().await?;
+        let response = response.error_for_api_status().await?;
+        let thread: Thread = self.json_object(response).await?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!(
@@ -128,7 +129,7 @@ impl ThreadsService for PortkeyClient {
         let response = self
             .send(reqwest::Method::DELETE, &format!("/threads/{}", thread_id))
             .await?;
-        let response = response.error_for_status()?;
+        let response = response.error_for_api_status().await?;
         let delete_response: DeleteThreadResponse = response.json().await?;
 
         #[cfg(feature = "tracing")]